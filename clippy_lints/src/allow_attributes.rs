@@ -0,0 +1,115 @@
+use rustc::lint::*;
+use syntax::ast::*;
+use syntax::codemap::Span;
+use crate::utils::{in_macro, span_help_and_lint};
+
+/// **What it does:** Checks for `#[allow(..)]` attributes which are not
+/// explained by a `reason = ".."` field, or by a comment above the attribute
+/// or at the end of its line.
+///
+/// **Why is this bad?** Allowing a lint should be an exception, and the
+/// reason for it is easily forgotten. A comment next to the attribute keeps
+/// suppressed warnings auditable.
+///
+/// **Known problems:** The `reason = ".."` field of lint attributes needs the
+/// `lint_reasons` feature, so a comment is accepted as a reason too. Any
+/// comment other than a doc comment counts. Set the
+/// `require-allow-reason-field` configuration to only accept the field.
+///
+/// **Example:**
+/// ```rust
+/// #[allow(dead_code)]
+/// fn unused() {}
+/// ```
+/// Use instead:
+/// ```rust
+/// #[allow(dead_code, reason = "only called from the FFI side")]
+/// fn unused() {}
+/// ```
+declare_clippy_lint! {
+    pub ALLOW_ATTRIBUTES_WITHOUT_REASON,
+    restriction,
+    "ensures that all `allow` attributes come with a reason"
+}
+
+#[derive(Copy, Clone)]
+pub struct AllowAttributes {
+    require_reason_field: bool,
+}
+
+impl AllowAttributes {
+    pub fn new(require_reason_field: bool) -> Self {
+        Self { require_reason_field }
+    }
+}
+
+impl LintPass for AllowAttributes {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(ALLOW_ATTRIBUTES_WITHOUT_REASON)
+    }
+}
+
+impl EarlyLintPass for AllowAttributes {
+    fn check_attribute(&mut self, cx: &EarlyContext, attr: &Attribute) {
+        if in_macro(attr.span) || !attr.check_name("allow") {
+            return;
+        }
+        let items = match attr.meta_item_list() {
+            Some(items) => items,
+            None => return,
+        };
+        if has_reason_field(&items) || (!self.require_reason_field && has_reason_comment(cx, attr.span)) {
+            return;
+        }
+
+        let help = if self.require_reason_field {
+            "explain why the lint is allowed in a `reason = \"..\"` field"
+        } else {
+            "explain why the lint is allowed in a `reason = \"..\"` field or in a comment above the attribute"
+        };
+        span_help_and_lint(
+            cx,
+            ALLOW_ATTRIBUTES_WITHOUT_REASON,
+            attr.span,
+            "`allow` attribute without specifying a reason",
+            help,
+        );
+    }
+}
+
+/// Checks for a `reason = ".."` item in the list of an `allow` attribute.
+fn has_reason_field(items: &[NestedMetaItem]) -> bool {
+    items
+        .iter()
+        .any(|item| item.check_name("reason") && item.value_str().is_some())
+}
+
+/// Checks for a comment at the end of the line of the attribute, or on the
+/// line above it and the attributes stacked with it.
+fn has_reason_comment(cx: &EarlyContext, span: Span) -> bool {
+    let codemap = cx.sess().codemap();
+    let end = codemap.lookup_char_pos(span.hi());
+    if let Some(line) = end.file.get_line(end.line - 1 /* line numbers in `Loc` are 1-based */) {
+        if line.chars().skip(end.col.0).collect::<String>().trim_left().starts_with("//") {
+            return true;
+        }
+    }
+
+    let start = codemap.lookup_char_pos(span.lo());
+    let mut line_no = start.line - 1;
+    while line_no > 0 {
+        line_no -= 1;
+        match start.file.get_line(line_no) {
+            Some(line) => {
+                let line = line.trim_left();
+                if line.starts_with("///") || line.starts_with("//!") || line.starts_with('#') {
+                    // doc comments and other attributes of the same item
+                    continue;
+                }
+                return line.starts_with("//");
+            },
+            None => return false,
+        }
+    }
+    false
+}
@@ -2,8 +2,8 @@
 
 use crate::reexport::*;
 use crate::utils::{
-    in_macro, last_line_of_span, match_def_path, opt_def_id, paths, snippet_opt, span_lint, span_lint_and_then,
-    without_block_comments,
+    in_macro, last_line_of_span, match_def_path, opt_def_id, paths, snippet, snippet_opt, span_help_and_lint,
+    span_lint, span_lint_and_sugg, span_lint_and_then, without_block_comments,
 };
 use rustc::hir::*;
 use rustc::lint::*;
@@ -116,6 +116,85 @@ declare_clippy_lint! {
     "empty line after outer attribute"
 }
 
+/// **What it does:** Checks for empty lines after doc comments.
+///
+/// **Why is this bad?**
+/// The doc comment documents the item following the empty lines, even though
+/// it most likely was meant for the enclosing module or crate, i.e. should
+/// have been an inner doc comment (`//!`), or for a previous item.
+///
+/// **Known problems:** Same as for `EMPTY_LINE_AFTER_OUTER_ATTR`, empty lines
+/// are found by looking at the source text.
+///
+/// **Example:**
+/// ```rust
+/// // Bad
+/// /// Some doc comment with a blank line after it.
+///
+/// fn not_quite_good_code() { }
+///
+/// // Good
+/// /// Good (no blank line)
+/// fn this_is_fine() { }
+/// ```
+declare_clippy_lint! {
+    pub EMPTY_LINE_AFTER_DOC_COMMENTS,
+    nursery,
+    "empty line after doc comments"
+}
+
+/// **What it does:** Checks for `any` and `all` combinators in `cfg` with only
+/// one condition.
+///
+/// **Why is this bad?** If there is only one condition, no need to wrap it into
+/// `any` or `all` combinators.
+///
+/// **Known problems:** Only the `cfg` attributes of compiled items are
+/// checked.
+///
+/// **Example:**
+/// ```rust
+/// #[cfg(any(unix))]
+/// pub struct Bar;
+/// ```
+/// Use instead:
+/// ```rust
+/// #[cfg(unix)]
+/// pub struct Bar;
+/// ```
+declare_clippy_lint! {
+    pub NON_MINIMAL_CFG,
+    style,
+    "ensure that all `cfg(any())` and `cfg(all())` have more than one condition"
+}
+
+/// **What it does:** Checks for `#[cfg(features = "...")]` and suggests to
+/// replace it with `#[cfg(feature = "...")]`, and for `#[cfg(test = "...")]`
+/// and suggests to replace it with `#[cfg(test)]`.
+///
+/// **Why is this bad?** `features` is not a conditional compilation option,
+/// and `test` doesn't take a value, so these conditions are always false.
+///
+/// **Known problems:** Only the `cfg` attributes of compiled items are
+/// checked, so the conditions are only found when they are negated or
+/// combined with others.
+///
+/// **Example:**
+/// ```rust
+/// #[cfg(not(features = "some-feature"))]
+/// fn conditional() { }
+/// ```
+/// Use instead:
+/// ```rust
+/// #[cfg(not(feature = "some-feature"))]
+/// fn conditional() { }
+/// ```
+declare_clippy_lint! {
+    pub MAYBE_MISUSED_CFG,
+    correctness,
+    "usage of `cfg(features = ...)` or `cfg(test = ...)` instead of `cfg(feature = ...)` or `cfg(test)`"
+}
+
 #[derive(Copy, Clone)]
 pub struct AttrPass;
 
@@ -125,7 +204,10 @@ impl LintPass for AttrPass {
             INLINE_ALWAYS,
             DEPRECATED_SEMVER,
             USELESS_ATTRIBUTE,
-            EMPTY_LINE_AFTER_OUTER_ATTR
+            EMPTY_LINE_AFTER_OUTER_ATTR,
+            EMPTY_LINE_AFTER_DOC_COMMENTS,
+            NON_MINIMAL_CFG,
+            MAYBE_MISUSED_CFG
         )
     }
 }
@@ -133,6 +215,11 @@ impl LintPass for AttrPass {
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for AttrPass {
     fn check_attribute(&mut self, cx: &LateContext<'a, 'tcx>, attr: &'tcx Attribute) {
         if let Some(ref items) = attr.meta_item_list() {
+            if attr.name() == "cfg" {
+                check_nested_cfg(cx, items);
+                check_nested_misused_cfg(cx, items);
+                return;
+            }
             if items.is_empty() || attr.name() != "deprecated" {
                 return;
             }
@@ -265,6 +352,8 @@ fn check_attrs(cx: &LateContext, span: Span, name: Name, attrs: &[Attribute]) {
         return;
     }
 
+    check_empty_line_after_doc_comments(cx, span, attrs);
+
     for attr in attrs {
         if attr.is_sugared_doc {
             return;
@@ -325,6 +414,60 @@ fn check_semver(cx: &LateContext, span: Span, lit: &Lit) {
     );
 }
 
+/// Lints `any` and `all` predicates with a single condition, and recurses into
+/// the others.
+fn check_nested_cfg(cx: &LateContext, items: &[NestedMetaItem]) {
+    for item in items {
+        if let NestedMetaItemKind::MetaItem(ref meta) = item.node {
+            if let MetaItemKind::List(ref list) = meta.node {
+                if list.len() == 1 && (meta.name() == "any" || meta.name() == "all") {
+                    span_lint_and_sugg(
+                        cx,
+                        NON_MINIMAL_CFG,
+                        meta.span,
+                        "unneeded sub `cfg` when there is only one condition",
+                        "try",
+                        snippet(cx, list[0].span, "..").into_owned(),
+                    );
+                } else {
+                    check_nested_cfg(cx, list);
+                }
+            }
+        }
+    }
+}
+
+fn check_nested_misused_cfg(cx: &LateContext, items: &[NestedMetaItem]) {
+    for item in items {
+        if let NestedMetaItemKind::MetaItem(ref meta) = item.node {
+            match meta.node {
+                MetaItemKind::List(ref list) => check_nested_misused_cfg(cx, list),
+                MetaItemKind::NameValue(ref lit) if meta.name() == "features" => {
+                    span_lint_and_sugg(
+                        cx,
+                        MAYBE_MISUSED_CFG,
+                        meta.span,
+                        "`features` is not a conditional compilation option",
+                        "did you mean",
+                        format!("feature = {}", snippet(cx, lit.span, "..")),
+                    );
+                },
+                MetaItemKind::NameValue(_) if meta.name() == "test" => {
+                    span_lint_and_sugg(
+                        cx,
+                        MAYBE_MISUSED_CFG,
+                        meta.span,
+                        "`test` doesn't take a value",
+                        "did you mean",
+                        "test".to_string(),
+                    );
+                },
+                _ => {},
+            }
+        }
+    }
+}
+
 fn is_word(nmi: &NestedMetaItem, expected: &str) -> bool {
     if let NestedMetaItemKind::MetaItem(ref mi) = nmi.node {
         mi.is_word() && mi.name() == expected
@@ -338,6 +481,38 @@ fn is_word(nmi: &NestedMetaItem, expected: &str) -> bool {
 // sources that the user has no control over.
 // For some reason these attributes don't have any expansion info on them, so
 // we have to check it this way until there is a better way.
+/// Checks for empty lines between the last of consecutive doc comments and the
+/// following attribute or item.
+fn check_empty_line_after_doc_comments(cx: &LateContext, span: Span, attrs: &[Attribute]) {
+    for (i, attr) in attrs.iter().enumerate() {
+        if !attr.is_sugared_doc || attr.style != AttrStyle::Outer {
+            continue;
+        }
+        let next_span = match attrs.get(i + 1) {
+            Some(next) if next.is_sugared_doc => continue,
+            Some(next) => next.span,
+            None => span,
+        };
+
+        let end_of_doc_to_next = Span::new(attr.span.hi(), next_span.lo(), span.ctxt());
+        if let Some(snippet) = snippet_opt(cx, end_of_doc_to_next) {
+            let lines = snippet.split('\n').collect::<Vec<_>>();
+            let lines = without_block_comments(lines);
+
+            if lines.iter().filter(|l| l.trim().is_empty()).count() > 2 {
+                span_help_and_lint(
+                    cx,
+                    EMPTY_LINE_AFTER_DOC_COMMENTS,
+                    Span::new(attr.span.lo(), next_span.lo(), span.ctxt()),
+                    "found an empty line after a doc comment",
+                    "if the comment should document the enclosing module or crate, use an inner doc comment \
+                     (`//!`), otherwise remove the empty line",
+                );
+            }
+        }
+    }
+}
+
 fn is_present_in_source(cx: &LateContext, span: Span) -> bool {
     if let Some(snippet) = snippet_opt(cx, span) {
         if snippet.is_empty() {
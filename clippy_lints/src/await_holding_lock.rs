@@ -0,0 +1,148 @@
+use rustc::hir::intravisit::{walk_body, walk_local, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::middle::region::ScopeTree;
+use rustc::ty::Ty;
+use crate::utils::paths;
+use crate::utils::{generator_interior_types, match_type, span_help_and_lint, span_lint};
+
+/// **What it does:** Checks for `MutexGuard`s, `RwLock` guards and `RefCell` borrows which
+/// are held across an `await` point.
+///
+/// **Why is this bad?** The guard is saved in the future while it is suspended. Any other
+/// task which tries to take the same lock on the same executor thread will then block
+/// forever, since the future holding the guard can't be polled to completion. A `RefCell`
+/// borrow held the same way makes other tasks panic instead.
+///
+/// **Known problems:** The check is based on scopes, so a guard which is dropped with
+/// `drop()` before the `await` is still reported. Move it into an inner block instead.
+///
+/// **Example:**
+/// ```rust
+/// async fn foo(x: &Mutex<u32>) {
+///     let mut guard = x.lock().unwrap();
+///     *guard += 1;
+///     await!(bar());
+/// }
+/// ```
+/// Use instead:
+/// ```rust
+/// async fn foo(x: &Mutex<u32>) {
+///     {
+///         let mut guard = x.lock().unwrap();
+///         *guard += 1;
+///     }
+///     await!(bar());
+/// }
+/// ```
+declare_clippy_lint! {
+    pub AWAIT_HOLDING_LOCK,
+    correctness,
+    "lock guards and `RefCell` borrows held across an `await` point"
+}
+
+const GUARDS: [(&[&str], &str); 5] = [
+    (&paths::MUTEX_GUARD, "MutexGuard"),
+    (&paths::RWLOCK_READ_GUARD, "RwLockReadGuard"),
+    (&paths::RWLOCK_WRITE_GUARD, "RwLockWriteGuard"),
+    (&paths::REFCELL_REF, "Ref"),
+    (&paths::REFCELL_REFMUT, "RefMut"),
+];
+
+pub struct AwaitHoldingLock;
+
+impl LintPass for AwaitHoldingLock {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(AWAIT_HOLDING_LOCK)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for AwaitHoldingLock {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if let ExprClosure(_, _, body_id, _, Some(_)) = expr.node {
+            let interior = match generator_interior_types(cx, cx.tables.expr_ty(expr)) {
+                Some(interior) => interior,
+                None => return,
+            };
+            let held: Vec<&'static str> = GUARDS
+                .iter()
+                .filter(|&&(path, _)| interior.iter().any(|&ty| match_type(cx, ty, path)))
+                .map(|&(_, name)| name)
+                .collect();
+            if held.is_empty() {
+                return;
+            }
+
+            let region_scope_tree = cx.tcx.region_scope_tree(cx.tcx.hir.body_owner_def_id(body_id));
+            let mut visitor = GuardBindingVisitor {
+                cx,
+                held: &held,
+                region_scope_tree: &region_scope_tree,
+                found: false,
+            };
+            walk_body(&mut visitor, cx.tcx.hir.body(body_id));
+
+            // the guard is only held by a temporary
+            if !visitor.found {
+                span_lint(
+                    cx,
+                    AWAIT_HOLDING_LOCK,
+                    expr.span,
+                    &format!("this future holds a `{}` across an `await` point", held[0]),
+                );
+            }
+        }
+    }
+}
+
+/// Returns the name of the guard type `ty` is, if it is one of the guards in `held`.
+fn guard_name<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, ty: Ty<'tcx>, held: &[&'static str]) -> Option<&'static str> {
+    GUARDS
+        .iter()
+        .find(|&&(path, name)| held.contains(&name) && match_type(cx, ty, path))
+        .map(|&(_, name)| name)
+}
+
+struct GuardBindingVisitor<'a, 'tcx: 'a, 'h> {
+    cx: &'a LateContext<'a, 'tcx>,
+    held: &'h [&'static str],
+    region_scope_tree: &'h ScopeTree,
+    found: bool,
+}
+
+impl<'a, 'tcx, 'h> GuardBindingVisitor<'a, 'tcx, 'h> {
+    /// Checks if the scope of the binding `pat` contains a suspension point. Only simple
+    /// bindings are checked, a guard in another pattern is assumed to be held.
+    fn is_held_across_yield(&self, pat: &Pat) -> bool {
+        if let PatKind::Binding(..) = pat.node {
+            let scope = self.region_scope_tree.var_scope(pat.hir_id.local_id);
+            self.region_scope_tree.yield_in_scope(scope).is_some()
+        } else {
+            true
+        }
+    }
+}
+
+impl<'a, 'tcx, 'h> Visitor<'tcx> for GuardBindingVisitor<'a, 'tcx, 'h> {
+    fn visit_local(&mut self, local: &'tcx Local) {
+        if_chain! {
+            if let Some(name) = guard_name(self.cx, self.cx.tables.pat_ty(&local.pat), self.held);
+            if self.is_held_across_yield(&local.pat);
+            then {
+                self.found = true;
+                span_help_and_lint(
+                    self.cx,
+                    AWAIT_HOLDING_LOCK,
+                    local.pat.span,
+                    &format!("this `{}` is held across an `await` point", name),
+                    "consider moving it into an inner block which ends before the `await`",
+                );
+            }
+        }
+        walk_local(self, local);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
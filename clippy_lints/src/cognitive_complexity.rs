@@ -0,0 +1,287 @@
+//! calculate cognitive complexity and warn about overly complex functions
+
+use rustc::lint::*;
+use rustc::hir::*;
+use rustc::hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use syntax::ast::{Attribute, NodeId};
+use syntax::codemap::{ExpnFormat, Span};
+
+use crate::utils::{higher, in_macro, span_help_and_lint, LimitStack};
+
+/// **What it does:** Checks for methods with high cognitive complexity.
+///
+/// **Why is this bad?** Methods of high cognitive complexity are hard to read
+/// and to maintain. Unlike the cyclomatic complexity, which counts the paths
+/// through a function, the cognitive complexity scores how hard the control
+/// flow is to follow:
+///
+/// * each `if`, `else if`, `else`, `match`, loop and closure adds one, plus one
+/// for each level of those constructs it is nested in,
+/// * each sequence of `&&` or `||` operators adds one,
+/// * each early exit, that is a `return`, a `?` or a labeled
+/// `break`/`continue`, adds one.
+///
+/// **Known problems:** Sometimes it's hard to find a way to reduce the
+/// complexity.
+///
+/// **Example:** No. You'll see it when you get the warning.
+declare_clippy_lint! {
+    pub COGNITIVE_COMPLEXITY,
+    nursery,
+    "functions whose control flow is hard to follow"
+}
+
+pub struct CognitiveComplexity {
+    limit: LimitStack,
+}
+
+impl CognitiveComplexity {
+    pub fn new(limit: u64) -> Self {
+        Self {
+            limit: LimitStack::new(limit),
+        }
+    }
+}
+
+impl LintPass for CognitiveComplexity {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(COGNITIVE_COMPLEXITY)
+    }
+}
+
+impl CognitiveComplexity {
+    fn check<'a, 'tcx: 'a>(&mut self, cx: &'a LateContext<'a, 'tcx>, body: &'tcx Body, span: Span) {
+        if in_macro(span) {
+            return;
+        }
+
+        let mut helper = CognitiveComplexityHelper {
+            cx,
+            nesting: 0,
+            score: 0,
+        };
+        helper.visit_expr(&body.value);
+
+        if helper.score > self.limit.limit() {
+            span_help_and_lint(
+                cx,
+                COGNITIVE_COMPLEXITY,
+                span,
+                &format!("the function has a cognitive complexity of {}", helper.score),
+                "you could split it up into multiple smaller functions",
+            );
+        }
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for CognitiveComplexity {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'a, 'tcx>,
+        kind: intravisit::FnKind<'tcx>,
+        _: &'tcx FnDecl,
+        body: &'tcx Body,
+        span: Span,
+        node_id: NodeId,
+    ) {
+        // closures are scored as part of their enclosing function
+        if let intravisit::FnKind::Closure(_) = kind {
+            return;
+        }
+
+        let def_id = cx.tcx.hir.local_def_id(node_id);
+        if !cx.tcx.has_attr(def_id, "test") {
+            self.check(cx, body, span);
+        }
+    }
+
+    fn enter_lint_attrs(&mut self, cx: &LateContext<'a, 'tcx>, attrs: &'tcx [Attribute]) {
+        self.limit
+            .push_attrs(cx.sess(), attrs, "cognitive_complexity");
+    }
+    fn exit_lint_attrs(&mut self, cx: &LateContext<'a, 'tcx>, attrs: &'tcx [Attribute]) {
+        self.limit
+            .pop_attrs(cx.sess(), attrs, "cognitive_complexity");
+    }
+}
+
+struct CognitiveComplexityHelper<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    nesting: u64,
+    score: u64,
+}
+
+impl<'a, 'tcx> CognitiveComplexityHelper<'a, 'tcx> {
+    /// Scores a structure which increases the nesting of its contents.
+    fn structure(&mut self) {
+        self.score += 1 + self.nesting;
+    }
+
+    fn visit_nested(&mut self, e: &'tcx Expr) {
+        self.nesting += 1;
+        self.visit_expr(e);
+        self.nesting -= 1;
+    }
+
+    fn visit_nested_block(&mut self, block: &'tcx Block) {
+        self.nesting += 1;
+        self.visit_block(block);
+        self.nesting -= 1;
+    }
+
+    /// Visits the `else` branch of an `if` or `if let`, an `else if` is not
+    /// nested any deeper than the `if` it follows.
+    fn visit_else(&mut self, e: &'tcx Expr) {
+        match e.node {
+            ExprIf(ref cond, ref then, ref otherwise) => {
+                self.score += 1;
+                self.visit_if(cond, then, otherwise.as_ref().map(|e| &**e));
+            },
+            ExprMatch(ref scrutinee, ref arms, MatchSource::IfLetDesugar { contains_else_clause }) => {
+                self.score += 1;
+                self.visit_if_let(scrutinee, arms, contains_else_clause);
+            },
+            _ => {
+                self.score += 1;
+                self.visit_nested(e);
+            },
+        }
+    }
+
+    fn visit_if(&mut self, cond: &'tcx Expr, then: &'tcx Expr, otherwise: Option<&'tcx Expr>) {
+        self.visit_expr(cond);
+        self.visit_nested(then);
+        if let Some(otherwise) = otherwise {
+            self.visit_else(otherwise);
+        }
+    }
+
+    fn visit_if_let(&mut self, scrutinee: &'tcx Expr, arms: &'tcx [Arm], contains_else_clause: bool) {
+        self.visit_expr(scrutinee);
+        self.visit_nested(&arms[0].body);
+        if contains_else_clause {
+            self.visit_else(&arms[1].body);
+        }
+    }
+
+    /// Scores a chain of `&&` and `||` operators, each change of the operator
+    /// starts a new sequence.
+    fn visit_logical_chain(&mut self, e: &'tcx Expr) {
+        let mut ops = Vec::new();
+        let mut operands = Vec::new();
+        flatten_logical_chain(e, &mut ops, &mut operands);
+
+        self.score += 1 + ops.windows(2).filter(|w| w[0] != w[1]).count() as u64;
+        for operand in operands {
+            self.visit_expr(operand);
+        }
+    }
+}
+
+/// Returns true if `span` was expanded by a macro, unlike `in_macro` the
+/// desugarings of `for` loops and `?` are not considered macros here.
+fn is_macro_expansion(span: Span) -> bool {
+    span.ctxt().outer().expn_info().map_or(false, |info| match info.format {
+        ExpnFormat::CompilerDesugaring(_) => false,
+        _ => true,
+    })
+}
+
+fn is_logical_op(op: BinOp) -> bool {
+    match op.node {
+        BiAnd | BiOr => true,
+        _ => false,
+    }
+}
+
+/// Collects the operators and the operands of a chain of `&&` and `||`, in
+/// source order.
+fn flatten_logical_chain<'tcx>(e: &'tcx Expr, ops: &mut Vec<BinOp_>, operands: &mut Vec<&'tcx Expr>) {
+    match e.node {
+        ExprBinary(op, ref lhs, ref rhs) if is_logical_op(op) => {
+            flatten_logical_chain(lhs, ops, operands);
+            ops.push(op.node);
+            flatten_logical_chain(rhs, ops, operands);
+        },
+        _ => operands.push(e),
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for CognitiveComplexityHelper<'a, 'tcx> {
+    fn visit_expr(&mut self, e: &'tcx Expr) {
+        // don't score the control flow generated by macros like `assert!`
+        if is_macro_expansion(e.span) {
+            walk_expr(self, e);
+            return;
+        }
+
+        if let Some((pat, arg, body)) = higher::for_loop(e) {
+            self.structure();
+            self.visit_pat(pat);
+            self.visit_expr(arg);
+            self.visit_nested(body);
+            return;
+        }
+
+        match e.node {
+            ExprIf(ref cond, ref then, ref otherwise) => {
+                self.structure();
+                self.visit_if(cond, then, otherwise.as_ref().map(|e| &**e));
+            },
+            ExprMatch(ref scrutinee, ref arms, source) => match source {
+                MatchSource::IfLetDesugar { contains_else_clause } => {
+                    self.structure();
+                    self.visit_if_let(scrutinee, arms, contains_else_clause);
+                },
+                // the enclosing loop has been scored, skip the `break` arm
+                MatchSource::WhileLetDesugar => {
+                    self.visit_expr(scrutinee);
+                    self.visit_expr(&arms[0].body);
+                },
+                MatchSource::TryDesugar => {
+                    self.score += 1;
+                    self.visit_expr(scrutinee);
+                },
+                _ => {
+                    self.structure();
+                    self.visit_expr(scrutinee);
+                    self.nesting += 1;
+                    for arm in arms {
+                        self.visit_arm(arm);
+                    }
+                    self.nesting -= 1;
+                },
+            },
+            ExprWhile(ref cond, ref block, _) => {
+                self.structure();
+                self.visit_expr(cond);
+                self.visit_nested_block(block);
+            },
+            ExprLoop(ref block, ..) => {
+                self.structure();
+                self.visit_nested_block(block);
+            },
+            ExprClosure(_, _, body_id, _, _) => {
+                self.structure();
+                let body = self.cx.tcx.hir.body(body_id);
+                self.visit_nested(&body.value);
+            },
+            ExprBinary(op, _, _) if is_logical_op(op) => self.visit_logical_chain(e),
+            ExprBreak(ref destination, _) | ExprContinue(ref destination) => {
+                if destination.label.is_some() {
+                    self.score += 1;
+                }
+                walk_expr(self, e);
+            },
+            ExprRet(_) => {
+                self.score += 1;
+                walk_expr(self, e);
+            },
+            _ => walk_expr(self, e),
+        }
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
@@ -0,0 +1,225 @@
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc_errors::Applicability;
+use std::borrow::Cow;
+use crate::utils::{get_parent_expr, get_trait_def_id, if_sequence, implements_trait, in_macro, paths, snippet,
+                   snippet_block, span_help_and_lint, span_lint_and_then, SpanlessEq};
+use crate::utils::sugg::indentation;
+
+/// **What it does:** Checks comparison chains written with `if` that can be
+/// rewritten with `match` and `cmp`.
+///
+/// **Why is this bad?** `if` is not guaranteed to be exhaustive and conditionals can get
+/// repetitive.
+///
+/// **Known problems:** Only types implementing `Ord` are checked, as `cmp`
+/// isn't available otherwise.
+///
+/// **Example:**
+/// ```rust
+/// fn f(x: u8, y: u8) {
+///     if x > y {
+///         a()
+///     } else if x < y {
+///         b()
+///     } else {
+///         c()
+///     }
+/// }
+/// ```
+///
+/// Could be written:
+///
+/// ```rust
+/// use std::cmp::Ordering;
+///
+/// fn f(x: u8, y: u8) {
+///     match x.cmp(&y) {
+///         Ordering::Greater => a(),
+///         Ordering::Less => b(),
+///         Ordering::Equal => c(),
+///     }
+/// }
+/// ```
+declare_clippy_lint! {
+    pub COMPARISON_CHAIN,
+    style,
+    "`if`s that can be rewritten with `match` and `cmp`"
+}
+
+#[derive(Copy, Clone)]
+pub struct ComparisonChain;
+
+impl LintPass for ComparisonChain {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(COMPARISON_CHAIN)
+    }
+}
+
+#[derive(Copy, Clone, PartialEq)]
+enum Ordering {
+    Less,
+    Equal,
+    Greater,
+}
+
+const ORDERINGS: [Ordering; 3] = [Ordering::Greater, Ordering::Less, Ordering::Equal];
+
+impl Ordering {
+    fn name(self) -> &'static str {
+        match self {
+            Ordering::Less => "Less",
+            Ordering::Equal => "Equal",
+            Ordering::Greater => "Greater",
+        }
+    }
+
+    fn reverse(self) -> Self {
+        match self {
+            Ordering::Less => Ordering::Greater,
+            Ordering::Equal => Ordering::Equal,
+            Ordering::Greater => Ordering::Less,
+        }
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ComparisonChain {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_macro(expr.span) {
+            return;
+        }
+
+        // skip ifs directly in else, it will be checked in the parent if
+        if let Some(&Expr {
+            node: ExprIf(_, _, Some(ref else_expr)),
+            ..
+        }) = get_parent_expr(cx, expr)
+        {
+            if else_expr.id == expr.id {
+                return;
+            }
+        }
+
+        let (conds, blocks) = if_sequence(expr);
+        // the final `else` block, if any
+        let els = blocks.get(conds.len());
+
+        // Check that there exists at least one explicit else condition
+        if conds.len() < 2 {
+            return;
+        }
+
+        let (lhs, rhs) = match conds[0].node {
+            ExprBinary(ref op, ref lhs, ref rhs) if ordering_of(op.node).is_some() => (lhs, rhs),
+            _ => return,
+        };
+
+        // Check that all conditions compare the same operands, and collect
+        // the ordering each of them tests for
+        let mut orderings = Vec::with_capacity(conds.len());
+        for cond in conds.iter() {
+            if let ExprBinary(ref op, ref l, ref r) = cond.node {
+                let ordering = match ordering_of(op.node) {
+                    Some(ordering) => ordering,
+                    None => return,
+                };
+                let mut spanless_eq = SpanlessEq::new(cx).ignore_fn();
+                if spanless_eq.eq_expr(l, lhs) && spanless_eq.eq_expr(r, rhs) {
+                    orderings.push(ordering);
+                } else if spanless_eq.eq_expr(l, rhs) && spanless_eq.eq_expr(r, lhs) {
+                    orderings.push(ordering.reverse());
+                } else {
+                    return;
+                }
+            } else {
+                return;
+            }
+        }
+
+        // Check that the same ordering isn't tested twice, which would make
+        // some branches unreachable
+        if orderings.iter().enumerate().any(|(i, o)| orderings[..i].contains(o)) {
+            return;
+        }
+
+        // Check that the type being compared implements `core::cmp::Ord`
+        let ty = cx.tables.expr_ty(lhs);
+        let is_ord = get_trait_def_id(cx, &paths::ORD).map_or(false, |id| implements_trait(cx, ty, id, &[]));
+        if !is_ord {
+            return;
+        }
+
+        let msg = "`if` chain can be rewritten with `match`";
+        let help = "consider rewriting the `if` chain with `match` (requires `use std::cmp::Ordering`)";
+        let missing = ORDERINGS
+            .iter()
+            .filter(|o| !orderings.contains(o))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        // Pair each possible ordering with the code of its branch, which is only
+        // possible if the final `else` (if any) covers exactly one ordering.
+        let arms = match els {
+            Some(_) if missing.len() != 1 => None,
+            _ => Some(
+                orderings
+                    .iter()
+                    .zip(blocks.iter())
+                    .map(|(ordering, block)| (*ordering, arm_body(cx, block)))
+                    .chain(missing.iter().map(|ordering| {
+                        let body = match els {
+                            Some(block) => arm_body(cx, block),
+                            None => "{}".into(),
+                        };
+                        (*ordering, body)
+                    }))
+                    .collect::<Vec<_>>(),
+            ),
+        };
+
+        // The suggestion is indented like the `if`, which requires the `if` to
+        // start its line.
+        if let (Some(arms), Some(indent)) = (arms, indentation(cx, expr.span)) {
+            span_lint_and_then(cx, COMPARISON_CHAIN, expr.span, msg, |db| {
+                let arms = arms
+                    .iter()
+                    .map(|(ordering, body)| {
+                        format!("    Ordering::{} => {},\n", ordering.name(), body.replace("\n", "\n    "))
+                    })
+                    .collect::<String>();
+                let sugg = format!(
+                    "match {}.cmp(&{}) {{\n{}}}",
+                    snippet(cx, lhs.span, ".."),
+                    snippet(cx, rhs.span, ".."),
+                    arms
+                );
+                db.span_suggestion_with_applicability(
+                    expr.span,
+                    help,
+                    sugg.replace("\n", &format!("\n{}", indent)),
+                    Applicability::MaybeIncorrect,
+                );
+            });
+        } else {
+            span_help_and_lint(cx, COMPARISON_CHAIN, expr.span, msg, help);
+        }
+    }
+}
+
+fn ordering_of(op: BinOp_) -> Option<Ordering> {
+    match op {
+        BiLt => Some(Ordering::Less),
+        BiEq => Some(Ordering::Equal),
+        BiGt => Some(Ordering::Greater),
+        _ => None,
+    }
+}
+
+/// Return the code of a `match` arm executing `block`, without the braces if
+/// the block is a single expression.
+fn arm_body(cx: &LateContext, block: &Block) -> Cow<'static, str> {
+    match block.expr {
+        Some(ref expr) if block.stmts.is_empty() => snippet(cx, expr.span, ".."),
+        _ => snippet_block(cx, block.span, ".."),
+    }
+}
@@ -4,9 +4,8 @@ use rustc::hir::*;
 use std::collections::HashMap;
 use std::collections::hash_map::Entry;
 use syntax::symbol::LocalInternedString;
-use syntax::util::small_vector::SmallVector;
 use crate::utils::{SpanlessEq, SpanlessHash};
-use crate::utils::{get_parent_expr, in_macro, snippet, span_lint_and_then, span_note_and_lint};
+use crate::utils::{get_parent_expr, if_sequence, in_macro, snippet, span_lint_and_then, span_note_and_lint};
 
 /// **What it does:** Checks for consecutive `if`s with the same condition.
 ///
@@ -228,39 +227,6 @@ fn lint_match_arms(cx: &LateContext, expr: &Expr) {
     }
 }
 
-/// Return the list of condition expressions and the list of blocks in a
-/// sequence of `if/else`.
-/// Eg. would return `([a, b], [c, d, e])` for the expression
-/// `if a { c } else if b { d } else { e }`.
-fn if_sequence(mut expr: &Expr) -> (SmallVector<&Expr>, SmallVector<&Block>) {
-    let mut conds = SmallVector::new();
-    let mut blocks: SmallVector<&Block> = SmallVector::new();
-
-    while let ExprIf(ref cond, ref then_expr, ref else_expr) = expr.node {
-        conds.push(&**cond);
-        if let ExprBlock(ref block, _) = then_expr.node {
-            blocks.push(block);
-        } else {
-            panic!("ExprIf node is not an ExprBlock");
-        }
-
-        if let Some(ref else_expr) = *else_expr {
-            expr = else_expr;
-        } else {
-            break;
-        }
-    }
-
-    // final `else {..}`
-    if !blocks.is_empty() {
-        if let ExprBlock(ref block, _) = expr.node {
-            blocks.push(&**block);
-        }
-    }
-
-    (conds, blocks)
-}
-
 /// Return the list of bindings in a pattern.
 fn bindings<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, pat: &Pat) -> HashMap<LocalInternedString, Ty<'tcx>> {
     fn bindings_impl<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, pat: &Pat, map: &mut HashMap<LocalInternedString, Ty<'tcx>>) {
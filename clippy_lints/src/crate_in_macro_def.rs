@@ -0,0 +1,98 @@
+use rustc::lint::*;
+use syntax::ast::*;
+use syntax::attr;
+use syntax::codemap::Span;
+use syntax::parse::token::{self, Token};
+use syntax::symbol::keywords;
+use syntax::tokenstream::{TokenStream, TokenTree};
+use crate::utils::span_lint_and_sugg;
+
+/// **What it does:** Checks for use of `crate` as opposed to `$crate` in an
+/// exported macro definition.
+///
+/// **Why is this bad?** The macro author almost certainly means to refer to
+/// their own crate with `crate::`, but `crate` is resolved where the macro is
+/// called, so the macro breaks when it is used from another crate. `$crate`
+/// always refers to the crate defining the macro.
+///
+/// **Known problems:** A macro may intentionally refer to items of the crate
+/// calling it.
+///
+/// **Example:**
+/// ```rust
+/// #[macro_export]
+/// macro_rules! print_message {
+///     () => {
+///         println!("{}", crate::MESSAGE);
+///     };
+/// }
+/// pub const MESSAGE: &str = "Hello!";
+/// ```
+/// Use instead:
+/// ```rust
+/// #[macro_export]
+/// macro_rules! print_message {
+///     () => {
+///         println!("{}", $crate::MESSAGE);
+///     };
+/// }
+/// pub const MESSAGE: &str = "Hello!";
+/// ```
+declare_clippy_lint! {
+    pub CRATE_IN_MACRO_DEF,
+    style,
+    "using `crate` in a macro definition"
+}
+
+#[derive(Copy, Clone)]
+pub struct CrateInMacroDef;
+
+impl LintPass for CrateInMacroDef {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(CRATE_IN_MACRO_DEF)
+    }
+}
+
+impl EarlyLintPass for CrateInMacroDef {
+    fn check_item(&mut self, cx: &EarlyContext, item: &Item) {
+        if_chain! {
+            if let ItemKind::MacroDef(ref macro_def) = item.node;
+            if attr::contains_name(&item.attrs, "macro_export");
+            if let Some(span) = first_crate_path(&macro_def.stream());
+            then {
+                span_lint_and_sugg(
+                    cx,
+                    CRATE_IN_MACRO_DEF,
+                    span,
+                    "`crate` references the macro call's crate",
+                    "to reference the macro definition's crate, use",
+                    "$crate".to_string(),
+                );
+            }
+        }
+    }
+}
+
+/// Returns the span of the first `crate` followed by `::` and not preceded by
+/// `$` in `tts`.
+fn first_crate_path(tts: &TokenStream) -> Option<Span> {
+    let trees: Vec<_> = tts.trees().collect();
+    for (i, tree) in trees.iter().enumerate() {
+        match *tree {
+            TokenTree::Token(span, Token::Ident(ident, false)) if ident.name == keywords::Crate.name() => {
+                let after_dollar = i > 0 && matches!(trees[i - 1], TokenTree::Token(_, token::Dollar));
+                let before_path = matches!(trees.get(i + 1), Some(&TokenTree::Token(_, token::ModSep)));
+                if !after_dollar && before_path {
+                    return Some(span);
+                }
+            },
+            TokenTree::Delimited(_, ref delimited) => {
+                if let Some(span) = first_crate_path(&delimited.stream()) {
+                    return Some(span);
+                }
+            },
+            TokenTree::Token(..) => (),
+        }
+    }
+    None
+}
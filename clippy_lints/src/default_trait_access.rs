@@ -1,6 +1,8 @@
 use rustc::hir::*;
+use rustc::hir::def_id::DefId;
 use rustc::lint::*;
 use rustc::ty::TypeVariants;
+use rustc::ty::subst::Substs;
 
 use crate::utils::{any_parent_is_automatically_derived, match_def_path, opt_def_id, paths, span_lint_and_sugg};
 
@@ -49,8 +51,8 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for DefaultTraitAccess {
                         // TODO: Work out a way to put "whatever the imported way of referencing
                         // this type in this file" rather than a fully-qualified type.
                         let expr_ty = cx.tables.expr_ty(expr);
-                        if let TypeVariants::TyAdt(..) = expr_ty.sty {
-                            let replacement = format!("{}::default()", expr_ty);
+                        if let TypeVariants::TyAdt(def, substs) = expr_ty.sty {
+                            let replacement = format!("{}::default()", adt_path(cx, def.did, substs));
                             span_lint_and_sugg(
                                 cx,
                                 DEFAULT_TRAIT_ACCESS,
@@ -66,3 +68,16 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for DefaultTraitAccess {
          }
     }
 }
+
+/// Renders the path of an ADT in expression position, that is with the generic
+/// arguments in turbofish form (`Foo::<T>`). Lifetimes are left out, they can be
+/// inferred.
+fn adt_path<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, did: DefId, substs: &'tcx Substs<'tcx>) -> String {
+    let path = cx.tcx.item_path_str(did);
+    let types = substs.types().map(|ty| ty.to_string()).collect::<Vec<_>>();
+    if types.is_empty() {
+        path
+    } else {
+        format!("{}::<{}>", path, types.join(", "))
+    }
+}
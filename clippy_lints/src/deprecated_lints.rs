@@ -82,3 +82,12 @@ declare_deprecated_lint! {
     pub MISALIGNED_TRANSMUTE,
     "this lint has been split into cast_ptr_alignment and transmute_ptr_to_ptr"
 }
+
+/// **What it does:** Nothing. This lint has been deprecated.
+///
+/// **Deprecation reason:** This used to check for `Box<Vec<_>>`, it has been
+/// generalized to all boxed collections by box_collection.
+declare_deprecated_lint! {
+    pub BOX_VEC,
+    "this lint has been generalized into box_collection"
+}
@@ -0,0 +1,134 @@
+use rustc::hir::*;
+use rustc::hir::def::Def;
+use rustc::lint::*;
+use rustc::ty;
+use syntax::ast::LitKind;
+use crate::utils::{get_trait_def_id, in_macro, is_integer_literal, match_def_path, opt_def_id, paths, remove_blocks,
+            span_lint_and_then};
+
+/// **What it does:** Detects manual `std::default::Default` implementations
+/// that are identical to a derived implementation.
+///
+/// **Why is this bad?** It is less concise and harder to keep in sync with the
+/// type definition than `#[derive(Default)]`.
+///
+/// **Known problems:** Only bodies made of `Default::default()` calls and the
+/// obvious default literals (`0`, `false`, `""`, `()` and `None`) are
+/// recognized. Generic types are skipped, since the derive adds
+/// `T: Default` bounds the manual implementation may not have.
+///
+/// **Example:**
+/// ```rust
+/// struct Foo {
+///     bar: bool
+/// }
+///
+/// impl Default for Foo {
+///     fn default() -> Self {
+///         Self {
+///             bar: false
+///         }
+///     }
+/// }
+/// ```
+///
+/// Could be written as:
+///
+/// ```rust
+/// #[derive(Default)]
+/// struct Foo {
+///     bar: bool
+/// }
+/// ```
+declare_clippy_lint! {
+    pub DERIVABLE_IMPLS,
+    complexity,
+    "manual implementation of the `Default` trait which is equal to a derive"
+}
+
+#[derive(Copy, Clone)]
+pub struct DerivableImpls;
+
+impl LintPass for DerivableImpls {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(DERIVABLE_IMPLS)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for DerivableImpls {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if_chain! {
+            if let ItemImpl(_, _, _, ref generics, Some(ref trait_ref), _, ref impl_items) = item.node;
+            if !in_macro(item.span);
+            // skips derived impls, and attributes or doc comments hint that the implementation
+            // is intentional
+            if item.attrs.is_empty();
+            if generics.params.is_empty();
+            if let Some(default_trait_id) = get_trait_def_id(cx, &paths::DEFAULT_TRAIT);
+            if trait_ref.path.def.def_id() == default_trait_id;
+            if impl_items.len() == 1;
+            if let ImplItemKind::Method(_, body_id) = cx.tcx.hir.impl_item(impl_items[0].id).node;
+            let self_ty = cx.tcx.type_of(cx.tcx.hir.local_def_id(item.id));
+            if let ty::TyAdt(adt_def, _) = self_ty.sty;
+            if adt_def.is_struct() && adt_def.did.is_local();
+            then {
+                let tables = cx.tcx.body_tables(body_id);
+                let expr = remove_blocks(&cx.tcx.hir.body(body_id).value);
+                let is_derivable = match expr.node {
+                    ExprStruct(_, ref fields, None) => {
+                        fields.iter().all(|field| is_default_equivalent(cx, tables, &field.expr))
+                    },
+                    ExprCall(ref callee, ref args) => {
+                        if let ExprPath(ref qpath) = callee.node {
+                            matches!(tables.qpath_def(qpath, callee.hir_id), Def::StructCtor(..))
+                                && args.iter().all(|arg| is_default_equivalent(cx, tables, arg))
+                        } else {
+                            false
+                        }
+                    },
+                    _ => false,
+                };
+                if is_derivable && tables.expr_ty(expr) == self_ty {
+                    span_lint_and_then(
+                        cx,
+                        DERIVABLE_IMPLS,
+                        item.span,
+                        "this `impl` can be derived",
+                        |db| {
+                            db.span_help(
+                                cx.tcx.def_span(adt_def.did),
+                                "try annotating the type with `#[derive(Default)]`",
+                            );
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Returns true if `expr` evaluates to the value `Default::default()` returns
+/// for its type.
+fn is_default_equivalent<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, tables: &ty::TypeckTables<'tcx>, expr: &Expr) -> bool {
+    match expr.node {
+        ExprCall(ref callee, ref args) if args.is_empty() => {
+            if let ExprPath(ref qpath) = callee.node {
+                opt_def_id(tables.qpath_def(qpath, callee.hir_id))
+                    .map_or(false, |def_id| match_def_path(cx.tcx, def_id, &paths::DEFAULT_TRAIT_METHOD))
+            } else {
+                false
+            }
+        },
+        ExprPath(ref qpath) => match tables.qpath_def(qpath, expr.hir_id) {
+            Def::VariantCtor(def_id, ..) => match_def_path(cx.tcx, def_id, &paths::OPTION_NONE),
+            _ => false,
+        },
+        ExprLit(ref lit) => match lit.node {
+            LitKind::Bool(value) => !value,
+            LitKind::Str(value, _) => value.as_str().is_empty(),
+            _ => is_integer_literal(expr, 0),
+        },
+        ExprTup(ref exprs) => exprs.iter().all(|expr| is_default_equivalent(cx, tables, expr)),
+        _ => false,
+    }
+}
@@ -3,7 +3,8 @@ use rustc::ty::{self, Ty};
 use rustc::hir::*;
 use syntax::codemap::Span;
 use crate::utils::paths;
-use crate::utils::{is_automatically_derived, is_copy, match_path, span_lint_and_then};
+use crate::utils::{get_trait_def_id, implements_trait, is_automatically_derived, is_copy, match_path,
+            span_lint_and_sugg, span_lint_and_then};
 
 /// **What it does:** Checks for deriving `Hash` but implementing `PartialEq`
 /// explicitly or vice versa.
@@ -60,11 +61,45 @@ declare_clippy_lint! {
     "implementing `Clone` explicitly on `Copy` types"
 }
 
+/// **What it does:** Checks for types that derive `PartialEq` and could
+/// implement `Eq`.
+///
+/// **Why is this bad?** If a type `T` derives `PartialEq` and all of its
+/// members implement `Eq`, then `T` can always implement `Eq`. Implementing
+/// `Eq` allows `T` to be used in APIs that require `Eq` types. It also allows
+/// structs containing `T` to derive `Eq` themselves.
+///
+/// **Known problems:** Generic types are only linted when their type
+/// parameters are already required to be `Eq`, since `#[derive(Eq)]` would
+/// otherwise add a bound the type may not want.
+///
+/// **Example:**
+/// ```rust
+/// #[derive(PartialEq)]
+/// struct Foo {
+///     i_am_eq: i32,
+///     i_am_eq_too: Vec<String>,
+/// }
+/// ```
+/// Use instead:
+/// ```rust
+/// #[derive(PartialEq, Eq)]
+/// struct Foo {
+///     i_am_eq: i32,
+///     i_am_eq_too: Vec<String>,
+/// }
+/// ```
+declare_clippy_lint! {
+    pub DERIVE_PARTIAL_EQ_WITHOUT_EQ,
+    pedantic,
+    "deriving `PartialEq` on a type that can implement `Eq`, without implementing `Eq`"
+}
+
 pub struct Derive;
 
 impl LintPass for Derive {
     fn get_lints(&self) -> LintArray {
-        lint_array!(EXPL_IMPL_CLONE_ON_COPY, DERIVE_HASH_XOR_EQ)
+        lint_array!(EXPL_IMPL_CLONE_ON_COPY, DERIVE_HASH_XOR_EQ, DERIVE_PARTIAL_EQ_WITHOUT_EQ)
     }
 }
 
@@ -76,7 +111,9 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Derive {
 
             check_hash_peq(cx, item.span, trait_ref, ty, is_automatically_derived);
 
-            if !is_automatically_derived {
+            if is_automatically_derived {
+                check_partial_eq_without_eq(cx, item.span, trait_ref, ty);
+            } else {
                 check_copy_clone(cx, item, trait_ref, ty);
             }
         }
@@ -170,3 +207,27 @@ fn check_copy_clone<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, item: &Item, trait_ref
         );
     }
 }
+
+/// Implementation of the `DERIVE_PARTIAL_EQ_WITHOUT_EQ` lint.
+fn check_partial_eq_without_eq<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, span: Span, trait_ref: &TraitRef, ty: Ty<'tcx>) {
+    if_chain! {
+        if let ty::TyAdt(adt, substs) = ty.sty;
+        if let Some(peq_trait_def_id) = cx.tcx.lang_items().eq_trait();
+        if trait_ref.path.def.def_id() == peq_trait_def_id;
+        if let Some(eq_trait_def_id) = get_trait_def_id(cx, &paths::EQ_TRAIT);
+        if !implements_trait(cx, ty, eq_trait_def_id, &[]);
+        // fields of generic types are checked against the bounds of the impl, so type parameters
+        // which are not required to be `Eq` keep the lint silent
+        if adt.all_fields().all(|field| implements_trait(cx, field.ty(cx.tcx, substs), eq_trait_def_id, &[]));
+        then {
+            span_lint_and_sugg(
+                cx,
+                DERIVE_PARTIAL_EQ_WITHOUT_EQ,
+                span,
+                "you are deriving `PartialEq` and can implement `Eq`",
+                "consider deriving `Eq` as well",
+                "PartialEq, Eq".to_string(),
+            );
+        }
+    }
+}
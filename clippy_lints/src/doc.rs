@@ -3,8 +3,9 @@ use pulldown_cmark;
 use rustc::lint::*;
 use syntax::ast;
 use syntax::codemap::{BytePos, Span};
+use syntax::visit::{self, Visitor};
 use syntax_pos::Pos;
-use crate::utils::span_lint;
+use crate::utils::{in_macro, is_expn_of, match_path_ast, span_lint, span_note_and_lint};
 use url::Url;
 
 /// **What it does:** Checks for the presence of `_`, `::` or camel-case words
@@ -31,6 +32,74 @@ declare_clippy_lint! {
     "presence of `_`, `::` or camel-case outside backticks in documentation"
 }
 
+/// **What it does:** Checks the doc comments of publicly visible functions that
+/// return a `Result` type and warns if there is no `# Errors` section.
+///
+/// **Why is this bad?** Documenting the type of errors that can be returned from a
+/// function can help callers write code to handle the errors appropriately.
+///
+/// **Known problems:** The return type is checked syntactically, so type aliases
+/// named anything other than `Result` are not recognized. Trait methods are not
+/// checked.
+///
+/// **Examples:**
+/// ```rust
+/// // Bad
+/// /// Reads the configuration file.
+/// pub fn read_config(path: &Path) -> io::Result<Config> { .. }
+///
+/// // Good
+/// /// Reads the configuration file.
+/// ///
+/// /// # Errors
+/// ///
+/// /// Will return `Err` if `path` does not exist or the user does not have
+/// /// permission to read it.
+/// pub fn read_config(path: &Path) -> io::Result<Config> { .. }
+/// ```
+declare_clippy_lint! {
+    pub MISSING_ERRORS_DOC,
+    pedantic,
+    "`pub fn` returns `Result` without `# Errors` in doc comment"
+}
+
+/// **What it does:** Checks the doc comments of publicly visible functions that
+/// may panic and warns if there is no `# Panics` section.
+///
+/// **Why is this bad?** Documenting the scenarios in which panicking occurs
+/// can help callers who do not want to panic to avoid those situations.
+///
+/// **Known problems:** Calls to `unwrap`, `expect` and indexing are recognized
+/// by name only, so methods of the same name on other types are reported too.
+/// Panics happening in called functions are not detected. `unreachable!`,
+/// `unimplemented!` and the `debug_assert!` family are ignored.
+///
+/// **Examples:**
+/// ```rust
+/// // Bad
+/// /// Divides `x` by `y`.
+/// pub fn divide_by(x: i32, y: i32) -> i32 {
+///     if y == 0 {
+///         panic!("Cannot divide by 0")
+///     } else {
+///         x / y
+///     }
+/// }
+///
+/// // Good
+/// /// Divides `x` by `y`.
+/// ///
+/// /// # Panics
+/// ///
+/// /// Will panic if `y` is 0
+/// pub fn divide_by(x: i32, y: i32) -> i32 { .. }
+/// ```
+declare_clippy_lint! {
+    pub MISSING_PANICS_DOC,
+    pedantic,
+    "`pub fn` may panic without `# Panics` in doc comment"
+}
+
 #[derive(Clone)]
 pub struct Doc {
     valid_idents: Vec<String>,
@@ -46,7 +115,7 @@ impl Doc {
 
 impl LintPass for Doc {
     fn get_lints(&self) -> LintArray {
-        lint_array![DOC_MARKDOWN]
+        lint_array![DOC_MARKDOWN, MISSING_ERRORS_DOC, MISSING_PANICS_DOC]
     }
 }
 
@@ -56,8 +125,119 @@ impl EarlyLintPass for Doc {
     }
 
     fn check_item(&mut self, cx: &EarlyContext, item: &ast::Item) {
-        check_attrs(cx, &self.valid_idents, &item.attrs);
+        let headers = check_attrs(cx, &self.valid_idents, &item.attrs);
+        if let ast::ItemKind::Fn(ref decl, _, _, ref body) = item.node {
+            if item.vis.node == ast::VisibilityKind::Public {
+                lint_for_missing_headers(cx, decl, body, item.span, headers);
+            }
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &EarlyContext, item: &ast::ImplItem) {
+        let headers = check_attrs(cx, &self.valid_idents, &item.attrs);
+        // methods of trait impls inherit their visibility and are never `pub` here
+        if let ast::ImplItemKind::Method(ref sig, ref body) = item.node {
+            if item.vis.node == ast::VisibilityKind::Public {
+                lint_for_missing_headers(cx, &sig.decl, body, item.span, headers);
+            }
+        }
+    }
+}
+
+/// The sections found in a doc comment, as reported by `check_attrs`.
+#[derive(Copy, Clone, Default)]
+pub struct DocHeaders {
+    errors: bool,
+    panics: bool,
+}
+
+fn lint_for_missing_headers(
+    cx: &EarlyContext,
+    decl: &ast::FnDecl,
+    body: &ast::Block,
+    span: Span,
+    headers: DocHeaders,
+) {
+    if !headers.errors {
+        if let ast::FunctionRetTy::Ty(ref ty) = decl.output {
+            if let ast::TyKind::Path(None, ref path) = ty.node {
+                if match_path_ast(path, &["Result"]) {
+                    span_lint(
+                        cx,
+                        MISSING_ERRORS_DOC,
+                        span,
+                        "docs for function returning `Result` missing `# Errors` section",
+                    );
+                }
+            }
+        }
     }
+
+    if !headers.panics {
+        let mut finder = FindPanicUnwrap { result: None };
+        finder.visit_block(body);
+        if let Some(panic_span) = finder.result {
+            span_note_and_lint(
+                cx,
+                MISSING_PANICS_DOC,
+                span,
+                "docs for function which may panic missing `# Panics` section",
+                panic_span,
+                "first possible panic found here",
+            );
+        }
+    }
+}
+
+/// Finds the first expression of a function body that may panic.
+struct FindPanicUnwrap {
+    result: Option<Span>,
+}
+
+impl<'ast> Visitor<'ast> for FindPanicUnwrap {
+    fn visit_expr(&mut self, expr: &'ast ast::Expr) {
+        if self.result.is_some() {
+            return;
+        }
+
+        // these are either never supposed to be reached or only run in debug builds
+        const IGNORED_MACROS: [&str; 5] = [
+            "unreachable",
+            "unimplemented",
+            "debug_assert",
+            "debug_assert_eq",
+            "debug_assert_ne",
+        ];
+        if IGNORED_MACROS.iter().any(|name| is_expn_of(expr.span, name).is_some()) {
+            return;
+        }
+
+        if is_expn_of(expr.span, "panic").is_some() {
+            self.result = Some(expr.span.source_callsite());
+            return;
+        }
+
+        if !in_macro(expr.span) {
+            match expr.node {
+                ast::ExprKind::MethodCall(ref path, _)
+                    if path.ident.name == "unwrap" || path.ident.name == "expect" =>
+                {
+                    self.result = Some(expr.span);
+                    return;
+                },
+                ast::ExprKind::Index(..) => {
+                    self.result = Some(expr.span);
+                    return;
+                },
+                _ => (),
+            }
+        }
+
+        visit::walk_expr(self, expr);
+    }
+
+    // nested items have their own docs
+    fn visit_item(&mut self, _: &'ast ast::Item) {}
 }
 
 struct Parser<'a> {
@@ -138,7 +318,7 @@ pub fn strip_doc_comment_decoration(comment: &str, span: Span) -> (String, Vec<(
     panic!("not a doc-comment: {}", comment);
 }
 
-pub fn check_attrs<'a>(cx: &EarlyContext, valid_idents: &[String], attrs: &'a [ast::Attribute]) {
+pub fn check_attrs<'a>(cx: &EarlyContext, valid_idents: &[String], attrs: &'a [ast::Attribute]) -> DocHeaders {
     let mut doc = String::new();
     let mut spans = vec![];
 
@@ -151,8 +331,12 @@ pub fn check_attrs<'a>(cx: &EarlyContext, valid_idents: &[String], attrs: &'a [a
                 doc.push_str(&current);
             }
         } else if attr.name() == "doc" {
-            // ignore mix of sugared and non-sugared doc
-            return;
+            // ignore mix of sugared and non-sugared doc, and don't ask for
+            // sections we can't see
+            return DocHeaders {
+                errors: true,
+                panics: true,
+            };
         }
     }
 
@@ -163,25 +347,27 @@ pub fn check_attrs<'a>(cx: &EarlyContext, valid_idents: &[String], attrs: &'a [a
         current += offset_copy;
     }
 
-    if !doc.is_empty() {
-        let parser = Parser::new(pulldown_cmark::Parser::new(&doc));
-        let parser = parser.coalesce(|x, y| {
-            use pulldown_cmark::Event::*;
+    if doc.is_empty() {
+        return DocHeaders::default();
+    }
 
-            let x_offset = x.0;
-            let y_offset = y.0;
+    let parser = Parser::new(pulldown_cmark::Parser::new(&doc));
+    let parser = parser.coalesce(|x, y| {
+        use pulldown_cmark::Event::*;
 
-            match (x.1, y.1) {
-                (Text(x), Text(y)) => {
-                    let mut x = x.into_owned();
-                    x.push_str(&y);
-                    Ok((x_offset, Text(x.into())))
-                },
-                (x, y) => Err(((x_offset, x), (y_offset, y))),
-            }
-        });
-        check_doc(cx, valid_idents, parser, &spans);
-    }
+        let x_offset = x.0;
+        let y_offset = y.0;
+
+        match (x.1, y.1) {
+            (Text(x), Text(y)) => {
+                let mut x = x.into_owned();
+                x.push_str(&y);
+                Ok((x_offset, Text(x.into())))
+            },
+            (x, y) => Err(((x_offset, x), (y_offset, y))),
+        }
+    });
+    check_doc(cx, valid_idents, parser, &spans)
 }
 
 fn check_doc<'a, Events: Iterator<Item = (usize, pulldown_cmark::Event<'a>)>>(
@@ -189,12 +375,14 @@ fn check_doc<'a, Events: Iterator<Item = (usize, pulldown_cmark::Event<'a>)>>(
     valid_idents: &[String],
     docs: Events,
     spans: &[(usize, Span)],
-) {
+) -> DocHeaders {
     use pulldown_cmark::Event::*;
     use pulldown_cmark::Tag::*;
 
+    let mut headers = DocHeaders::default();
     let mut in_code = false;
     let mut in_link = None;
+    let mut in_heading = false;
 
     for (offset, event) in docs {
         match event {
@@ -202,6 +390,8 @@ fn check_doc<'a, Events: Iterator<Item = (usize, pulldown_cmark::Event<'a>)>>(
             End(CodeBlock(_)) | End(Code) => in_code = false,
             Start(Link(link, _)) => in_link = Some(link),
             End(Link(_, _)) => in_link = None,
+            Start(Header(_)) => in_heading = true,
+            End(Header(_)) => in_heading = false,
             Start(_tag) | End(_tag) => (),         // We don't care about other tags
             Html(_html) | InlineHtml(_html) => (), // HTML is weird, just ignore it
             SoftBreak | HardBreak => (),
@@ -213,6 +403,11 @@ fn check_doc<'a, Events: Iterator<Item = (usize, pulldown_cmark::Event<'a>)>>(
                     continue;
                 }
 
+                if in_heading {
+                    headers.errors |= text.trim() == "Errors";
+                    headers.panics |= text.trim() == "Panics";
+                }
+
                 if !in_code {
                     let index = match spans.binary_search_by(|c| c.0.cmp(&offset)) {
                         Ok(o) => o,
@@ -229,6 +424,8 @@ fn check_doc<'a, Events: Iterator<Item = (usize, pulldown_cmark::Event<'a>)>>(
             },
         }
     }
+
+    headers
 }
 
 fn check_text(cx: &EarlyContext, valid_idents: &[String], text: &str, span: Span) {
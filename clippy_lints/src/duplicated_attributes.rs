@@ -0,0 +1,159 @@
+use rustc::lint::*;
+use std::collections::HashMap;
+use syntax::ast::*;
+use syntax::codemap::Span;
+use syntax::print::pprust;
+use crate::utils::{in_macro, span_note_and_lint};
+
+/// **What it does:** Checks for attributes that appear more than once on the
+/// same item, including the same lint in lint level attributes like
+/// `#[allow(..)]`, and for lint levels already set to the same level by a
+/// parent item.
+///
+/// **Why is this bad?** The repeated attribute has no effect, and is usually
+/// left over from a merge or a refactoring.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// #[allow(dead_code)]
+/// mod foo {
+///     #[allow(dead_code)]
+///     #[inline]
+///     #[inline]
+///     fn bar() {}
+/// }
+/// ```
+declare_clippy_lint! {
+    pub DUPLICATED_ATTRIBUTES,
+    style,
+    "duplicated attribute"
+}
+
+/// The lint level attributes set by an item, by lint name.
+type LintLevels = HashMap<String, (String, Span)>;
+
+#[derive(Default)]
+pub struct DuplicatedAttributes {
+    /// the lint levels of all the items containing the current item
+    parents: Vec<LintLevels>,
+}
+
+impl LintPass for DuplicatedAttributes {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(DUPLICATED_ATTRIBUTES)
+    }
+}
+
+impl DuplicatedAttributes {
+    /// Lints duplicated attributes, and returns the lint levels set by `attrs`.
+    fn check_attrs(&self, cx: &EarlyContext, attrs: &[Attribute]) -> LintLevels {
+        let mut seen: HashMap<String, Span> = HashMap::new();
+        let mut levels = LintLevels::new();
+        for attr in attrs {
+            if attr.is_sugared_doc || attr.check_name("doc") || in_macro(attr.span) {
+                continue;
+            }
+            let level = attr.name().as_str();
+            let lints = match attr.meta_item_list() {
+                Some(lints) if ["allow", "warn", "deny", "forbid"].contains(&&*level) => lints,
+                _ => {
+                    check_duplicate(cx, &mut seen, pprust::attribute_to_string(attr), attr.span, "attribute");
+                    continue;
+                },
+            };
+            for lint in &lints {
+                let name = pprust::meta_list_item_to_string(lint);
+                check_duplicate(cx, &mut seen, format!("{}({})", level, name), lint.span, "lint name");
+                if let Some(&(ref parent_level, parent_span)) = self.effective_level(&name) {
+                    if *parent_level == *level {
+                        span_note_and_lint(
+                            cx,
+                            DUPLICATED_ATTRIBUTES,
+                            lint.span,
+                            &format!("`{}({})` is already set by a parent item", level, name),
+                            parent_span,
+                            "first set here",
+                        );
+                    }
+                }
+                levels.insert(name, (level.to_string(), lint.span));
+            }
+        }
+        levels
+    }
+
+    /// Returns the level set for `lint` by the innermost parent setting it.
+    fn effective_level(&self, lint: &str) -> Option<&(String, Span)> {
+        self.parents.iter().rev().find_map(|levels| levels.get(lint))
+    }
+}
+
+fn check_duplicate(cx: &EarlyContext, seen: &mut HashMap<String, Span>, key: String, span: Span, kind: &str) {
+    if let Some(&first) = seen.get(&key) {
+        span_note_and_lint(
+            cx,
+            DUPLICATED_ATTRIBUTES,
+            span,
+            &format!("duplicated {}", kind),
+            first,
+            "first defined here",
+        );
+    } else {
+        seen.insert(key, span);
+    }
+}
+
+impl EarlyLintPass for DuplicatedAttributes {
+    fn check_crate(&mut self, cx: &EarlyContext, krate: &Crate) {
+        let levels = self.check_attrs(cx, &krate.attrs);
+        self.parents.push(levels);
+    }
+
+    fn check_item(&mut self, cx: &EarlyContext, item: &Item) {
+        let levels = self.check_attrs(cx, &item.attrs);
+        self.parents.push(levels);
+    }
+
+    fn check_item_post(&mut self, _: &EarlyContext, _: &Item) {
+        self.parents.pop();
+    }
+
+    fn check_impl_item(&mut self, cx: &EarlyContext, item: &ImplItem) {
+        let levels = self.check_attrs(cx, &item.attrs);
+        self.parents.push(levels);
+    }
+
+    fn check_impl_item_post(&mut self, _: &EarlyContext, _: &ImplItem) {
+        self.parents.pop();
+    }
+
+    fn check_trait_item(&mut self, cx: &EarlyContext, item: &TraitItem) {
+        let levels = self.check_attrs(cx, &item.attrs);
+        self.parents.push(levels);
+    }
+
+    fn check_trait_item_post(&mut self, _: &EarlyContext, _: &TraitItem) {
+        self.parents.pop();
+    }
+
+    fn check_variant(&mut self, cx: &EarlyContext, variant: &Variant, _: &Generics) {
+        let levels = self.check_attrs(cx, &variant.node.attrs);
+        self.parents.push(levels);
+    }
+
+    fn check_variant_post(&mut self, _: &EarlyContext, _: &Variant, _: &Generics) {
+        self.parents.pop();
+    }
+
+    // fields and locals contain no items, so their lint levels don't need to be kept
+
+    fn check_struct_field(&mut self, cx: &EarlyContext, field: &StructField) {
+        self.check_attrs(cx, &field.attrs);
+    }
+
+    fn check_local(&mut self, cx: &EarlyContext, local: &Local) {
+        self.check_attrs(cx, &local.attrs);
+    }
+}
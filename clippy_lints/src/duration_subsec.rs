@@ -1,10 +1,11 @@
 use rustc::hir::*;
 use rustc::lint::*;
+use semver::Version;
 use syntax::codemap::Spanned;
 
 use crate::consts::{constant, Constant};
 use crate::utils::paths;
-use crate::utils::{match_type, snippet, span_lint_and_sugg, walk_ptrs_ty};
+use crate::utils::{match_type, meets_msrv, snippet, span_lint_and_sugg, walk_ptrs_ty};
 
 /// **What it does:** Checks for calculation of subsecond microseconds or milliseconds
 /// from other `Duration` methods.
@@ -12,7 +13,9 @@ use crate::utils::{match_type, snippet, span_lint_and_sugg, walk_ptrs_ty};
 /// **Why is this bad?** It's more concise to call `Duration::subsec_micros()` or
 /// `Duration::subsec_millis()` than to calculate them.
 ///
-/// **Known problems:** None.
+/// **Known problems:** `subsec_micros` and `subsec_millis` are only available
+/// since Rust 1.27, set the `msrv` configuration if the crate supports older
+/// versions.
 ///
 /// **Example:**
 /// ```rust
@@ -26,8 +29,15 @@ declare_clippy_lint! {
     "checks for calculation of subsecond microseconds or milliseconds"
 }
 
-#[derive(Copy, Clone)]
-pub struct DurationSubsec;
+pub struct DurationSubsec {
+    msrv: Option<Version>,
+}
+
+impl DurationSubsec {
+    pub fn new(msrv: Option<Version>) -> Self {
+        Self { msrv }
+    }
+}
 
 impl LintPass for DurationSubsec {
     fn get_lints(&self) -> LintArray {
@@ -42,6 +52,7 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for DurationSubsec {
             if let ExprMethodCall(ref method_path, _ , ref args) = left.node;
             if match_type(cx, walk_ptrs_ty(cx.tables.expr_ty(&args[0])), &paths::DURATION);
             if let Some((Constant::Int(divisor), _)) = constant(cx, cx.tables, right);
+            if meets_msrv(self.msrv.as_ref(), &Version::new(1, 27, 0));
             then {
                 let suggested_fn = match (method_path.ident.as_str().as_ref(), divisor) {
                     ("subsec_micros", 1_000) => "subsec_millis",
@@ -0,0 +1,158 @@
+use rustc::lint::*;
+use syntax::ast::*;
+use syntax::codemap::Span;
+use rustc_target::spec::abi::Abi;
+use crate::utils::{in_macro, match_path_ast, span_help_and_lint};
+
+/// **What it does:** Checks for excessive use of bools in structs.
+///
+/// **Why is this bad?** Such structs are often a state machine in disguise:
+/// most combinations of the flags are meaningless, and the compiler can't
+/// help keep them consistent. An enum of the valid states is usually clearer.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// // Bad
+/// struct S {
+///     is_pending: bool,
+///     is_processing: bool,
+///     is_finished: bool,
+///     is_cancelled: bool,
+/// }
+///
+/// // Good
+/// enum S {
+///     Pending,
+///     Processing,
+///     Finished,
+///     Cancelled,
+/// }
+/// ```
+declare_clippy_lint! {
+    pub STRUCT_EXCESSIVE_BOOLS,
+    pedantic,
+    "using too many bools in a struct"
+}
+
+/// **What it does:** Checks for functions taking more `bool` parameters than a
+/// configurable threshold.
+///
+/// **Why is this bad?** Call sites like `render(true, false, true)` are hard to
+/// read and easy to get wrong. Two-variant enums or a configuration struct
+/// make the meaning of every argument explicit.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// // Bad
+/// fn f(is_round: bool, is_hot: bool, is_blue: bool, is_large: bool) { .. }
+///
+/// // Good
+/// enum Shape { Round, Spiky }
+/// enum Temperature { Hot, Cold }
+/// fn f(shape: Shape, temperature: Temperature, ..) { .. }
+/// ```
+declare_clippy_lint! {
+    pub FN_PARAMS_EXCESSIVE_BOOLS,
+    pedantic,
+    "using too many bools in function parameters"
+}
+
+#[derive(Copy, Clone)]
+pub struct ExcessiveBools {
+    max_struct_bools: u64,
+    max_fn_params_bools: u64,
+}
+
+impl ExcessiveBools {
+    pub fn new(max_struct_bools: u64, max_fn_params_bools: u64) -> Self {
+        Self {
+            max_struct_bools,
+            max_fn_params_bools,
+        }
+    }
+
+    fn check_fn_decl(self, cx: &EarlyContext, decl: &FnDecl, span: Span) {
+        let bools = decl.inputs.iter().filter(|arg| is_bool_ty(&arg.ty)).count() as u64;
+        if bools > self.max_fn_params_bools {
+            span_help_and_lint(
+                cx,
+                FN_PARAMS_EXCESSIVE_BOOLS,
+                span,
+                &format!("more than {} bools in function parameters", self.max_fn_params_bools),
+                "consider refactoring bools into two-variant enums",
+            );
+        }
+    }
+}
+
+impl LintPass for ExcessiveBools {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(STRUCT_EXCESSIVE_BOOLS, FN_PARAMS_EXCESSIVE_BOOLS)
+    }
+}
+
+fn is_bool_ty(ty: &Ty) -> bool {
+    if let TyKind::Path(None, ref path) = ty.node {
+        return match_path_ast(path, &["bool"]);
+    }
+    false
+}
+
+/// Returns true for `#[repr(C)]` types, whose layout is usually dictated by FFI.
+fn is_repr_c(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        attr.check_name("repr")
+            && attr.meta_item_list()
+                .map_or(false, |items| items.iter().any(|item| item.check_name("C")))
+    })
+}
+
+impl EarlyLintPass for ExcessiveBools {
+    fn check_item(&mut self, cx: &EarlyContext, item: &Item) {
+        if in_macro(item.span) {
+            return;
+        }
+        match item.node {
+            ItemKind::Struct(ref variant_data, _) => {
+                if is_repr_c(&item.attrs) {
+                    return;
+                }
+
+                let bools = variant_data.fields().iter().filter(|field| is_bool_ty(&field.ty)).count() as u64;
+                if bools > self.max_struct_bools {
+                    span_help_and_lint(
+                        cx,
+                        STRUCT_EXCESSIVE_BOOLS,
+                        item.span,
+                        &format!("more than {} bools in a struct", self.max_struct_bools),
+                        "consider using a state machine or refactoring bools into two-variant enums",
+                    );
+                }
+            },
+            // extern functions have their signature dictated by the other side
+            ItemKind::Fn(ref decl, ref header, _, _) if header.abi == Abi::Rust => {
+                self.check_fn_decl(cx, decl, item.span);
+            },
+            // trait implementations can't choose their signature, only the trait can be blamed
+            ItemKind::Impl(_, _, _, _, None, _, ref items) => for impl_item in items {
+                if let ImplItemKind::Method(ref sig, _) = impl_item.node {
+                    if sig.header.abi == Abi::Rust {
+                        self.check_fn_decl(cx, &sig.decl, impl_item.span);
+                    }
+                }
+            },
+            ItemKind::Trait(_, _, _, _, ref items) => for trait_item in items {
+                if let TraitItemKind::Method(ref sig, _) = trait_item.node {
+                    if sig.header.abi == Abi::Rust {
+                        self.check_fn_decl(cx, &sig.decl, trait_item.span);
+                    }
+                }
+            },
+            _ => (),
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use rustc::hir::*;
+use rustc::lint::*;
+use syntax::attr;
+use crate::utils::{in_macro, span_lint_and_then};
+use crate::utils::sugg::DiagnosticBuilderExt;
+
+/// **What it does:** Warns on any exported `enum`s that are not tagged
+/// `#[non_exhaustive]`.
+///
+/// **Why is this bad?** Exhaustive enums are typically fine, but a project
+/// which does not wish to make a stability commitment around exported enums
+/// may wish to disable them by default.
+///
+/// **Known problems:** `#[non_exhaustive]` is unstable, so the lint only
+/// triggers if the `non_exhaustive` feature is enabled.
+///
+/// **Example:**
+/// ```rust
+/// enum Foo {
+///     Bar,
+///     Baz
+/// }
+/// ```
+/// Use instead:
+/// ```rust
+/// #[non_exhaustive]
+/// enum Foo {
+///     Bar,
+///     Baz
+/// }
+/// ```
+declare_clippy_lint! {
+    pub EXHAUSTIVE_ENUMS,
+    restriction,
+    "detects exported enums that have not been marked #[non_exhaustive]"
+}
+
+/// **What it does:** Warns on any exported `struct`s that are not tagged
+/// `#[non_exhaustive]`.
+///
+/// **Why is this bad?** Exhaustive structs are typically fine, but a project
+/// which does not wish to make a stability commitment around exported structs
+/// may wish to disable them by default.
+///
+/// **Known problems:** `#[non_exhaustive]` is unstable, so the lint only
+/// triggers if the `non_exhaustive` feature is enabled. Structs with private
+/// fields can't be constructed or destructured exhaustively outside of their
+/// crate, so they are not linted.
+///
+/// **Example:**
+/// ```rust
+/// struct Foo {
+///     bar: u8,
+///     baz: String,
+/// }
+/// ```
+/// Use instead:
+/// ```rust
+/// #[non_exhaustive]
+/// struct Foo {
+///     bar: u8,
+///     baz: String,
+/// }
+/// ```
+declare_clippy_lint! {
+    pub EXHAUSTIVE_STRUCTS,
+    restriction,
+    "detects exported structs that have not been marked #[non_exhaustive]"
+}
+
+#[derive(Copy, Clone)]
+pub struct ExhaustiveItems;
+
+impl LintPass for ExhaustiveItems {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(EXHAUSTIVE_ENUMS, EXHAUSTIVE_STRUCTS)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ExhaustiveItems {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if !cx.tcx.features().non_exhaustive
+            || in_macro(item.span)
+            || !cx.access_levels.is_exported(item.id)
+            || attr::contains_name(&item.attrs, "non_exhaustive")
+        {
+            return;
+        }
+        let (lint, msg) = match item.node {
+            ItemEnum(..) => (EXHAUSTIVE_ENUMS, "exported enums should not be exhaustive"),
+            ItemStruct(ref data, _) => {
+                if data.fields().iter().any(|field| field.vis.node != VisibilityKind::Public) {
+                    return;
+                }
+                (EXHAUSTIVE_STRUCTS, "exported structs should not be exhaustive")
+            },
+            _ => return,
+        };
+        span_lint_and_then(cx, lint, cx.tcx.def_span(cx.tcx.hir.local_def_id(item.id)), msg, |db| {
+            db.suggest_item_with_attr(cx, item.span, "try adding #[non_exhaustive]", "#[non_exhaustive]");
+        });
+    }
+}
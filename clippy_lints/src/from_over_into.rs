@@ -0,0 +1,193 @@
+use rustc::hir::*;
+use rustc::hir::def::Def;
+use rustc::hir::intravisit::{walk_expr, walk_path, NestedVisitorMap, Visitor};
+use rustc::lint::*;
+use syntax::ast::NodeId;
+use syntax::symbol::keywords;
+use syntax_pos::Span;
+use crate::utils::{in_macro, match_path, multispan_sugg, paths, snippet_opt, span_lint_and_then};
+
+/// **What it does:** Searches for implementations of the `Into<..>` trait and
+/// suggests to implement `From<..>` instead.
+///
+/// **Why is this bad?** According to the std docs, implementing `From<..>` is
+/// preferred since it gives you `Into<..>` for free where the reverse isn't
+/// true.
+///
+/// **Known problems:** Implementations into a generic type parameter are not
+/// checked, since `From` can't be implemented for them.
+///
+/// **Example:**
+/// ```rust
+/// struct StringWrapper(String);
+///
+/// impl Into<StringWrapper> for String {
+///     fn into(self) -> StringWrapper {
+///         StringWrapper(self)
+///     }
+/// }
+/// ```
+/// Use instead:
+/// ```rust
+/// struct StringWrapper(String);
+///
+/// impl From<String> for StringWrapper {
+///     fn from(val: String) -> StringWrapper {
+///         StringWrapper(val)
+///     }
+/// }
+/// ```
+declare_clippy_lint! {
+    pub FROM_OVER_INTO,
+    style,
+    "Warns on implementations of `Into<..>` to use `From<..>`"
+}
+
+#[derive(Copy, Clone)]
+pub struct FromOverInto;
+
+impl LintPass for FromOverInto {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(FROM_OVER_INTO)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for FromOverInto {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if in_macro(item.span) {
+            return;
+        }
+        if_chain! {
+            if let ItemImpl(_, _, _, _, Some(ref trait_ref), ref self_ty, ref impl_items) = item.node;
+            if match_path(&trait_ref.path, &paths::INTO);
+            if let Some(&PathSegment { args: Some(ref params), .. }) = trait_ref.path.segments.last();
+            if let Some(&GenericArg::Type(ref target_ty)) = params.args.iter().next();
+            if !is_ty_param(target_ty);
+            then {
+                span_lint_and_then(
+                    cx,
+                    FROM_OVER_INTO,
+                    cx.tcx.def_span(cx.tcx.hir.local_def_id(item.id)),
+                    "an implementation of `From` is preferred since it gives you `Into<_>` for free where the \
+                     reverse isn't true",
+                    |db| {
+                        let suggestions = impl_items
+                            .iter()
+                            .find(|impl_item| impl_item.ident.name == "into")
+                            .and_then(|impl_item| convert_to_from(cx, trait_ref, self_ty, target_ty, impl_item));
+                        if let (Some(suggestions), Some(self_ty)) = (suggestions, snippet_opt(cx, self_ty.span)) {
+                            multispan_sugg(
+                                db,
+                                format!("replace the `Into` implementation with `From<{}>`", self_ty),
+                                suggestions,
+                            );
+                        } else {
+                            db.help("replace the `Into` implementation with `From<..>`");
+                        }
+                    },
+                );
+            }
+        }
+    }
+}
+
+fn is_ty_param(ty: &Ty) -> bool {
+    if let TyPath(QPath::Resolved(None, ref path)) = ty.node {
+        if let Def::TyParam(..) = path.def {
+            return true;
+        }
+    }
+    false
+}
+
+/// Builds the replacements turning the `Into` impl into a `From` impl, or
+/// `None` if the method body can't be rewritten reliably.
+fn convert_to_from(
+    cx: &LateContext,
+    trait_ref: &TraitRef,
+    self_ty: &Ty,
+    target_ty: &Ty,
+    impl_item: &ImplItemRef,
+) -> Option<Vec<(Span, String)>> {
+    let impl_item = cx.tcx.hir.impl_item(impl_item.id);
+    let (sig, body_id) = match impl_item.node {
+        ImplItemKind::Method(ref sig, body_id) => (sig, body_id),
+        _ => return None,
+    };
+    if !sig.decl.has_implicit_self {
+        return None;
+    }
+    let body = cx.tcx.hir.body(body_id);
+    let self_arg = body.arguments.first()?;
+    let (self_id, self_mut) = match self_arg.pat.node {
+        PatKind::Binding(BindingAnnotation::Unannotated, id, ..) => (id, ""),
+        PatKind::Binding(BindingAnnotation::Mutable, id, ..) => (id, "mut "),
+        _ => return None,
+    };
+
+    let self_ty_snip = snippet_opt(cx, self_ty.span)?;
+    let target_ty_snip = snippet_opt(cx, target_ty.span)?;
+
+    let mut finder = SelfFinder {
+        self_id,
+        self_uses: Vec::new(),
+        self_ty_uses: Vec::new(),
+        invalid: false,
+    };
+    finder.visit_expr(&body.value);
+    if finder.invalid {
+        return None;
+    }
+
+    let mut suggestions = vec![
+        (trait_ref.path.span, format!("From<{}>", self_ty_snip)),
+        (self_ty.span, target_ty_snip),
+        (impl_item.ident.span, "from".to_string()),
+        (self_arg.pat.span, format!("{}val: {}", self_mut, self_ty_snip)),
+    ];
+    suggestions.extend(finder.self_uses.into_iter().map(|span| (span, "val".to_string())));
+    suggestions.extend(finder.self_ty_uses.into_iter().map(|span| (span, self_ty_snip.clone())));
+    Some(suggestions)
+}
+
+/// Collects the uses of `self` and `Self` in the body of `into`, which have to
+/// be renamed when the impl is turned around.
+struct SelfFinder {
+    self_id: NodeId,
+    self_uses: Vec<Span>,
+    self_ty_uses: Vec<Span>,
+    invalid: bool,
+}
+
+impl<'tcx> Visitor<'tcx> for SelfFinder {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if let ExprPath(QPath::Resolved(None, ref path)) = expr.node {
+            if path.def == Def::Local(self.self_id) {
+                if in_macro(expr.span) {
+                    self.invalid = true;
+                } else {
+                    self.self_uses.push(expr.span);
+                }
+                return;
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn visit_path(&mut self, path: &'tcx Path, _: NodeId) {
+        if let Some(segment) = path.segments.first() {
+            if segment.ident.name == keywords::SelfType.name() {
+                if in_macro(segment.ident.span) {
+                    self.invalid = true;
+                } else {
+                    self.self_ty_uses.push(segment.ident.span);
+                }
+            }
+        }
+        walk_path(self, path);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
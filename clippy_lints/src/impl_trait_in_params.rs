@@ -0,0 +1,87 @@
+use rustc::lint::*;
+use syntax::ast::*;
+use syntax::codemap::Span;
+use syntax::visit::{walk_ty, FnKind, Visitor};
+use crate::utils::{in_macro, span_help_and_lint};
+
+/// **What it does:** Lints when `impl Trait` is being used in a function's
+/// parameters.
+///
+/// **Why is this bad?** Turbofish syntax (`::<>`) cannot be used when
+/// `impl Trait` is being used, so callers of the function can never name the
+/// type explicitly. A named type parameter allows them to.
+///
+/// **Known problems:** This is a style choice rather than a problem, hence a
+/// restriction lint. Only public functions and methods are checked.
+///
+/// **Example:**
+/// ```rust
+/// trait MyTrait {}
+/// pub fn foo(a: impl MyTrait) {
+///     // [...]
+/// }
+/// ```
+/// Use instead:
+/// ```rust
+/// trait MyTrait {}
+/// pub fn foo<T: MyTrait>(a: T) {
+///     // [...]
+/// }
+/// ```
+declare_clippy_lint! {
+    pub IMPL_TRAIT_IN_PARAMS,
+    restriction,
+    "`impl Trait` is used in the function's parameters"
+}
+
+#[derive(Copy, Clone)]
+pub struct ImplTraitInParams;
+
+impl LintPass for ImplTraitInParams {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(IMPL_TRAIT_IN_PARAMS)
+    }
+}
+
+impl EarlyLintPass for ImplTraitInParams {
+    fn check_fn(&mut self, cx: &EarlyContext, kind: FnKind, decl: &FnDecl, span: Span, _: NodeId) {
+        if in_macro(span) {
+            return;
+        }
+        // trait methods and trait impls don't have a visibility of their own
+        let vis = match kind {
+            FnKind::ItemFn(_, _, vis, _) | FnKind::Method(_, _, Some(vis), _) => vis,
+            _ => return,
+        };
+        if vis.node != VisibilityKind::Public {
+            return;
+        }
+
+        let mut visitor = ImplTraitVisitor { spans: Vec::new() };
+        for arg in &decl.inputs {
+            visitor.visit_ty(&arg.ty);
+        }
+        for span in visitor.spans {
+            span_help_and_lint(
+                cx,
+                IMPL_TRAIT_IN_PARAMS,
+                span,
+                "`impl Trait` used as a function parameter",
+                "add a type parameter instead, so callers can name the type with the turbofish syntax",
+            );
+        }
+    }
+}
+
+struct ImplTraitVisitor {
+    spans: Vec<Span>,
+}
+
+impl<'ast> Visitor<'ast> for ImplTraitVisitor {
+    fn visit_ty(&mut self, ty: &'ast Ty) {
+        if let TyKind::ImplTrait(..) = ty.node {
+            self.spans.push(ty.span);
+        }
+        walk_ty(self, ty);
+    }
+}
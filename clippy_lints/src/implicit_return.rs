@@ -0,0 +1,153 @@
+use rustc::hir::*;
+use rustc::hir::intravisit::{walk_expr, FnKind, NestedVisitorMap, Visitor};
+use rustc::lint::*;
+use rustc::ty;
+use rustc_errors::Applicability;
+use syntax::ast::NodeId;
+use syntax_pos::Span;
+use crate::utils::{in_macro, snippet_opt, span_lint_and_then};
+
+/// **What it does:** Checks for missing return statements at the end of a
+/// block.
+///
+/// **Why is this bad?** Actually omitting the return keyword is idiomatic
+/// Rust code. Programmers coming from other languages might prefer the
+/// expressiveness of `return`. It's possible to miss the last returning
+/// statement because the only difference is a missing `;`. Especially in
+/// bigger code with multiple return paths having a `return` keyword makes it
+/// easier to find the corresponding statements.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// fn foo(x: usize) -> usize {
+///     x
+/// }
+/// ```
+/// add return
+/// ```rust
+/// fn foo(x: usize) -> usize {
+///     return x;
+/// }
+/// ```
+declare_clippy_lint! {
+    pub IMPLICIT_RETURN,
+    restriction,
+    "use a return statement like `return expr` instead of an expression"
+}
+
+#[derive(Copy, Clone)]
+pub struct ImplicitReturn;
+
+impl LintPass for ImplicitReturn {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(IMPLICIT_RETURN)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ImplicitReturn {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'a, 'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl,
+        body: &'tcx Body,
+        span: Span,
+        _: NodeId,
+    ) {
+        if in_macro(span) {
+            return;
+        }
+        // closures like `|x| x + 1` have no block to put a `return` in
+        if let FnKind::Closure(..) = kind {
+            match body.value.node {
+                ExprBlock(..) => (),
+                _ => return,
+            }
+        }
+        check_final_expr(cx, &body.value);
+    }
+}
+
+/// Looks through blocks, `if`, `match` and `loop` for the expressions which
+/// are implicitly returned.
+fn check_final_expr<'e>(cx: &LateContext, expr: &'e Expr) {
+    match expr.node {
+        ExprBlock(ref block, _) => {
+            if let Some(ref tail) = block.expr {
+                check_final_expr(cx, tail);
+            }
+        },
+        ExprIf(_, ref then, Some(ref els)) => {
+            check_final_expr(cx, then);
+            check_final_expr(cx, els);
+        },
+        ExprMatch(_, ref arms, MatchSource::Normal) | ExprMatch(_, ref arms, MatchSource::IfLetDesugar { .. }) => {
+            for arm in arms {
+                check_final_expr(cx, &arm.body);
+            }
+        },
+        ExprLoop(ref block, _, LoopSource::Loop) => {
+            let mut visitor = BreakVisitor {
+                loop_id: expr.id,
+                breaks: Vec::new(),
+            };
+            visitor.visit_block(block);
+            for (break_expr, value) in visitor.breaks {
+                lint(cx, break_expr.span, value.span, "change `break` to `return` as shown");
+            }
+        },
+        // these already leave the function, or don't produce a value
+        ExprRet(_) | ExprBreak(..) | ExprContinue(_) | ExprIf(..) | ExprLoop(..) | ExprWhile(..) => (),
+        _ => {
+            if let ty::TyTuple(tys) = cx.tables.expr_ty(expr).sty {
+                if tys.is_empty() {
+                    return;
+                }
+            }
+            // a macro call is returned as a whole
+            let span = expr.span.source_callsite();
+            lint(cx, span, span, "add `return` as shown");
+        },
+    }
+}
+
+fn lint(cx: &LateContext, outer_span: Span, inner_span: Span, msg: &str) {
+    if in_macro(outer_span) {
+        return;
+    }
+    if let Some(snippet) = snippet_opt(cx, inner_span) {
+        span_lint_and_then(cx, IMPLICIT_RETURN, outer_span, "missing return statement", |db| {
+            db.span_suggestion_with_applicability(
+                outer_span,
+                msg,
+                format!("return {}", snippet),
+                Applicability::MachineApplicable,
+            );
+        });
+    }
+}
+
+/// Collects the `break` expressions with a value which leave a given loop.
+struct BreakVisitor<'tcx> {
+    loop_id: NodeId,
+    breaks: Vec<(&'tcx Expr, &'tcx Expr)>,
+}
+
+impl<'tcx> Visitor<'tcx> for BreakVisitor<'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if let ExprBreak(destination, Some(ref value)) = expr.node {
+            if let Ok(target_id) = destination.target_id {
+                if target_id == self.loop_id {
+                    self.breaks.push((expr, value));
+                }
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
@@ -0,0 +1,103 @@
+use rustc::lint::*;
+use rustc::hir::*;
+use crate::utils::{in_macro, snippet, snippet_opt, span_lint_and_sugg};
+
+/// **What it does:** Checks for tuple structs initialized with field syntax.
+/// It will however not lint if a base initializer is present.
+///
+/// **Why is this bad?** This may be confusing to the uninitiated and adds no
+/// benefit as opposed to tuple initializers.
+///
+/// **Known problems:** Fields which are not given in order are only linted if
+/// their expressions have no side effects, as the rewrite changes the order in
+/// which they are evaluated.
+///
+/// **Example:**
+/// ```rust
+/// struct TupleStruct(u8, u16);
+///
+/// let _ = TupleStruct {
+///     0: 1,
+///     1: 23,
+/// };
+/// ```
+/// Use instead:
+/// ```rust
+/// # struct TupleStruct(u8, u16);
+/// let _ = TupleStruct(1, 23);
+/// ```
+declare_clippy_lint! {
+    pub INIT_NUMBERED_FIELDS,
+    style,
+    "numbered fields in tuple struct initializer"
+}
+
+pub struct NumberedFields;
+
+impl LintPass for NumberedFields {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(INIT_NUMBERED_FIELDS)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for NumberedFields {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_macro(expr.span) {
+            return;
+        }
+
+        if let ExprStruct(_, ref fields, None) = expr.node {
+            if fields.is_empty() {
+                return;
+            }
+            // the fields may be given in any order, the constructor takes them by index
+            let mut ordered = Vec::with_capacity(fields.len());
+            for field in fields {
+                if field.is_shorthand {
+                    return;
+                }
+                match field.ident.name.as_str().parse::<usize>() {
+                    Ok(idx) => ordered.push((idx, field)),
+                    Err(_) => return,
+                }
+            }
+            let in_order = ordered.windows(2).all(|w| w[0].0 < w[1].0);
+            if !in_order && !fields.iter().all(|field| is_side_effect_free(&field.expr)) {
+                return;
+            }
+            ordered.sort_by_key(|&(idx, _)| idx);
+
+            let path = match snippet_opt(cx, expr.span) {
+                Some(ref snip) => match snip.find('{') {
+                    Some(brace) => snip[..brace].trim_right().to_string(),
+                    None => return,
+                },
+                None => return,
+            };
+            let args = ordered
+                .iter()
+                .map(|&(_, field)| snippet(cx, field.expr.span, ".."))
+                .collect::<Vec<_>>()
+                .join(", ");
+            span_lint_and_sugg(
+                cx,
+                INIT_NUMBERED_FIELDS,
+                expr.span,
+                "used a field initializer for a tuple struct",
+                "try this instead",
+                format!("{}({})", path, args),
+            );
+        }
+    }
+}
+
+fn is_side_effect_free(expr: &Expr) -> bool {
+    match expr.node {
+        ExprLit(_) | ExprPath(_) => true,
+        ExprField(ref inner, _) | ExprUnary(_, ref inner) | ExprCast(ref inner, _) | ExprAddrOf(_, ref inner) => {
+            is_side_effect_free(inner)
+        },
+        ExprBinary(_, ref left, ref right) => is_side_effect_free(left) && is_side_effect_free(right),
+        _ => false,
+    }
+}
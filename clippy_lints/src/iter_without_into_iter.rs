@@ -0,0 +1,265 @@
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::ty::{self, RegionKind};
+use crate::utils::{get_trait_def_id, implements_trait, in_macro, is_automatically_derived, paths, return_ty, snippet,
+            span_lint_and_then};
+
+/// **What it does:** Looks for `iter` and `iter_mut` methods without an
+/// associated `IntoIterator for (&|&mut) Type` implementation.
+///
+/// **Why is this bad?** It's not bad, but having them is idiomatic and allows
+/// the type to be used in for loops directly (`for val in &iter {}`), without
+/// having to first call `iter()` or `iter_mut()`.
+///
+/// **Known problems:** Only public methods taking `&self` or `&mut self` and
+/// no other argument are checked. The suggested implementation is a skeleton:
+/// elided lifetimes in the iterator type have to be filled in by hand.
+///
+/// **Example:**
+/// ```rust
+/// struct MySlice<'a>(&'a [u8]);
+/// impl<'a> MySlice<'a> {
+///     pub fn iter(&self) -> std::slice::Iter<'a, u8> {
+///         self.0.iter()
+///     }
+/// }
+/// ```
+/// Use instead:
+/// ```rust
+/// struct MySlice<'a>(&'a [u8]);
+/// impl<'a> MySlice<'a> {
+///     pub fn iter(&self) -> std::slice::Iter<'a, u8> {
+///         self.0.iter()
+///     }
+/// }
+/// impl<'b, 'a> IntoIterator for &'b MySlice<'a> {
+///     type Item = &'a u8;
+///     type IntoIter = std::slice::Iter<'a, u8>;
+///     fn into_iter(self) -> Self::IntoIter {
+///         self.iter()
+///     }
+/// }
+/// ```
+declare_clippy_lint! {
+    pub ITER_WITHOUT_INTO_ITER,
+    pedantic,
+    "implementing `iter(_mut)` without an associated `IntoIterator for (&|&mut) Type` impl"
+}
+
+/// **What it does:** This is the opposite of the `iter_without_into_iter` lint.
+/// It looks for `IntoIterator for (&|&mut) Type` implementations without an
+/// inherent `iter` or `iter_mut` method on the type.
+///
+/// **Why is this bad?** It's not bad, but having them is idiomatic and allows
+/// the type to be used in iterator chains by just calling `.iter()`, instead
+/// of the more awkward `<&Type>::into_iter` or `(&val).into_iter()` syntax in
+/// case of ambiguity with another `IntoIterator` impl.
+///
+/// **Known problems:** Only public types are checked.
+///
+/// **Example:**
+/// ```rust
+/// struct MySlice<'a>(&'a [u8]);
+/// impl<'a> IntoIterator for &MySlice<'a> {
+///     type Item = &'a u8;
+///     type IntoIter = std::slice::Iter<'a, u8>;
+///     fn into_iter(self) -> Self::IntoIter {
+///         self.0.iter()
+///     }
+/// }
+/// ```
+/// Use instead:
+/// ```rust
+/// struct MySlice<'a>(&'a [u8]);
+/// impl<'a> MySlice<'a> {
+///     pub fn iter(&self) -> std::slice::Iter<'a, u8> {
+///         self.into_iter()
+///     }
+/// }
+/// impl<'a> IntoIterator for &MySlice<'a> {
+///     type Item = &'a u8;
+///     type IntoIter = std::slice::Iter<'a, u8>;
+///     fn into_iter(self) -> Self::IntoIter {
+///         self.0.iter()
+///     }
+/// }
+/// ```
+declare_clippy_lint! {
+    pub INTO_ITER_WITHOUT_ITER,
+    pedantic,
+    "implementing `IntoIterator for (&|&mut) Type` without an inherent `iter(_mut)` method"
+}
+
+#[derive(Copy, Clone)]
+pub struct IterWithoutIntoIter;
+
+impl LintPass for IterWithoutIntoIter {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(ITER_WITHOUT_INTO_ITER, INTO_ITER_WITHOUT_ITER)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for IterWithoutIntoIter {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if in_macro(item.span) {
+            return;
+        }
+        match item.node {
+            ItemImpl(_, _, _, ref generics, None, ref self_ty_hir, ref impl_items) => for impl_item in impl_items {
+                let mutbl = match &*impl_item.ident.as_str() {
+                    "iter" => MutImmutable,
+                    "iter_mut" => MutMutable,
+                    _ => continue,
+                };
+                check_iter_method(cx, item, generics, self_ty_hir, impl_item, mutbl);
+            },
+            ItemImpl(_, _, _, ref generics, Some(ref trait_ref), ref self_ty_hir, _) => {
+                if_chain! {
+                    if !is_automatically_derived(&item.attrs);
+                    if let Some(into_iter_trait) = get_trait_def_id(cx, &paths::INTO_ITERATOR);
+                    if trait_ref.path.def.def_id() == into_iter_trait;
+                    if let TyRptr(_, MutTy { ty: ref inner_ty_hir, mutbl }) = self_ty_hir.node;
+                    then {
+                        check_into_iter_impl(cx, item, generics, inner_ty_hir, mutbl);
+                    }
+                }
+            },
+            _ => (),
+        }
+    }
+}
+
+fn check_into_iter_impl<'a, 'tcx>(
+    cx: &LateContext<'a, 'tcx>,
+    item: &'tcx Item,
+    generics: &Generics,
+    inner_ty_hir: &Ty,
+    mutbl: Mutability,
+) {
+    let (method, ref_desc) = match mutbl {
+        MutImmutable => ("iter", "&"),
+        MutMutable => ("iter_mut", "&mut "),
+    };
+    let self_ty = cx.tcx.type_of(cx.tcx.hir.local_def_id(item.id));
+    if_chain! {
+        if let ty::TyRef(_, inner_ty, _) = self_ty.sty;
+        if let ty::TyAdt(adt_def, _) = inner_ty.sty;
+        if let Some(adt_id) = cx.tcx.hir.as_local_node_id(adt_def.did);
+        if cx.access_levels.is_exported(adt_id);
+        if !cx.tcx.inherent_impls(adt_def.did).iter().any(|&impl_def_id| {
+            cx.tcx
+                .associated_items(impl_def_id)
+                .any(|assoc_item| assoc_item.ident.name == method)
+        });
+        then {
+            let sugg = format!(
+                "impl{generics} {ty} {{\n    \
+                 pub fn {method}({ref_desc}self) -> <{ref_desc}Self as IntoIterator>::IntoIter {{\n        \
+                 <{ref_desc}Self as IntoIterator>::into_iter(self)\n    \
+                 }}\n\
+                 }}\n\n",
+                generics = snippet(cx, generics.span, ""),
+                ty = snippet(cx, inner_ty_hir.span, ".."),
+                method = method,
+                ref_desc = ref_desc,
+            );
+            span_lint_and_then(
+                cx,
+                INTO_ITER_WITHOUT_ITER,
+                cx.tcx.def_span(cx.tcx.hir.local_def_id(item.id)),
+                &format!(
+                    "`IntoIterator` implemented for `{}{}` without an `{}` method",
+                    ref_desc,
+                    inner_ty,
+                    method
+                ),
+                |db| {
+                    db.span_suggestion(
+                        item.span.with_hi(item.span.lo()),
+                        &format!("consider implementing `{}`", method),
+                        sugg,
+                    );
+                },
+            );
+        }
+    }
+}
+
+fn check_iter_method<'a, 'tcx>(
+    cx: &LateContext<'a, 'tcx>,
+    item: &'tcx Item,
+    generics: &Generics,
+    self_ty_hir: &Ty,
+    impl_item: &ImplItemRef,
+    mutbl: Mutability,
+) {
+    let self_ty = cx.tcx.type_of(cx.tcx.hir.local_def_id(item.id));
+    let method_def_id = cx.tcx.hir.local_def_id(impl_item.id.node_id);
+    let sig = cx.tcx.fn_sig(method_def_id);
+    if_chain! {
+        if let AssociatedItemKind::Method { has_self: true } = impl_item.kind;
+        if cx.access_levels.is_exported(impl_item.id.node_id);
+        if sig.inputs().skip_binder().len() == 1;
+        if let ty::TyRef(_, receiver_ty, receiver_mutbl) = sig.inputs().skip_binder()[0].sty;
+        if receiver_ty == self_ty && receiver_mutbl == mutbl;
+        let ret_ty = return_ty(cx, impl_item.id.node_id);
+        if let Some(iterator_trait) = get_trait_def_id(cx, &paths::ITERATOR);
+        if implements_trait(cx, ret_ty, iterator_trait, &[]);
+        if let Some(into_iter_trait) = get_trait_def_id(cx, &paths::INTO_ITERATOR);
+        let ref_ty = cx.tcx.mk_ref(&RegionKind::ReErased, ty::TypeAndMut { ty: self_ty, mutbl });
+        if !implements_trait(cx, ref_ty, into_iter_trait, &[]);
+        if let ImplItemKind::Method(ref method_sig, _) = cx.tcx.hir.impl_item(impl_item.id).node;
+        if let FunctionRetTy::Return(ref ret_ty_hir) = method_sig.decl.output;
+        then {
+            // the impl needs a lifetime for the reference which isn't used by the type already
+            let lifetime = (b'a'..=b'z')
+                .map(|c| format!("'{}", c as char))
+                .find(|name| !generics.params.iter().any(|param| param.name.ident().as_str() == **name))
+                .unwrap_or_else(|| "'_".to_string());
+            let (ref_mut, ref_desc) = match mutbl {
+                MutImmutable => ("", "&"),
+                MutMutable => ("mut ", "&mut "),
+            };
+            let params = if generics.params.is_empty() {
+                String::new()
+            } else {
+                let params = snippet(cx, generics.span, "<..>");
+                format!(", {}", params.trim_left_matches('<').trim_right_matches('>'))
+            };
+            let iter_ty = snippet(cx, ret_ty_hir.span, "..");
+            let sugg = format!(
+                "impl<{lifetime}{params}> IntoIterator for &{lifetime} {ref_mut}{self_ty} {{\n    \
+                 type Item = <{iter_ty} as Iterator>::Item;\n    \
+                 type IntoIter = {iter_ty};\n    \
+                 fn into_iter(self) -> Self::IntoIter {{\n        \
+                 self.{method}()\n    \
+                 }}\n\
+                 }}\n\n",
+                lifetime = lifetime,
+                params = params,
+                ref_mut = ref_mut,
+                self_ty = snippet(cx, self_ty_hir.span, ".."),
+                iter_ty = iter_ty,
+                method = impl_item.ident,
+            );
+            span_lint_and_then(
+                cx,
+                ITER_WITHOUT_INTO_ITER,
+                cx.tcx.def_span(method_def_id),
+                &format!(
+                    "`{}` method without an `IntoIterator` impl for `{}{}`",
+                    impl_item.ident,
+                    ref_desc,
+                    self_ty
+                ),
+                |db| {
+                    db.span_suggestion(
+                        item.span.with_hi(item.span.lo()),
+                        &format!("consider implementing `IntoIterator` for `{}{}`", ref_desc, self_ty),
+                        sugg,
+                    );
+                },
+            );
+        }
+    }
+}
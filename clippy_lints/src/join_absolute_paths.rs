@@ -0,0 +1,93 @@
+use rustc::hir::*;
+use rustc::lint::*;
+use syntax::ast::LitKind;
+use crate::utils::{match_type, paths, snippet, span_lint_and_then, walk_ptrs_ty};
+
+/// **What it does:** Checks for calls to `Path::join` with a string literal
+/// starting with a path separator or a Windows drive prefix, like
+/// `path.join("/sh")`.
+///
+/// **Why is this bad?** If the argument is an absolute path, `join` discards
+/// the base path and just returns the argument, which is rarely what was
+/// intended.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// use std::path::{Path, PathBuf};
+///
+/// let path = Path::new("/bin");
+/// let joined_path = path.join("/sh");
+/// assert_eq!(joined_path, PathBuf::from("/sh"));
+/// ```
+/// Use instead:
+/// ```rust
+/// use std::path::{Path, PathBuf};
+///
+/// let path = Path::new("/bin");
+/// let joined_path = path.join("sh");
+/// assert_eq!(joined_path, PathBuf::from("/bin/sh"));
+/// ```
+declare_clippy_lint! {
+    pub JOIN_ABSOLUTE_PATHS,
+    nursery,
+    "calling `join` on a path with an absolute path, which replaces the base path"
+}
+
+#[derive(Copy, Clone)]
+pub struct JoinAbsolutePaths;
+
+impl LintPass for JoinAbsolutePaths {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(JOIN_ABSOLUTE_PATHS)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for JoinAbsolutePaths {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_chain! {
+            if let ExprMethodCall(ref path, _, ref args) = expr.node;
+            if path.ident.name == "join";
+            if args.len() == 2;
+            let obj_ty = walk_ptrs_ty(cx.tables.expr_ty(&args[0]));
+            if match_type(cx, obj_ty, &paths::PATH) || match_type(cx, obj_ty, &paths::PATH_BUF);
+            if let ExprLit(ref lit) = args[1].node;
+            if let LitKind::Str(ref joined, _) = lit.node;
+            let joined = joined.as_str();
+            let has_separator = joined.starts_with('/') || joined.starts_with('\\');
+            if has_separator || has_drive_prefix(&joined);
+            then {
+                span_lint_and_then(
+                    cx,
+                    JOIN_ABSOLUTE_PATHS,
+                    lit.span,
+                    "argument to `Path::join` is an absolute path",
+                    |db| {
+                        db.note("joining an absolute path will replace the base path instead");
+                        // stripping the separator after a drive prefix still doesn't make it relative
+                        if has_separator {
+                            db.span_suggestion(
+                                lit.span,
+                                "if this is unintentional, try removing the starting separator",
+                                format!("\"{}\"", joined.trim_left_matches(|c| c == '/' || c == '\\')),
+                            );
+                        }
+                        db.span_suggestion(
+                            expr.span,
+                            "if this is intentional, try using `PathBuf::from` instead",
+                            format!("PathBuf::from({})", snippet(cx, lit.span, "..")),
+                        );
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Checks if `path` starts with a Windows drive prefix and separator, like
+/// `C:\`.
+fn has_drive_prefix(path: &str) -> bool {
+    let bytes = path.as_bytes();
+    bytes.len() >= 3 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':' && (bytes[2] == b'/' || bytes[2] == b'\\')
+}
@@ -0,0 +1,176 @@
+use rustc::hir::*;
+use rustc::hir::def::Def;
+use rustc::hir::def_id::DefId;
+use rustc::hir::intravisit::{walk_expr, walk_pat, NestedVisitorMap, Visitor};
+use rustc::lint::*;
+use rustc::ty::{self, Ty, TypeckTables};
+use rustc_errors::Applicability;
+use rustc_target::abi::LayoutOf;
+use crate::utils::{implements_trait, in_macro, is_copy, snippet_opt, span_lint_and_then};
+
+/// **What it does:** Checks for large `const` arrays that should
+/// be defined as `static` instead.
+///
+/// **Why is this bad?** Performance: const variables are inlined upon use.
+/// Static items result in only one instance and has a fixed location in
+/// memory.
+///
+/// **Known problems:** A `static` can't be used in other constants or in
+/// patterns. The suggestion is only machine applicable if the constant isn't
+/// exported and not used in such places.
+///
+/// The configuration option `array_size_threshold` can be set to override the
+/// default limit of 512000 bytes for a project.
+///
+/// **Example:**
+/// ```rust
+/// pub const A: [u32; 1_000_000] = [0u32; 1_000_000];
+/// ```
+/// Use instead:
+/// ```rust
+/// pub static A: [u32; 1_000_000] = [0u32; 1_000_000];
+/// ```
+declare_clippy_lint! {
+    pub LARGE_CONST_ARRAYS,
+    perf,
+    "large non-scalar const array may cause performance overhead"
+}
+
+#[derive(Copy, Clone)]
+pub struct LargeConstArrays {
+    maximum_allowed_size: u64,
+}
+
+impl LargeConstArrays {
+    pub fn new(maximum_allowed_size: u64) -> Self {
+        Self { maximum_allowed_size }
+    }
+}
+
+impl LintPass for LargeConstArrays {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(LARGE_CONST_ARRAYS)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for LargeConstArrays {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if in_macro(item.span) {
+            return;
+        }
+        if let ItemConst(..) = item.node {
+            let def_id = cx.tcx.hir.local_def_id(item.id);
+            let ty = cx.tcx.type_of(def_id);
+            if let ty::TyArray(..) = ty.sty {
+                let size = match cx.layout_of(ty) {
+                    Ok(layout) => layout.size.bytes(),
+                    Err(_) => return,
+                };
+                if size <= self.maximum_allowed_size {
+                    return;
+                }
+                span_lint_and_then(
+                    cx,
+                    LARGE_CONST_ARRAYS,
+                    item.span,
+                    &format!("large array of {} bytes defined as const", size),
+                    |db| {
+                        let const_kw_pos = match snippet_opt(cx, item.span).and_then(|snip| snip.find("const ")) {
+                            Some(pos) => pos,
+                            None => return,
+                        };
+                        let problem = static_problem(cx, item, def_id, ty);
+                        db.span_suggestion_with_applicability(
+                            item.span.from_inner_byte_pos(const_kw_pos, const_kw_pos + 5),
+                            "make this a static item",
+                            "static".to_string(),
+                            if problem.is_some() {
+                                Applicability::MaybeIncorrect
+                            } else {
+                                Applicability::MachineApplicable
+                            },
+                        );
+                        if let Some(problem) = problem {
+                            db.note(&problem);
+                        }
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Returns why replacing `const` with `static` is not enough to turn the
+/// constant `item` into a `static`, if it isn't.
+fn static_problem<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, item: &Item, def_id: DefId, ty: Ty<'tcx>) -> Option<String> {
+    if cx.access_levels.is_exported(item.id) {
+        Some("other crates may use this constant where a `static` isn't allowed".to_string())
+    } else if !is_copy(cx, ty) {
+        Some(format!("`{}` is not `Copy`, a `static` of it can't be used by value", ty))
+    } else if !cx.tcx.lang_items().sync_trait().map_or(false, |sync| implements_trait(cx, ty, sync, &[])) {
+        Some(format!("`{}` is not `Sync`, which is required for a `static`", ty))
+    } else if is_used_where_static_is_not_allowed(cx, def_id) {
+        Some("this constant is used in a pattern or in a constant context, where a `static` isn't allowed".to_string())
+    } else {
+        None
+    }
+}
+
+/// Checks if the constant `def_id` is used in a pattern or in a constant
+/// context anywhere in the crate.
+fn is_used_where_static_is_not_allowed(cx: &LateContext, def_id: DefId) -> bool {
+    cx.tcx.hir.krate().bodies.iter().any(|(&body_id, body)| {
+        let in_const = match cx.tcx.hir.body_owner_kind(cx.tcx.hir.body_owner(body_id)) {
+            BodyOwnerKind::Fn => cx.tcx.is_const_fn(cx.tcx.hir.body_owner_def_id(body_id)),
+            BodyOwnerKind::Const | BodyOwnerKind::Static(..) => true,
+        };
+        let mut visitor = ConstUseVisitor {
+            tables: cx.tcx.body_tables(body_id),
+            def_id,
+            in_const,
+            found: false,
+        };
+        visitor.visit_body(body);
+        visitor.found
+    })
+}
+
+struct ConstUseVisitor<'a, 'tcx: 'a> {
+    tables: &'a TypeckTables<'tcx>,
+    def_id: DefId,
+    in_const: bool,
+    found: bool,
+}
+
+impl<'a, 'tcx> ConstUseVisitor<'a, 'tcx> {
+    fn is_const(&self, qpath: &QPath, id: HirId) -> bool {
+        match self.tables.qpath_def(qpath, id) {
+            Def::Const(def_id) => def_id == self.def_id,
+            _ => false,
+        }
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for ConstUseVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if let ExprPath(ref qpath) = expr.node {
+            if self.in_const && self.is_const(qpath, expr.hir_id) {
+                self.found = true;
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn visit_pat(&mut self, pat: &'tcx Pat) {
+        if let PatKind::Path(ref qpath) = pat.node {
+            if self.is_const(qpath, pat.hir_id) {
+                self.found = true;
+            }
+        }
+        walk_pat(self, pat);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
@@ -6,7 +6,8 @@ use crate::utils::{snippet_opt, span_lint_and_then};
 use rustc::ty::layout::LayoutOf;
 
 /// **What it does:** Checks for large size differences between variants on
-/// `enum`s.
+/// `enum`s. The largest variant is compared to the second largest one, since
+/// boxing it can't make the enum smaller than that.
 ///
 /// **Why is this bad?** Enum size is bounded by the largest variant. Having a
 /// large variant
@@ -54,32 +55,34 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for LargeEnumVariant {
             let adt = ty.ty_adt_def()
                 .expect("already checked whether this is an enum");
 
-            let mut smallest_variant: Option<(_, _)> = None;
-            let mut largest_variant: Option<(_, _)> = None;
+            let mut variants_size: Vec<(u64, usize)> = adt.variants
+                .iter()
+                .enumerate()
+                .map(|(i, variant)| {
+                    let size: u64 = variant
+                        .fields
+                        .iter()
+                        .filter_map(|f| {
+                            let ty = cx.tcx.type_of(f.did);
+                            // don't count generics by filtering out everything
+                            // that does not have a layout
+                            cx.layout_of(ty).ok().map(|l| l.size.bytes())
+                        })
+                        .sum();
+                    (size, i)
+                })
+                .collect();
 
-            for (i, variant) in adt.variants.iter().enumerate() {
-                let size: u64 = variant
-                    .fields
-                    .iter()
-                    .filter_map(|f| {
-                        let ty = cx.tcx.type_of(f.did);
-                        // don't count generics by filtering out everything
-                        // that does not have a layout
-                        cx.layout_of(ty).ok().map(|l| l.size.bytes())
-                    })
-                    .sum();
+            // boxing the largest variant only makes the enum smaller down to
+            // the size of the second largest one
+            variants_size.sort_by(|a, b| b.0.cmp(&a.0));
 
-                let grouped = (size, (i, variant));
-
-                update_if(&mut smallest_variant, grouped, |a, b| b.0 <= a.0);
-                update_if(&mut largest_variant, grouped, |a, b| b.0 >= a.0);
-            }
-
-            if let (Some(smallest), Some(largest)) = (smallest_variant, largest_variant) {
-                let difference = largest.0 - smallest.0;
+            if variants_size.len() >= 2 {
+                let difference = variants_size[0].0 - variants_size[1].0;
 
                 if difference > self.maximum_size_difference_allowed {
-                    let (i, variant) = largest.1;
+                    let i = variants_size[0].1;
+                    let variant = &adt.variants[i];
 
                     span_lint_and_then(
                         cx,
@@ -116,15 +119,3 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for LargeEnumVariant {
     }
 }
 
-fn update_if<T, F>(old: &mut Option<T>, new: T, f: F)
-where
-    F: Fn(&T, &T) -> bool,
-{
-    if let Some(ref mut val) = *old {
-        if f(val, &new) {
-            *val = new;
-        }
-    } else {
-        *old = Some(new);
-    }
-}
@@ -0,0 +1,79 @@
+use rustc::hir::*;
+use rustc::lint::*;
+use syntax::ast::LitKind;
+use crate::utils::{is_direct_expn_of, span_help_and_lint};
+
+/// **What it does:** Checks for the inclusion of large files via
+/// `include_bytes!()` and `include_str!()`.
+///
+/// **Why is this bad?** Including large files can increase the size of the
+/// binary, and the whole file is kept in memory while compiling.
+///
+/// **Known problems:** None.
+///
+/// The configuration option `max_include_file_size` can be set to override
+/// the default limit of 1000000 bytes for a project.
+///
+/// **Example:**
+/// ```rust
+/// let included_str = include_str!("very_large_file.txt");
+/// let included_bytes = include_bytes!("very_large_file.txt");
+/// ```
+/// Instead, read the file at runtime:
+/// ```rust
+/// use std::fs;
+///
+/// let string = fs::read_to_string("very_large_file.txt")?;
+/// let bytes = fs::read("very_large_file.txt")?;
+/// ```
+declare_clippy_lint! {
+    pub LARGE_INCLUDE_FILE,
+    restriction,
+    "including a large file"
+}
+
+#[derive(Copy, Clone)]
+pub struct LargeIncludeFile {
+    max_file_size: u64,
+}
+
+impl LargeIncludeFile {
+    pub fn new(max_file_size: u64) -> Self {
+        Self { max_file_size }
+    }
+}
+
+impl LintPass for LargeIncludeFile {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(LARGE_INCLUDE_FILE)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for LargeIncludeFile {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if let ExprLit(ref lit) = expr.node {
+            // the macros expand to a literal holding the whole file, so its
+            // length is the size of the file
+            let len = match lit.node {
+                LitKind::ByteStr(ref bytes) => bytes.len(),
+                LitKind::Str(sym, _) => sym.as_str().len(),
+                _ => return,
+            };
+            let call_site = match is_direct_expn_of(expr.span, "include_bytes")
+                .or_else(|| is_direct_expn_of(expr.span, "include_str"))
+            {
+                Some(span) => span,
+                None => return,
+            };
+            if len as u64 > self.max_file_size {
+                span_help_and_lint(
+                    cx,
+                    LARGE_INCLUDE_FILE,
+                    call_site,
+                    &format!("attempted to include a file of {} bytes, more than {}", len, self.max_file_size),
+                    "consider reading the file at runtime instead",
+                );
+            }
+        }
+    }
+}
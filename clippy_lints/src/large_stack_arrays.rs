@@ -0,0 +1,106 @@
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::ty::{self, Ty};
+use rustc_target::abi::LayoutOf;
+use crate::utils::{in_constant, in_macro, snippet, span_help_and_lint};
+
+/// **What it does:** Checks for local arrays that may be too large.
+///
+/// **Why is this bad?** Large local arrays may cause stack overflow.
+///
+/// **Known problems:** The size is computed from the layout of the array, so
+/// arrays of generic element types are not checked.
+///
+/// The configuration option `array_size_threshold` can be set to override the
+/// default limit of 512000 bytes for a project.
+///
+/// **Example:**
+/// ```rust
+/// let a = [0u32; 1_000_000];
+/// ```
+/// Use instead:
+/// ```rust
+/// let a = vec![0u32; 1_000_000].into_boxed_slice();
+/// ```
+declare_clippy_lint! {
+    pub LARGE_STACK_ARRAYS,
+    pedantic,
+    "allocating large arrays on stack may cause stack overflow"
+}
+
+#[derive(Copy, Clone)]
+pub struct LargeStackArrays {
+    maximum_allowed_size: u64,
+}
+
+impl LargeStackArrays {
+    pub fn new(maximum_allowed_size: u64) -> Self {
+        Self { maximum_allowed_size }
+    }
+
+    /// Returns the size of `ty` if it is an array larger than the threshold.
+    fn too_large<'a, 'tcx>(self, cx: &LateContext<'a, 'tcx>, ty: Ty<'tcx>) -> Option<u64> {
+        if let ty::TyArray(..) = ty.sty {
+            // the layout accounts for nested arrays, and fails for generic ones
+            let size = cx.layout_of(ty).ok()?.size.bytes();
+            if size > self.maximum_allowed_size {
+                return Some(size);
+            }
+        }
+        None
+    }
+}
+
+impl LintPass for LargeStackArrays {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(LARGE_STACK_ARRAYS)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for LargeStackArrays {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_macro(expr.span) || in_constant(cx, expr.id) {
+            return;
+        }
+        if is_array_literal(expr) {
+            if let Some(size) = self.too_large(cx, cx.tables.expr_ty(expr)) {
+                span_help_and_lint(
+                    cx,
+                    LARGE_STACK_ARRAYS,
+                    expr.span,
+                    &format!("allocating a local array of {} bytes, more than {}", size, self.maximum_allowed_size),
+                    &format!(
+                        "consider allocating on the heap with `vec!{}.into_boxed_slice()`",
+                        snippet(cx, expr.span, "[...]")
+                    ),
+                );
+            }
+        }
+    }
+
+    fn check_local(&mut self, cx: &LateContext<'a, 'tcx>, local: &'tcx Local) {
+        if in_macro(local.span) {
+            return;
+        }
+        // array literals are checked as expressions
+        if local.init.as_ref().map_or(false, |init| is_array_literal(init)) {
+            return;
+        }
+        if let Some(size) = self.too_large(cx, cx.tables.pat_ty(&local.pat)) {
+            span_help_and_lint(
+                cx,
+                LARGE_STACK_ARRAYS,
+                local.span,
+                &format!("allocating a local array of {} bytes, more than {}", size, self.maximum_allowed_size),
+                "consider storing the array on the heap in a `Box<[T]>` or a `Vec<T>`",
+            );
+        }
+    }
+}
+
+fn is_array_literal(expr: &Expr) -> bool {
+    match expr.node {
+        ExprArray(..) | ExprRepeat(..) => true,
+        _ => false,
+    }
+}
@@ -0,0 +1,106 @@
+use rustc::hir::*;
+use rustc::lint::*;
+use crate::utils::{in_external_macro, is_must_use_func_call, is_must_use_ty, span_help_and_lint};
+
+/// **What it does:** Checks for `let _ = <expr>`
+/// where expr is `#[must_use]`
+///
+/// **Why is this bad?** It's better to explicitly
+/// handle the value of a `#[must_use]` expr
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// fn f() -> Result<u32, u32> {
+///     Ok(0)
+/// }
+///
+/// let _ = f();
+/// // is_ok() is marked #[must_use]
+/// let _ = f().is_ok();
+/// ```
+declare_clippy_lint! {
+    pub LET_UNDERSCORE_MUST_USE,
+    restriction,
+    "non-binding let on a `#[must_use]` expression"
+}
+
+/// **What it does:** Checks for `let _ = <expr>` without a type annotation.
+///
+/// **Why is this bad?** It's easy to accidentally drop a value this way, and
+/// if the type of the expression changes later, for example from a plain
+/// value to a guard or a future, the binding silently drops something else.
+/// An annotation makes the type that is dropped explicit.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// fn foo() -> Result<u32, ()> {
+///     Ok(123)
+/// }
+/// let _ = foo();
+/// ```
+/// Use instead:
+/// ```rust
+/// fn foo() -> Result<u32, ()> {
+///     Ok(123)
+/// }
+/// let _: Result<u32, ()> = foo();
+/// ```
+declare_clippy_lint! {
+    pub LET_UNDERSCORE_UNTYPED,
+    restriction,
+    "non-binding `let` without a type annotation"
+}
+
+#[derive(Copy, Clone)]
+pub struct LetUnderscore;
+
+impl LintPass for LetUnderscore {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(LET_UNDERSCORE_MUST_USE, LET_UNDERSCORE_UNTYPED)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for LetUnderscore {
+    fn check_local(&mut self, cx: &LateContext<'a, 'tcx>, local: &'tcx Local) {
+        if in_external_macro(cx, local.span) {
+            return;
+        }
+        if_chain! {
+            if let PatKind::Wild = local.pat.node;
+            if let Some(ref init) = local.init;
+            then {
+                if is_must_use_ty(cx, cx.tables.expr_ty(init)) {
+                    span_help_and_lint(
+                        cx,
+                        LET_UNDERSCORE_MUST_USE,
+                        local.span,
+                        "non-binding let on an expression with `#[must_use]` type",
+                        "consider explicitly using expression value",
+                    );
+                } else if is_must_use_func_call(cx, init) {
+                    span_help_and_lint(
+                        cx,
+                        LET_UNDERSCORE_MUST_USE,
+                        local.span,
+                        "non-binding let on a result of a `#[must_use]` function",
+                        "consider explicitly using function result",
+                    );
+                }
+
+                if local.ty.is_none() {
+                    span_help_and_lint(
+                        cx,
+                        LET_UNDERSCORE_UNTYPED,
+                        local.span,
+                        "non-binding `let` without a type annotation",
+                        "consider adding a type annotation",
+                    );
+                }
+            }
+        }
+    }
+}
@@ -70,25 +70,32 @@ pub mod consts;
 pub mod utils;
 
 // begin lints modules, do not remove this comment, it’s used in `update_lints`
+pub mod allow_attributes;
 pub mod approx_const;
 pub mod arithmetic;
 pub mod assign_ops;
 pub mod attrs;
+pub mod await_holding_lock;
 pub mod bit_mask;
 pub mod blacklisted_name;
 pub mod block_in_if_condition;
 pub mod booleans;
 pub mod bytecount;
+pub mod cognitive_complexity;
 pub mod collapsible_if;
+pub mod comparison_chain;
 pub mod const_static_lifetime;
 pub mod copies;
+pub mod crate_in_macro_def;
 pub mod cyclomatic_complexity;
 pub mod default_trait_access;
+pub mod derivable_impls;
 pub mod derive;
 pub mod doc;
 pub mod double_comparison;
 pub mod double_parens;
 pub mod drop_forget_ref;
+pub mod duplicated_attributes;
 pub mod duration_subsec;
 pub mod else_if_without_else;
 pub mod empty_enum;
@@ -101,30 +108,45 @@ pub mod erasing_op;
 pub mod escape;
 pub mod eta_reduction;
 pub mod eval_order_dependence;
+pub mod excessive_bools;
 pub mod excessive_precision;
+pub mod exhaustive_items;
 pub mod explicit_write;
 pub mod fallible_impl_from;
 pub mod format;
 pub mod formatting;
+pub mod from_over_into;
 pub mod functions;
 pub mod identity_conversion;
 pub mod identity_op;
 pub mod if_let_redundant_pattern_matching;
 pub mod if_not_else;
+pub mod impl_trait_in_params;
+pub mod implicit_return;
 pub mod indexing_slicing;
 pub mod infallible_destructuring_match;
 pub mod infinite_iter;
 pub mod inherent_impl;
+pub mod init_numbered_fields;
 pub mod inline_fn_without_body;
 pub mod int_plus_one;
 pub mod invalid_ref;
 pub mod items_after_statements;
+pub mod iter_without_into_iter;
+pub mod join_absolute_paths;
+pub mod large_const_arrays;
 pub mod large_enum_variant;
+pub mod large_include_file;
+pub mod large_stack_arrays;
 pub mod len_zero;
 pub mod let_if_seq;
+pub mod let_underscore;
 pub mod lifetimes;
 pub mod literal_representation;
 pub mod loops;
+pub mod manual_assert;
+pub mod manual_non_exhaustive;
+pub mod manual_retain;
 pub mod map_clone;
 pub mod map_unit_fn;
 pub mod matches;
@@ -133,9 +155,14 @@ pub mod methods;
 pub mod minmax;
 pub mod misc;
 pub mod misc_early;
+pub mod mismatching_type_param_order;
+pub mod missing_assert_message;
+pub mod missing_const_for_fn;
 pub mod missing_doc;
 pub mod missing_inline;
+pub mod missing_trait_methods;
 pub mod multiple_crate_versions;
+pub mod mut_key;
 pub mod mut_mut;
 pub mod mut_reference;
 pub mod mutex_atomic;
@@ -143,46 +170,75 @@ pub mod needless_bool;
 pub mod needless_borrow;
 pub mod needless_borrowed_ref;
 pub mod needless_continue;
+pub mod needless_for_each;
+pub mod needless_late_init;
 pub mod needless_pass_by_value;
 pub mod needless_update;
 pub mod neg_cmp_op_on_partial_ord;
 pub mod neg_multiply;
 pub mod new_without_default;
 pub mod no_effect;
+pub mod non_canonical_impls;
 pub mod non_copy_const;
 pub mod non_expressive_names;
+pub mod non_octal_unix_permissions;
+pub mod non_send_fields_in_send_ty;
 pub mod ok_if_let;
 pub mod open_options;
 pub mod overflow_check_conditional;
 pub mod panic_unimplemented;
 pub mod partialeq_ne_impl;
+pub mod pass_by_ref_or_value;
+pub mod path_buf_push_overwrite;
 pub mod precedence;
 pub mod ptr;
+pub mod pub_underscore_fields;
+pub mod pub_use;
 pub mod question_mark;
 pub mod ranges;
+pub mod read_zero_byte_vec;
+pub mod recursive_format_impl;
+pub mod redundant_clone;
+pub mod redundant_else;
 pub mod redundant_field_names;
+pub mod redundant_pub_crate;
+pub mod redundant_type_annotations;
+pub mod ref_option;
 pub mod reference;
 pub mod regex;
 pub mod replace_consts;
+pub mod return_self_not_must_use;
 pub mod returns;
+pub mod same_name_method;
+pub mod seek;
+pub mod self_assignment;
+pub mod semicolon_if_nothing_returned;
 pub mod serde_api;
 pub mod shadow;
+pub mod size_of_in_element_count;
 pub mod strings;
 pub mod suspicious_trait_impl;
 pub mod swap;
 pub mod temporary_assignment;
+pub mod trailing_empty_array;
 pub mod transmute;
-pub mod trivially_copy_pass_by_ref;
 pub mod types;
+pub mod unchecked_duration_subtraction;
 pub mod unicode;
+pub mod uninhabited_references;
+pub mod unnecessary_box_returns;
+pub mod unnecessary_owned_cow;
 pub mod unsafe_removed_from_name;
 pub mod unused_io_amount;
 pub mod unused_label;
+pub mod unused_rounding;
+pub mod unused_self;
 pub mod unwrap;
 pub mod use_self;
 pub mod vec;
 pub mod write;
 pub mod zero_div_zero;
+pub mod zero_sized_map_values;
 // end lints modules, do not remove this comment, it’s used in `update_lints`
 
 mod reexport {
@@ -235,6 +291,22 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         }
     };
 
+    let msrv = conf.msrv.as_ref().and_then(|msrv| {
+        let parsed = utils::parse_msrv(msrv);
+        if parsed.is_none() {
+            reg.sess.struct_err(&format!("error reading Clippy's configuration file: `{}` is not a valid Rust version", msrv)).emit();
+        }
+        parsed
+    });
+    let separate_literal_suffix = match &*conf.literal_suffix_style {
+        "separated" => true,
+        "unseparated" => false,
+        style => {
+            reg.sess.struct_err(&format!("error reading Clippy's configuration file: `{}` is not a valid literal suffix style, expected `separated` or `unseparated`", style)).emit();
+            true
+        },
+    };
+
     let mut store = reg.sess.lint_store.borrow_mut();
     store.register_removed(
         "should_assert_eq",
@@ -268,6 +340,10 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         "misaligned_transmute",
         "this lint has been split into cast_ptr_alignment and transmute_ptr_to_ptr",
     );
+    store.register_removed(
+        "box_vec",
+        "this lint has been generalized into box_collection",
+    );
     // end deprecated lints, do not remove this comment, it’s used in `update_lints`
 
     reg.register_late_lint_pass(box serde_api::Serde);
@@ -303,15 +379,15 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
     reg.register_late_lint_pass(box unicode::Unicode);
     reg.register_late_lint_pass(box strings::StringAdd);
     reg.register_early_lint_pass(box returns::ReturnPass);
-    reg.register_late_lint_pass(box methods::Pass);
+    reg.register_late_lint_pass(box methods::Pass::new(conf.expensive_constructors));
     reg.register_late_lint_pass(box shadow::Pass);
     reg.register_late_lint_pass(box types::LetPass);
     reg.register_late_lint_pass(box types::UnitCmp);
     reg.register_late_lint_pass(box loops::Pass);
-    reg.register_late_lint_pass(box lifetimes::LifetimePass);
+    reg.register_late_lint_pass(box lifetimes::LifetimePass::new(conf.avoid_breaking_exported_api));
     reg.register_late_lint_pass(box entry::HashMapLint);
     reg.register_late_lint_pass(box ranges::Pass);
-    reg.register_late_lint_pass(box types::CastPass);
+    reg.register_late_lint_pass(box types::CastPass::new(msrv.clone()));
     reg.register_late_lint_pass(box types::TypeComplexityPass::new(conf.type_complexity_threshold));
     reg.register_late_lint_pass(box matches::MatchPass);
     reg.register_late_lint_pass(box minmax::MinMaxPass);
@@ -328,8 +404,11 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
     reg.register_late_lint_pass(
         box cyclomatic_complexity::CyclomaticComplexity::new(conf.cyclomatic_complexity_threshold)
     );
+    reg.register_late_lint_pass(
+        box cognitive_complexity::CognitiveComplexity::new(conf.cognitive_complexity_threshold)
+    );
     reg.register_late_lint_pass(box escape::Pass{too_large_for_stack: conf.too_large_for_stack});
-    reg.register_early_lint_pass(box misc_early::MiscEarly);
+    reg.register_early_lint_pass(box misc_early::MiscEarly::new(separate_literal_suffix));
     reg.register_late_lint_pass(box panic_unimplemented::Pass);
     reg.register_late_lint_pass(box strings::StringLitAsBytes);
     reg.register_late_lint_pass(box derive::Derive);
@@ -355,7 +434,10 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
     reg.register_late_lint_pass(box unused_label::UnusedLabel);
     reg.register_late_lint_pass(box new_without_default::NewWithoutDefault);
     reg.register_late_lint_pass(box blacklisted_name::BlackListedName::new(conf.blacklisted_names));
-    reg.register_late_lint_pass(box functions::Functions::new(conf.too_many_arguments_threshold));
+    reg.register_late_lint_pass(box functions::Functions::new(
+            conf.too_many_arguments_threshold,
+            conf.too_many_lines_threshold,
+    ));
     reg.register_early_lint_pass(box doc::Doc::new(conf.doc_valid_idents));
     reg.register_late_lint_pass(box neg_multiply::NegMultiply);
     reg.register_early_lint_pass(box unsafe_removed_from_name::UnsafeNameRemoval);
@@ -376,8 +458,10 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
     reg.register_late_lint_pass(box large_enum_variant::LargeEnumVariant::new(conf.enum_variant_size_threshold));
     reg.register_late_lint_pass(box explicit_write::Pass);
     reg.register_late_lint_pass(box needless_pass_by_value::NeedlessPassByValue);
-    reg.register_late_lint_pass(box trivially_copy_pass_by_ref::TriviallyCopyPassByRef::new(
+    reg.register_late_lint_pass(box pass_by_ref_or_value::PassByRefOrValue::new(
             conf.trivial_copy_size_limit,
+            conf.pass_by_value_size_limit,
+            conf.avoid_breaking_exported_api,
             &reg.sess.target,
     ));
     reg.register_early_lint_pass(box literal_representation::LiteralDigitGrouping);
@@ -405,17 +489,83 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
     reg.register_late_lint_pass(box inherent_impl::Pass::default());
     reg.register_late_lint_pass(box neg_cmp_op_on_partial_ord::NoNegCompOpForPartialOrd);
     reg.register_late_lint_pass(box unwrap::Pass);
-    reg.register_late_lint_pass(box duration_subsec::DurationSubsec);
+    reg.register_late_lint_pass(box duration_subsec::DurationSubsec::new(msrv.clone()));
     reg.register_late_lint_pass(box default_trait_access::DefaultTraitAccess);
     reg.register_late_lint_pass(box indexing_slicing::IndexingSlicing);
     reg.register_late_lint_pass(box non_copy_const::NonCopyConst);
+    reg.register_early_lint_pass(box excessive_bools::ExcessiveBools::new(
+            conf.max_struct_bools,
+            conf.max_fn_params_bools,
+    ));
+    reg.register_late_lint_pass(box missing_const_for_fn::MissingConstForFn::new(msrv.clone()));
+    reg.register_late_lint_pass(box unused_self::UnusedSelf);
+    reg.register_late_lint_pass(box return_self_not_must_use::ReturnSelfNotMustUse);
+    reg.register_early_lint_pass(box impl_trait_in_params::ImplTraitInParams);
+    reg.register_late_lint_pass(box missing_trait_methods::MissingTraitMethods);
+    reg.register_late_lint_pass(box unnecessary_box_returns::UnnecessaryBoxReturns::new(conf.unnecessary_box_size));
+    reg.register_late_lint_pass(box ref_option::RefOption::default());
+    reg.register_late_lint_pass(box non_send_fields_in_send_ty::NonSendFieldsInSendTy);
+    reg.register_late_lint_pass(box derivable_impls::DerivableImpls);
+    reg.register_late_lint_pass(box non_canonical_impls::NonCanonicalImpls);
+    reg.register_late_lint_pass(box iter_without_into_iter::IterWithoutIntoIter);
+    reg.register_late_lint_pass(box from_over_into::FromOverInto);
+    reg.register_late_lint_pass(box same_name_method::SameNameMethod);
+    reg.register_late_lint_pass(box uninhabited_references::UninhabitedReferences);
+    reg.register_late_lint_pass(box mismatching_type_param_order::TypeParamMismatch);
+    reg.register_late_lint_pass(box large_stack_arrays::LargeStackArrays::new(conf.array_size_threshold));
+    reg.register_late_lint_pass(box large_const_arrays::LargeConstArrays::new(conf.array_size_threshold));
+    reg.register_late_lint_pass(box trailing_empty_array::TrailingEmptyArray);
+    reg.register_late_lint_pass(box unnecessary_owned_cow::UnnecessaryOwnedCow);
+    reg.register_late_lint_pass(box mut_key::MutableKeyType::new(conf.ignore_interior_mutability));
+    reg.register_late_lint_pass(box zero_sized_map_values::ZeroSizedMapValues);
+    reg.register_early_lint_pass(box crate_in_macro_def::CrateInMacroDef);
+    reg.register_early_lint_pass(box duplicated_attributes::DuplicatedAttributes::default());
+    reg.register_early_lint_pass(box allow_attributes::AllowAttributes::new(conf.require_allow_reason_field));
+    reg.register_late_lint_pass(box large_include_file::LargeIncludeFile::new(conf.max_include_file_size));
+    reg.register_early_lint_pass(box pub_underscore_fields::PubUnderscoreFields);
+    reg.register_late_lint_pass(box exhaustive_items::ExhaustiveItems);
+    reg.register_late_lint_pass(box semicolon_if_nothing_returned::SemicolonIfNothingReturned);
+    reg.register_late_lint_pass(box implicit_return::ImplicitReturn);
+    reg.register_early_lint_pass(box redundant_else::RedundantElse);
+    reg.register_early_lint_pass(box manual_assert::ManualAssert);
+    reg.register_late_lint_pass(box needless_late_init::NeedlessLateInit);
+    reg.register_late_lint_pass(box init_numbered_fields::NumberedFields);
+    reg.register_late_lint_pass(box redundant_pub_crate::RedundantPubCrate::default());
+    reg.register_early_lint_pass(box pub_use::PubUse::new(conf.allowed_pub_use_modules));
+    reg.register_late_lint_pass(box redundant_type_annotations::RedundantTypeAnnotations);
+    reg.register_late_lint_pass(box let_underscore::LetUnderscore);
+    reg.register_late_lint_pass(box self_assignment::SelfAssignment);
+    reg.register_late_lint_pass(box manual_non_exhaustive::ManualNonExhaustive::new(msrv.clone()));
+    reg.register_late_lint_pass(box comparison_chain::ComparisonChain);
+    reg.register_late_lint_pass(box needless_for_each::NeedlessForEach);
+    reg.register_late_lint_pass(box manual_retain::ManualRetain);
+    reg.register_early_lint_pass(box unused_rounding::UnusedRounding);
+    reg.register_late_lint_pass(box seek::Seek::new(msrv.clone()));
+    reg.register_late_lint_pass(box read_zero_byte_vec::ReadZeroByteVec);
+    reg.register_late_lint_pass(box size_of_in_element_count::SizeOfInElementCount);
+    reg.register_late_lint_pass(box path_buf_push_overwrite::PathBufPushOverwrite);
+    reg.register_late_lint_pass(box recursive_format_impl::RecursiveFormatImpl::default());
+    reg.register_late_lint_pass(box non_octal_unix_permissions::NonOctalUnixPermissions);
+    reg.register_late_lint_pass(box join_absolute_paths::JoinAbsolutePaths);
+    reg.register_late_lint_pass(box unchecked_duration_subtraction::UncheckedDurationSubtraction);
+    reg.register_late_lint_pass(box missing_assert_message::MissingAssertMessage::default());
+    reg.register_late_lint_pass(box redundant_clone::RedundantClone);
+    reg.register_late_lint_pass(box await_holding_lock::AwaitHoldingLock);
 
     reg.register_lint_group("clippy_restriction", vec![
+        allow_attributes::ALLOW_ATTRIBUTES_WITHOUT_REASON,
         arithmetic::FLOAT_ARITHMETIC,
         arithmetic::INTEGER_ARITHMETIC,
         assign_ops::ASSIGN_OPS,
         else_if_without_else::ELSE_IF_WITHOUT_ELSE,
+        exhaustive_items::EXHAUSTIVE_ENUMS,
+        exhaustive_items::EXHAUSTIVE_STRUCTS,
+        impl_trait_in_params::IMPL_TRAIT_IN_PARAMS,
+        implicit_return::IMPLICIT_RETURN,
         inherent_impl::MULTIPLE_INHERENT_IMPL,
+        large_include_file::LARGE_INCLUDE_FILE,
+        let_underscore::LET_UNDERSCORE_MUST_USE,
+        let_underscore::LET_UNDERSCORE_UNTYPED,
         literal_representation::DECIMAL_LITERAL_REPRESENTATION,
         mem_forget::MEM_FORGET,
         methods::CLONE_ON_REF_PTR,
@@ -423,13 +573,26 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         methods::RESULT_UNWRAP_USED,
         methods::WRONG_PUB_SELF_CONVENTION,
         misc::FLOAT_CMP_CONST,
+        misc_early::SEPARATED_LITERAL_SUFFIX,
+        misc_early::UNSEPARATED_LITERAL_SUFFIX,
+        missing_assert_message::MISSING_ASSERT_MESSAGE,
         missing_doc::MISSING_DOCS_IN_PRIVATE_ITEMS,
         missing_inline::MISSING_INLINE_IN_PUBLIC_ITEMS,
+        missing_trait_methods::MISSING_TRAIT_METHODS,
+        panic_unimplemented::PANIC,
+        panic_unimplemented::TODO,
         panic_unimplemented::UNIMPLEMENTED,
+        panic_unimplemented::UNREACHABLE,
+        pub_use::PUB_USE,
+        redundant_type_annotations::REDUNDANT_TYPE_ANNOTATIONS,
+        same_name_method::SAME_NAME_METHOD,
         shadow::SHADOW_REUSE,
         shadow::SHADOW_SAME,
         shadow::SHADOW_UNRELATED,
         strings::STRING_ADD,
+        types::FN_TO_NUMERIC_CAST_ANY,
+        types::RC_BUFFER,
+        types::RC_MUTEX,
         write::PRINT_STDOUT,
         write::USE_DEBUG,
     ]);
@@ -438,27 +601,44 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         attrs::INLINE_ALWAYS,
         copies::MATCH_SAME_ARMS,
         default_trait_access::DEFAULT_TRAIT_ACCESS,
+        derive::DERIVE_PARTIAL_EQ_WITHOUT_EQ,
         derive::EXPL_IMPL_CLONE_ON_COPY,
         doc::DOC_MARKDOWN,
+        doc::MISSING_ERRORS_DOC,
+        doc::MISSING_PANICS_DOC,
         empty_enum::EMPTY_ENUM,
         enum_glob_use::ENUM_GLOB_USE,
         enum_variants::PUB_ENUM_VARIANT_NAMES,
         enum_variants::STUTTER,
+        excessive_bools::FN_PARAMS_EXCESSIVE_BOOLS,
+        excessive_bools::STRUCT_EXCESSIVE_BOOLS,
+        functions::TOO_MANY_LINES,
         if_not_else::IF_NOT_ELSE,
         indexing_slicing::INDEXING_SLICING,
         infinite_iter::MAYBE_INFINITE_ITER,
         items_after_statements::ITEMS_AFTER_STATEMENTS,
+        iter_without_into_iter::INTO_ITER_WITHOUT_ITER,
+        iter_without_into_iter::ITER_WITHOUT_INTO_ITER,
+        large_stack_arrays::LARGE_STACK_ARRAYS,
+        manual_assert::MANUAL_ASSERT,
         matches::SINGLE_MATCH_ELSE,
         methods::FILTER_MAP,
         methods::OPTION_MAP_UNWRAP_OR,
         methods::OPTION_MAP_UNWRAP_OR_ELSE,
         methods::RESULT_MAP_UNWRAP_OR_ELSE,
         misc::USED_UNDERSCORE_BINDING,
-        misc_early::UNSEPARATED_LITERAL_SUFFIX,
+        mismatching_type_param_order::MISMATCHING_TYPE_PARAM_ORDER,
         mut_mut::MUT_MUT,
         needless_continue::NEEDLESS_CONTINUE,
+        needless_for_each::NEEDLESS_FOR_EACH,
         non_expressive_names::SIMILAR_NAMES,
+        pass_by_ref_or_value::LARGE_TYPES_PASSED_BY_VALUE,
+        pub_underscore_fields::PUB_UNDERSCORE_FIELDS,
+        redundant_else::REDUNDANT_ELSE,
+        ref_option::REF_OPTION,
         replace_consts::REPLACE_CONSTS,
+        return_self_not_must_use::RETURN_SELF_NOT_MUST_USE,
+        semicolon_if_nothing_returned::SEMICOLON_IF_NOTHING_RETURNED,
         strings::STRING_ADD_ASSIGN,
         types::CAST_POSSIBLE_TRUNCATION,
         types::CAST_POSSIBLE_WRAP,
@@ -466,9 +646,14 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         types::CAST_SIGN_LOSS,
         types::INVALID_UPCAST_COMPARISONS,
         types::LINKEDLIST,
+        types::REF_AS_PTR,
         unicode::NON_ASCII_LITERAL,
         unicode::UNICODE_NOT_NFC,
+        unnecessary_box_returns::UNNECESSARY_BOX_RETURNS,
+        unnecessary_owned_cow::UNNECESSARY_OWNED_COW,
+        unused_self::UNUSED_SELF,
         use_self::USE_SELF,
+        zero_sized_map_values::ZERO_SIZED_MAP_VALUES,
     ]);
 
     reg.register_lint_group("clippy_internal", vec![
@@ -481,7 +666,10 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         assign_ops::ASSIGN_OP_PATTERN,
         assign_ops::MISREFACTORED_ASSIGN_OP,
         attrs::DEPRECATED_SEMVER,
+        attrs::MAYBE_MISUSED_CFG,
+        attrs::NON_MINIMAL_CFG,
         attrs::USELESS_ATTRIBUTE,
+        await_holding_lock::AWAIT_HOLDING_LOCK,
         bit_mask::BAD_BIT_MASK,
         bit_mask::INEFFECTIVE_BIT_MASK,
         bit_mask::VERBOSE_BIT_MASK,
@@ -492,10 +680,13 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         booleans::NONMINIMAL_BOOL,
         bytecount::NAIVE_BYTECOUNT,
         collapsible_if::COLLAPSIBLE_IF,
+        comparison_chain::COMPARISON_CHAIN,
         const_static_lifetime::CONST_STATIC_LIFETIME,
         copies::IF_SAME_THEN_ELSE,
         copies::IFS_SAME_COND,
+        crate_in_macro_def::CRATE_IN_MACRO_DEF,
         cyclomatic_complexity::CYCLOMATIC_COMPLEXITY,
+        derivable_impls::DERIVABLE_IMPLS,
         derive::DERIVE_HASH_XOR_EQ,
         double_comparison::DOUBLE_COMPARISONS,
         double_parens::DOUBLE_PARENS,
@@ -503,6 +694,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         drop_forget_ref::DROP_REF,
         drop_forget_ref::FORGET_COPY,
         drop_forget_ref::FORGET_REF,
+        duplicated_attributes::DUPLICATED_ATTRIBUTES,
         duration_subsec::DURATION_SUBSEC,
         entry::MAP_ENTRY,
         enum_clike::ENUM_CLIKE_UNPORTABLE_VARIANT,
@@ -521,6 +713,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         formatting::POSSIBLE_MISSING_COMMA,
         formatting::SUSPICIOUS_ASSIGNMENT_FORMATTING,
         formatting::SUSPICIOUS_ELSE_FORMATTING,
+        from_over_into::FROM_OVER_INTO,
         functions::NOT_UNSAFE_PTR_ARG_DEREF,
         functions::TOO_MANY_ARGUMENTS,
         identity_conversion::IDENTITY_CONVERSION,
@@ -529,15 +722,20 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         indexing_slicing::OUT_OF_BOUNDS_INDEXING,
         infallible_destructuring_match::INFALLIBLE_DESTRUCTURING_MATCH,
         infinite_iter::INFINITE_ITER,
+        init_numbered_fields::INIT_NUMBERED_FIELDS,
         inline_fn_without_body::INLINE_FN_WITHOUT_BODY,
         int_plus_one::INT_PLUS_ONE,
         invalid_ref::INVALID_REF,
+        large_const_arrays::LARGE_CONST_ARRAYS,
         large_enum_variant::LARGE_ENUM_VARIANT,
         len_zero::LEN_WITHOUT_IS_EMPTY,
         len_zero::LEN_ZERO,
         let_if_seq::USELESS_LET_IF_SEQ,
         lifetimes::EXTRA_UNUSED_LIFETIMES,
         lifetimes::NEEDLESS_LIFETIMES,
+        lifetimes::REDUNDANT_STATIC_BOUNDS,
+        lifetimes::TRIVIAL_LIFETIME_BOUNDS,
+        lifetimes::UNIFIABLE_LIFETIMES,
         literal_representation::INCONSISTENT_DIGIT_GROUPING,
         literal_representation::LARGE_DIGIT_GROUPS,
         literal_representation::UNREADABLE_LITERAL,
@@ -551,6 +749,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         loops::ITER_NEXT_LOOP,
         loops::MANUAL_MEMCPY,
         loops::MUT_RANGE_BOUND,
+        loops::NEEDLESS_COLLECT,
         loops::NEEDLESS_RANGE_LOOP,
         loops::NEVER_LOOP,
         loops::REVERSE_RANGE_LOOP,
@@ -558,6 +757,8 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         loops::WHILE_IMMUTABLE_CONDITION,
         loops::WHILE_LET_LOOP,
         loops::WHILE_LET_ON_ITERATOR,
+        manual_non_exhaustive::MANUAL_NON_EXHAUSTIVE,
+        manual_retain::MANUAL_RETAIN,
         map_clone::MAP_CLONE,
         map_unit_fn::OPTION_MAP_UNIT_FN,
         map_unit_fn::RESULT_MAP_UNIT_FN,
@@ -572,6 +773,8 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         methods::CLONE_DOUBLE_REF,
         methods::CLONE_ON_COPY,
         methods::EXPECT_FUN_CALL,
+        methods::EXPENSIVE_CONSTRUCTION_IN_LOOPS,
+        methods::EXTEND_WITH_DRAIN,
         methods::FILTER_NEXT,
         methods::GET_UNWRAP,
         methods::ITER_CLONED_COLLECT,
@@ -585,7 +788,9 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         methods::SHOULD_IMPLEMENT_TRAIT,
         methods::SINGLE_CHAR_PATTERN,
         methods::STRING_EXTEND_CHARS,
+        methods::SUSPICIOUS_COMMAND_ARG_SPACE,
         methods::TEMPORARY_CSTRING_AS_PTR,
+        methods::UNIT_HASH,
         methods::UNNECESSARY_FOLD,
         methods::USELESS_ASREF,
         methods::WRONG_SELF_CONVENTION,
@@ -605,11 +810,13 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         misc_early::REDUNDANT_CLOSURE_CALL,
         misc_early::UNNEEDED_FIELD_PATTERN,
         misc_early::ZERO_PREFIXED_LITERAL,
+        mut_key::MUTABLE_KEY_TYPE,
         mut_reference::UNNECESSARY_MUT_PASSED,
         mutex_atomic::MUTEX_ATOMIC,
         needless_bool::BOOL_COMPARISON,
         needless_bool::NEEDLESS_BOOL,
         needless_borrowed_ref::NEEDLESS_BORROWED_REFERENCE,
+        needless_late_init::NEEDLESS_LATE_INIT,
         needless_pass_by_value::NEEDLESS_PASS_BY_VALUE,
         needless_update::NEEDLESS_UPDATE,
         neg_cmp_op_on_partial_ord::NEG_CMP_OP_ON_PARTIAL_ORD,
@@ -618,15 +825,20 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         new_without_default::NEW_WITHOUT_DEFAULT_DERIVE,
         no_effect::NO_EFFECT,
         no_effect::UNNECESSARY_OPERATION,
+        non_canonical_impls::NON_CANONICAL_CLONE_IMPL,
+        non_canonical_impls::NON_CANONICAL_PARTIAL_ORD_IMPL,
         non_copy_const::BORROW_INTERIOR_MUTABLE_CONST,
         non_copy_const::DECLARE_INTERIOR_MUTABLE_CONST,
         non_expressive_names::JUST_UNDERSCORES_AND_DIGITS,
         non_expressive_names::MANY_SINGLE_CHAR_NAMES,
+        non_octal_unix_permissions::NON_OCTAL_UNIX_PERMISSIONS,
+        non_send_fields_in_send_ty::NON_SEND_FIELDS_IN_SEND_TY,
         ok_if_let::IF_LET_SOME_RESULT,
         open_options::NONSENSICAL_OPEN_OPTIONS,
         overflow_check_conditional::OVERFLOW_CHECK_CONDITIONAL,
         panic_unimplemented::PANIC_PARAMS,
         partialeq_ne_impl::PARTIALEQ_NE_IMPL,
+        pass_by_ref_or_value::TRIVIALLY_COPY_PASS_BY_REF,
         precedence::PRECEDENCE,
         ptr::CMP_NULL,
         ptr::MUT_FROM_REF,
@@ -635,6 +847,8 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         ranges::ITERATOR_STEP_BY_ZERO,
         ranges::RANGE_MINUS_ONE,
         ranges::RANGE_ZIP_WITH_LEN,
+        read_zero_byte_vec::READ_ZERO_BYTE_VEC,
+        recursive_format_impl::RECURSIVE_FORMAT_IMPL,
         redundant_field_names::REDUNDANT_FIELD_NAMES,
         reference::DEREF_ADDROF,
         reference::REF_IN_DEREF,
@@ -643,7 +857,11 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         regex::TRIVIAL_REGEX,
         returns::LET_AND_RETURN,
         returns::NEEDLESS_RETURN,
+        seek::SEEK_FROM_CURRENT,
+        seek::SEEK_TO_START_INSTEAD_OF_REWIND,
+        self_assignment::SELF_ASSIGNMENT,
         serde_api::SERDE_API_MISUSE,
+        size_of_in_element_count::SIZE_OF_IN_ELEMENT_COUNT,
         strings::STRING_LIT_AS_BYTES,
         suspicious_trait_impl::SUSPICIOUS_ARITHMETIC_IMPL,
         suspicious_trait_impl::SUSPICIOUS_OP_ASSIGN_IMPL,
@@ -657,12 +875,12 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         transmute::TRANSMUTE_INT_TO_FLOAT,
         transmute::TRANSMUTE_PTR_TO_PTR,
         transmute::TRANSMUTE_PTR_TO_REF,
+        transmute::TRANSMUTE_UNDEFINED_REPR,
         transmute::USELESS_TRANSMUTE,
         transmute::WRONG_TRANSMUTE,
-        trivially_copy_pass_by_ref::TRIVIALLY_COPY_PASS_BY_REF,
         types::ABSURD_EXTREME_COMPARISONS,
         types::BORROWED_BOX,
-        types::BOX_VEC,
+        types::BOX_COLLECTION,
         types::CAST_LOSSLESS,
         types::CAST_PTR_ALIGNMENT,
         types::CHAR_LIT_AS_U8,
@@ -671,11 +889,14 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         types::IMPLICIT_HASHER,
         types::LET_UNIT_VALUE,
         types::OPTION_OPTION,
+        types::REDUNDANT_ALLOCATION,
         types::TYPE_COMPLEXITY,
         types::UNIT_ARG,
         types::UNIT_CMP,
         types::UNNECESSARY_CAST,
+        unchecked_duration_subtraction::UNCHECKED_DURATION_SUBTRACTION,
         unicode::ZERO_WIDTH_SPACE,
+        uninhabited_references::UNINHABITED_REFERENCES,
         unsafe_removed_from_name::UNSAFE_REMOVED_FROM_NAME,
         unused_io_amount::UNUSED_IO_AMOUNT,
         unused_label::UNUSED_LABEL,
@@ -691,12 +912,16 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
 
     reg.register_lint_group("clippy_style", vec![
         assign_ops::ASSIGN_OP_PATTERN,
+        attrs::NON_MINIMAL_CFG,
         bit_mask::VERBOSE_BIT_MASK,
         blacklisted_name::BLACKLISTED_NAME,
         block_in_if_condition::BLOCK_IN_IF_CONDITION_EXPR,
         block_in_if_condition::BLOCK_IN_IF_CONDITION_STMT,
         collapsible_if::COLLAPSIBLE_IF,
+        comparison_chain::COMPARISON_CHAIN,
         const_static_lifetime::CONST_STATIC_LIFETIME,
+        crate_in_macro_def::CRATE_IN_MACRO_DEF,
+        duplicated_attributes::DUPLICATED_ATTRIBUTES,
         enum_variants::ENUM_VARIANT_NAMES,
         enum_variants::MODULE_INCEPTION,
         eq_op::OP_REF,
@@ -704,8 +929,10 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         excessive_precision::EXCESSIVE_PRECISION,
         formatting::SUSPICIOUS_ASSIGNMENT_FORMATTING,
         formatting::SUSPICIOUS_ELSE_FORMATTING,
+        from_over_into::FROM_OVER_INTO,
         if_let_redundant_pattern_matching::IF_LET_REDUNDANT_PATTERN_MATCHING,
         infallible_destructuring_match::INFALLIBLE_DESTRUCTURING_MATCH,
+        init_numbered_fields::INIT_NUMBERED_FIELDS,
         len_zero::LEN_WITHOUT_IS_EMPTY,
         len_zero::LEN_ZERO,
         let_if_seq::USELESS_LET_IF_SEQ,
@@ -718,6 +945,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         loops::FOR_KV_MAP,
         loops::NEEDLESS_RANGE_LOOP,
         loops::WHILE_LET_ON_ITERATOR,
+        manual_non_exhaustive::MANUAL_NON_EXHAUSTIVE,
         map_clone::MAP_CLONE,
         matches::MATCH_BOOL,
         matches::MATCH_OVERLAPPING_ARM,
@@ -744,10 +972,12 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         misc_early::MIXED_CASE_HEX_LITERALS,
         misc_early::UNNEEDED_FIELD_PATTERN,
         mut_reference::UNNECESSARY_MUT_PASSED,
+        needless_late_init::NEEDLESS_LATE_INIT,
         needless_pass_by_value::NEEDLESS_PASS_BY_VALUE,
         neg_multiply::NEG_MULTIPLY,
         new_without_default::NEW_WITHOUT_DEFAULT,
         new_without_default::NEW_WITHOUT_DEFAULT_DERIVE,
+        non_canonical_impls::NON_CANONICAL_CLONE_IMPL,
         non_expressive_names::JUST_UNDERSCORES_AND_DIGITS,
         non_expressive_names::MANY_SINGLE_CHAR_NAMES,
         ok_if_let::IF_LET_SOME_RESULT,
@@ -778,6 +1008,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         assign_ops::MISREFACTORED_ASSIGN_OP,
         booleans::NONMINIMAL_BOOL,
         cyclomatic_complexity::CYCLOMATIC_COMPLEXITY,
+        derivable_impls::DERIVABLE_IMPLS,
         double_comparison::DOUBLE_COMPARISONS,
         double_parens::DOUBLE_PARENS,
         duration_subsec::DURATION_SUBSEC,
@@ -791,6 +1022,9 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         int_plus_one::INT_PLUS_ONE,
         lifetimes::EXTRA_UNUSED_LIFETIMES,
         lifetimes::NEEDLESS_LIFETIMES,
+        lifetimes::REDUNDANT_STATIC_BOUNDS,
+        lifetimes::TRIVIAL_LIFETIME_BOUNDS,
+        lifetimes::UNIFIABLE_LIFETIMES,
         loops::EXPLICIT_COUNTER_LOOP,
         loops::MUT_RANGE_BOUND,
         loops::WHILE_LET_LOOP,
@@ -818,6 +1052,8 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         ranges::RANGE_ZIP_WITH_LEN,
         reference::DEREF_ADDROF,
         reference::REF_IN_DEREF,
+        seek::SEEK_FROM_CURRENT,
+        seek::SEEK_TO_START_INSTEAD_OF_REWIND,
         swap::MANUAL_SWAP,
         temporary_assignment::TEMPORARY_ASSIGNMENT,
         transmute::CROSSPOINTER_TRANSMUTE,
@@ -842,7 +1078,9 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
     reg.register_lint_group("clippy_correctness", vec![
         approx_const::APPROX_CONSTANT,
         attrs::DEPRECATED_SEMVER,
+        attrs::MAYBE_MISUSED_CFG,
         attrs::USELESS_ATTRIBUTE,
+        await_holding_lock::AWAIT_HOLDING_LOCK,
         bit_mask::BAD_BIT_MASK,
         bit_mask::INEFFECTIVE_BIT_MASK,
         booleans::LOGIC_BUG,
@@ -869,27 +1107,40 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         loops::REVERSE_RANGE_LOOP,
         loops::WHILE_IMMUTABLE_CONDITION,
         methods::CLONE_DOUBLE_REF,
+        methods::SUSPICIOUS_COMMAND_ARG_SPACE,
         methods::TEMPORARY_CSTRING_AS_PTR,
+        methods::UNIT_HASH,
         minmax::MIN_MAX,
         misc::CMP_NAN,
         misc::FLOAT_CMP,
         misc::MODULO_ONE,
+        mut_key::MUTABLE_KEY_TYPE,
+        non_canonical_impls::NON_CANONICAL_PARTIAL_ORD_IMPL,
         non_copy_const::BORROW_INTERIOR_MUTABLE_CONST,
         non_copy_const::DECLARE_INTERIOR_MUTABLE_CONST,
+        non_octal_unix_permissions::NON_OCTAL_UNIX_PERMISSIONS,
+        non_send_fields_in_send_ty::NON_SEND_FIELDS_IN_SEND_TY,
         open_options::NONSENSICAL_OPEN_OPTIONS,
         ptr::MUT_FROM_REF,
         ranges::ITERATOR_STEP_BY_ZERO,
+        read_zero_byte_vec::READ_ZERO_BYTE_VEC,
+        recursive_format_impl::RECURSIVE_FORMAT_IMPL,
         regex::INVALID_REGEX,
+        self_assignment::SELF_ASSIGNMENT,
         serde_api::SERDE_API_MISUSE,
+        size_of_in_element_count::SIZE_OF_IN_ELEMENT_COUNT,
         suspicious_trait_impl::SUSPICIOUS_ARITHMETIC_IMPL,
         suspicious_trait_impl::SUSPICIOUS_OP_ASSIGN_IMPL,
         swap::ALMOST_SWAPPED,
+        transmute::TRANSMUTE_UNDEFINED_REPR,
         transmute::WRONG_TRANSMUTE,
         types::ABSURD_EXTREME_COMPARISONS,
         types::CAST_PTR_ALIGNMENT,
         types::FN_TO_NUMERIC_CAST_WITH_TRUNCATION,
         types::UNIT_CMP,
+        unchecked_duration_subtraction::UNCHECKED_DURATION_SUBTRACTION,
         unicode::ZERO_WIDTH_SPACE,
+        uninhabited_references::UNINHABITED_REFERENCES,
         unused_io_amount::UNUSED_IO_AMOUNT,
     ]);
 
@@ -897,17 +1148,23 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         bytecount::NAIVE_BYTECOUNT,
         entry::MAP_ENTRY,
         escape::BOXED_LOCAL,
+        large_const_arrays::LARGE_CONST_ARRAYS,
         large_enum_variant::LARGE_ENUM_VARIANT,
         loops::MANUAL_MEMCPY,
+        loops::NEEDLESS_COLLECT,
         loops::UNUSED_COLLECT,
+        manual_retain::MANUAL_RETAIN,
         methods::EXPECT_FUN_CALL,
+        methods::EXPENSIVE_CONSTRUCTION_IN_LOOPS,
+        methods::EXTEND_WITH_DRAIN,
         methods::ITER_NTH,
         methods::OR_FUN_CALL,
         methods::SINGLE_CHAR_PATTERN,
         misc::CMP_OWNED,
         mutex_atomic::MUTEX_ATOMIC,
-        trivially_copy_pass_by_ref::TRIVIALLY_COPY_PASS_BY_REF,
-        types::BOX_VEC,
+        pass_by_ref_or_value::TRIVIALLY_COPY_PASS_BY_REF,
+        types::BOX_COLLECTION,
+        types::REDUNDANT_ALLOCATION,
         vec::USELESS_VEC,
     ]);
 
@@ -916,11 +1173,20 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
     ]);
 
     reg.register_lint_group("clippy_nursery", vec![
+        attrs::EMPTY_LINE_AFTER_DOC_COMMENTS,
         attrs::EMPTY_LINE_AFTER_OUTER_ATTR,
+        cognitive_complexity::COGNITIVE_COMPLEXITY,
         fallible_impl_from::FALLIBLE_IMPL_FROM,
+        join_absolute_paths::JOIN_ABSOLUTE_PATHS,
+        missing_const_for_fn::MISSING_CONST_FOR_FN,
         mutex_atomic::MUTEX_INTEGER,
         needless_borrow::NEEDLESS_BORROW,
+        path_buf_push_overwrite::PATH_BUF_PUSH_OVERWRITE,
         ranges::RANGE_PLUS_ONE,
+        redundant_clone::REDUNDANT_CLONE,
+        redundant_pub_crate::REDUNDANT_PUB_CRATE,
+        trailing_empty_array::TRAILING_EMPTY_ARRAY,
+        unused_rounding::UNUSED_ROUNDING,
         unwrap::PANICKING_UNWRAP,
         unwrap::UNNECESSARY_UNWRAP,
     ]);
@@ -3,20 +3,31 @@ use rustc::lint::*;
 use rustc::hir::def::Def;
 use rustc::hir::*;
 use rustc::hir::intravisit::*;
+use rustc::hir::map as hir_map;
+use rustc::ty;
+use rustc_errors::Applicability;
 use std::collections::{HashMap, HashSet};
-use syntax::codemap::Span;
-use crate::utils::{in_external_macro, last_path_segment, span_lint};
+use syntax::ast::NodeId;
+use syntax::codemap::{BytePos, Span};
+use crate::utils::{in_external_macro, in_macro, last_path_segment, match_def_path, paths, snippet, snippet_opt,
+            span_lint, span_lint_and_then};
 use syntax::symbol::keywords;
 
 /// **What it does:** Checks for lifetime annotations which can be removed by
-/// relying on lifetime elision.
+/// relying on lifetime elision, in function signatures as well as in the
+/// `for<..>` binders of function pointer types and `Fn` trait bounds. On Rust
+/// 2018, it also checks for lifetimes which are used only once and could be
+/// anonymous, i.e. `'_`.
 ///
 /// **Why is this bad?** The additional lifetimes make the code look more
 /// complicated, while there is nothing out of the ordinary going on. Removing
 /// them leads to more readable code.
 ///
 /// **Known problems:** Potential false negatives: we bail out if the function
-/// has a `where` clause where lifetimes are mentioned.
+/// has a `where` clause which mentions the lifetimes that could be elided.
+///
+/// The configuration option `avoid_breaking_exported_api` can be set to keep
+/// the explicit lifetimes of the exported API.
 ///
 /// **Example:**
 /// ```rust
@@ -30,7 +41,7 @@ declare_clippy_lint! {
 }
 
 /// **What it does:** Checks for lifetimes in generics that are never used
-/// anywhere else.
+/// anywhere else, in function and impl definitions.
 ///
 /// **Why is this bad?** The additional lifetimes make the code look more
 /// complicated, while there is nothing out of the ordinary going on. Removing
@@ -38,45 +49,226 @@ declare_clippy_lint! {
 ///
 /// **Known problems:** None.
 ///
+/// The configuration option `avoid_breaking_exported_api` can be set to keep
+/// the lifetimes of the exported API.
+///
 /// **Example:**
 /// ```rust
 /// fn unused_lifetime<'a>(x: u8) { .. }
+///
+/// impl<'a> Foo { .. }
 /// ```
 declare_clippy_lint! {
     pub EXTRA_UNUSED_LIFETIMES,
     complexity,
-    "unused lifetimes in function definitions"
+    "unused lifetimes in function and impl definitions"
+}
+
+/// **What it does:** Checks for `'static` bounds which are already implied:
+/// on boxed trait objects like `Box<dyn Trait + 'static>` in signatures, where
+/// `'static` is the default lifetime, and on generics like `T: Any + 'static`,
+/// where another bound requires `'static` already.
+///
+/// **Why is this bad?** The additional bounds make the code look more
+/// complicated, while they don't change anything. Removing them leads to more
+/// readable code.
+///
+/// **Known problems:** The default lifetime of a trait object is not `'static`
+/// if the trait itself has a lifetime bound. We only recognize such traits by
+/// their lifetime parameters.
+///
+/// **Example:**
+/// ```rust
+/// fn boxed(f: Box<dyn Fn() + 'static>) { .. }
+///
+/// fn generic<T: Any + 'static>(x: T) { .. }
+/// ```
+declare_clippy_lint! {
+    pub REDUNDANT_STATIC_BOUNDS,
+    complexity,
+    "`'static` bounds on boxed trait objects or generics which are already implied"
+}
+
+/// **What it does:** Checks for lifetime bounds which are trivially
+/// satisfied, like `'a: 'a` or `'static: 'a`, and for bounds in the `where`
+/// clause which are already declared in the generic parameters.
+///
+/// **Why is this bad?** The bounds don't restrict anything, they only make
+/// the code look more complicated.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// fn reflexive<'a: 'a>(x: &'a u8) { .. }
+///
+/// fn repeated<'a, 'b: 'a>(x: &'a u8, y: &'b u8) where 'b: 'a { .. }
+/// ```
+declare_clippy_lint! {
+    pub TRIVIAL_LIFETIME_BOUNDS,
+    complexity,
+    "lifetime bounds which are trivially satisfied or declared twice"
+}
+
+/// **What it does:** Checks for lifetime parameters of functions which are
+/// bounded by another lifetime parameter, like `'b: 'a`, and are otherwise
+/// only used as the lifetime of references in the parameter types.
+///
+/// **Why is this bad?** Such references can be coerced to the shorter
+/// lifetime, so the additional lifetime parameter doesn't make the function
+/// accept anything more. Using the shorter lifetime leads to more readable
+/// code.
+///
+/// **Known problems:** Lifetimes without such a bound are not linted: in
+/// `fn f<'a, 'b>(x: &'a u8, y: &'b u8) -> &'a u8`, the return type doesn't
+/// borrow from `y`, which unifying the lifetimes would change.
+///
+/// **Example:**
+/// ```rust
+/// fn longest<'a, 'b: 'a>(x: &'a str, y: &'b str) -> &'a str { .. }
+/// ```
+/// can be written as
+/// ```rust
+/// fn longest<'a>(x: &'a str, y: &'a str) -> &'a str { .. }
+/// ```
+declare_clippy_lint! {
+    pub UNIFIABLE_LIFETIMES,
+    complexity,
+    "lifetime parameters which could be replaced by a shorter lifetime they outlive"
 }
 
 #[derive(Copy, Clone)]
-pub struct LifetimePass;
+pub struct LifetimePass {
+    avoid_breaking_exported_api: bool,
+}
+
+impl LifetimePass {
+    pub fn new(avoid_breaking_exported_api: bool) -> Self {
+        Self {
+            avoid_breaking_exported_api,
+        }
+    }
+
+    /// Whether the lifetimes of the item with the given id are part of the
+    /// exported API which the configuration asks us to keep.
+    fn is_kept_exported(self, cx: &LateContext, id: NodeId) -> bool {
+        self.avoid_breaking_exported_api && cx.access_levels.is_exported(id)
+    }
+}
 
 impl LintPass for LifetimePass {
     fn get_lints(&self) -> LintArray {
-        lint_array!(NEEDLESS_LIFETIMES, EXTRA_UNUSED_LIFETIMES)
+        lint_array!(
+            NEEDLESS_LIFETIMES,
+            EXTRA_UNUSED_LIFETIMES,
+            REDUNDANT_STATIC_BOUNDS,
+            TRIVIAL_LIFETIME_BOUNDS,
+            UNIFIABLE_LIFETIMES
+        )
     }
 }
 
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for LifetimePass {
     fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
-        if let ItemFn(ref decl, _, ref generics, id) = item.node {
-            check_fn_inner(cx, decl, Some(id), generics, item.span);
+        if !in_macro(item.span) {
+            walk_item(&mut StaticBoundVisitor { cx }, item);
+        }
+        if self.is_kept_exported(cx, item.id) {
+            return;
+        }
+        match item.node {
+            ItemFn(ref decl, header, ref generics, id) => {
+                check_fn_inner(cx, decl, header.asyncness, Some(id), generics, item.span)
+            },
+            ItemImpl(_, _, _, ref generics, ..) => report_extra_item_lifetimes(cx, item, generics, "impl"),
+            _ => (),
         }
     }
 
     fn check_impl_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx ImplItem) {
+        if !in_macro(item.span) {
+            walk_impl_item(&mut StaticBoundVisitor { cx }, item);
+        }
+        if self.is_kept_exported(cx, item.id) {
+            return;
+        }
         if let ImplItemKind::Method(ref sig, id) = item.node {
-            check_fn_inner(cx, &sig.decl, Some(id), &item.generics, item.span);
+            check_fn_inner(cx, &sig.decl, sig.header.asyncness, Some(id), &item.generics, item.span);
         }
     }
 
     fn check_trait_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx TraitItem) {
+        if !in_macro(item.span) {
+            walk_trait_item(&mut StaticBoundVisitor { cx }, item);
+        }
+        if self.is_kept_exported(cx, item.id) {
+            return;
+        }
         if let TraitItemKind::Method(ref sig, ref body) = item.node {
             let body = match *body {
                 TraitMethod::Required(_) => None,
                 TraitMethod::Provided(id) => Some(id),
             };
-            check_fn_inner(cx, &sig.decl, body, &item.generics, item.span);
+            check_fn_inner(cx, &sig.decl, sig.header.asyncness, body, &item.generics, item.span);
+        }
+    }
+
+    fn check_ty(&mut self, cx: &LateContext<'a, 'tcx>, ty: &'tcx Ty) {
+        if let TyBareFn(ref bare_fn) = ty.node {
+            let output = match bare_fn.decl.output {
+                Return(ref ty) => Some(&**ty),
+                DefaultReturn(_) => None,
+            };
+            check_binder(cx, &bare_fn.generic_params, &bare_fn.decl.inputs, output, ty.span);
+        }
+    }
+
+    fn check_poly_trait_ref(&mut self, cx: &LateContext<'a, 'tcx>, poly: &'tcx PolyTraitRef, _: TraitBoundModifier) {
+        // `Fn(A, B) -> C` is `Fn<(A, B), Output = C>`
+        if_chain! {
+            if let Some(args) = poly.trait_ref.path.segments.last().and_then(|seg| seg.args.as_ref());
+            if args.parenthesized;
+            if let Some(&GenericArg::Type(Ty { node: TyTup(ref inputs), .. })) = args.args.first();
+            if let Some(output) = args.bindings.first();
+            then {
+                check_binder(cx, &poly.bound_generic_params, inputs, Some(&output.ty), poly.span);
+            }
+        }
+    }
+
+    fn check_generics(&mut self, cx: &LateContext<'a, 'tcx>, generics: &'tcx Generics) {
+        // the outlives bounds of the generic parameters, which don't need to be repeated
+        let mut declared = HashSet::new();
+        for param in &generics.params {
+            let name = param.name.ident().name;
+            check_trivial_lifetime_bounds(cx, name, param.span, &param.bounds, None);
+            for bound in &param.bounds {
+                if let GenericBound::Outlives(ref lt) = *bound {
+                    declared.insert((name, lt.name.ident().name));
+                }
+            }
+        }
+        for predicate in &generics.where_clause.predicates {
+            match *predicate {
+                WherePredicate::RegionPredicate(ref pred) => check_trivial_lifetime_bounds(
+                    cx,
+                    pred.lifetime.name.ident().name,
+                    pred.lifetime.span,
+                    &pred.bounds,
+                    Some(&declared),
+                ),
+                WherePredicate::BoundPredicate(ref pred) => if_chain! {
+                    if pred.bound_generic_params.is_empty();
+                    if let TyPath(QPath::Resolved(None, ref path)) = pred.bounded_ty.node;
+                    if let Def::TyParam(_) = path.def;
+                    if path.segments.len() == 1;
+                    then {
+                        let name = path.segments[0].ident.name;
+                        check_trivial_lifetime_bounds(cx, name, pred.bounded_ty.span, &pred.bounds, Some(&declared));
+                    }
+                },
+                WherePredicate::EqPredicate(_) => (),
+            }
         }
     }
 }
@@ -92,13 +284,28 @@ enum RefLt {
 fn check_fn_inner<'a, 'tcx>(
     cx: &LateContext<'a, 'tcx>,
     decl: &'tcx FnDecl,
+    asyncness: IsAsync,
     body: Option<BodyId>,
     generics: &'tcx Generics,
     span: Span,
 ) {
-    if in_external_macro(cx, span) || has_where_lifetimes(cx, &generics.where_clause) {
+    if in_external_macro(cx, span) {
         return;
     }
+    // the return type as written in the source, `None` if omitted
+    let output = match decl.output {
+        Return(ref ty) if asyncness == IsAsync::Async => match async_output_ty(cx, ty) {
+            Some(ty) => Some(ty),
+            None => return,
+        },
+        Return(ref ty) => Some(&**ty),
+        DefaultReturn(_) => None,
+    };
+    let where_lts = match where_lifetimes(cx, &generics.where_clause) {
+        Some(lts) => lts,
+        None => return,
+    };
+    check_unifiable_lifetimes(cx, decl, output, body, generics, &where_lts, span);
 
     let mut bounds_lts = Vec::new();
     let types = generics.params.iter().filter_map(|param| match param.kind {
@@ -135,24 +342,154 @@ fn check_fn_inner<'a, 'tcx>(
             }
         }
     }
-    if could_use_elision(cx, decl, body, &generics.params, bounds_lts) {
-        span_lint(
+    // a `where` clause mentioning an elided lifetime would need to keep its name
+    match could_use_elision(cx, &decl.inputs, output, asyncness, body, &generics.params, bounds_lts) {
+        Some(Elision::Full(ref lts)) if lts.is_disjoint(&where_lts) => {
+            report_elidable_lifetimes(
+                cx,
+                decl,
+                output,
+                generics,
+                span,
+                lts,
+                "explicit lifetimes given in parameter types where they could be elided",
+                "elide the lifetimes",
+            );
+        },
+        Some(Elision::Anonymous(lts)) => {
+            // `'_` is only idiomatic since Rust 2018
+            let lts = lts.difference(&where_lts).cloned().collect::<HashSet<_>>();
+            if !lts.is_empty() && cx.sess().rust_2018() {
+                report_elidable_lifetimes(
+                    cx,
+                    decl,
+                    output,
+                    generics,
+                    span,
+                    &lts,
+                    "explicit lifetimes given in parameter types where they could be anonymous",
+                    "use anonymous lifetimes",
+                );
+            }
+        },
+        Some(Elision::Full(_)) | None => (),
+    }
+    report_extra_lifetimes(cx, decl, output, generics);
+}
+
+/// Checks the `for<..>` binder of a function pointer type or of a `Fn` trait
+/// bound, like `for<'a> fn(&'a u8) -> &'a u8`.
+fn check_binder<'a, 'tcx: 'a>(
+    cx: &LateContext<'a, 'tcx>,
+    params: &'tcx [GenericParam],
+    inputs: &'tcx [Ty],
+    output: Option<&'tcx Ty>,
+    span: Span,
+) {
+    if params.is_empty() || in_macro(span) {
+        return;
+    }
+    let elision = could_use_elision(cx, inputs, output, IsAsync::NotAsync, None, params, Vec::new());
+    if let Some(Elision::Full(lts)) = elision {
+        span_lint_and_then(
             cx,
             NEEDLESS_LIFETIMES,
             span,
             "explicit lifetimes given in parameter types where they could be elided",
+            |db| {
+                if_chain! {
+                    if let Some(sig) = snippet_opt(cx, span);
+                    if let Some(binder_span) = binder_span(params, span, &sig);
+                    if let Some(sugg) = elided_signature(inputs, output, params, binder_span, &lts, span, &sig);
+                    then {
+                        db.span_suggestion_with_applicability(
+                            span,
+                            "elide the lifetimes",
+                            sugg,
+                            Applicability::MachineApplicable,
+                        );
+                    }
+                }
+            },
         );
     }
-    report_extra_lifetimes(cx, decl, generics);
 }
 
+/// Returns the span of `for<..>` at the start of `span`, including the
+/// whitespace after it.
+fn binder_span(params: &[GenericParam], span: Span, snippet: &str) -> Option<Span> {
+    let last = params.last()?;
+    let offset = (last.span.hi() - span.lo()).0 as usize;
+    let end = offset + snippet.get(offset..)?.find('>')? + 1;
+    let end = end + snippet[end..].len() - snippet[end..].trim_left().len();
+    Some(span.with_hi(span.lo() + BytePos(end as u32)))
+}
+
+/// Returns the `Output` of the `impl Future` which the return type of an
+/// `async fn` desugars to, i.e. the return type written in the source.
+fn async_output_ty<'a, 'tcx: 'a>(cx: &LateContext<'a, 'tcx>, ty: &'tcx Ty) -> Option<&'tcx Ty> {
+    if let TyPath(QPath::Resolved(_, ref path)) = ty.node {
+        if let Def::Existential(def_id) = path.def {
+            let node_id = cx.tcx.hir.as_local_node_id(def_id)?;
+            if let ItemExistential(ref exist_ty) = cx.tcx.hir.expect_item(node_id).node {
+                return exist_ty
+                    .bounds
+                    .iter()
+                    .filter_map(|bound| match *bound {
+                        GenericBound::Trait(ref trait_ref, _) => {
+                            trait_ref.trait_ref.path.segments.last()?.args.as_ref()
+                        },
+                        GenericBound::Outlives(_) => None,
+                    })
+                    .flat_map(|args| args.bindings.iter())
+                    .find(|binding| binding.ident.name == "Output")
+                    .map(|binding| &*binding.ty);
+            }
+        }
+    }
+    None
+}
+
+fn report_elidable_lifetimes<'tcx>(
+    cx: &LateContext,
+    decl: &'tcx FnDecl,
+    output: Option<&'tcx Ty>,
+    generics: &Generics,
+    span: Span,
+    lts: &HashSet<Name>,
+    msg: &str,
+    help: &str,
+) {
+    match signature(cx, decl, output, span) {
+        Some((sig_span, sig)) => span_lint_and_then(cx, NEEDLESS_LIFETIMES, sig_span, msg, |db| {
+            let sugg = elided_signature(&decl.inputs, output, &generics.params, generics.span, lts, sig_span, &sig);
+            if let Some(sugg) = sugg {
+                db.span_suggestion_with_applicability(sig_span, help, sugg, Applicability::MachineApplicable);
+            }
+        }),
+        None => span_lint(cx, NEEDLESS_LIFETIMES, span, msg),
+    }
+}
+
+/// The named lifetimes of a function signature which can be left out.
+enum Elision {
+    /// All of them, they can be elided.
+    Full(HashSet<Name>),
+    /// The ones used only once in the parameter types, they can be replaced
+    /// by `'_`.
+    Anonymous(HashSet<Name>),
+}
+
+/// Returns the named lifetimes which can be elided, or at least anonymized.
 fn could_use_elision<'a, 'tcx: 'a>(
     cx: &LateContext<'a, 'tcx>,
-    func: &'tcx FnDecl,
+    inputs: &'tcx [Ty],
+    output: Option<&'tcx Ty>,
+    asyncness: IsAsync,
     body: Option<BodyId>,
     named_generics: &'tcx [GenericParam],
     bounds_lts: Vec<&'tcx Lifetime>,
-) -> bool {
+) -> Option<Elision> {
     // There are two scenarios where elision works:
     // * no output references, all input references have different LT
     // * output references, exactly one input reference with same LT
@@ -167,22 +504,16 @@ fn could_use_elision<'a, 'tcx: 'a>(
     let mut output_visitor = RefVisitor::new(cx);
 
     // extract lifetimes in input argument types
-    for arg in &func.inputs {
+    for arg in inputs {
         input_visitor.visit_ty(arg);
     }
     // extract lifetimes in output type
-    if let Return(ref ty) = func.output {
+    if let Some(ty) = output {
         output_visitor.visit_ty(ty);
     }
 
-    let input_lts = match input_visitor.into_vec() {
-        Some(lts) => lts_from_bounds(lts, bounds_lts.into_iter()),
-        None => return false,
-    };
-    let output_lts = match output_visitor.into_vec() {
-        Some(val) => val,
-        None => return false,
-    };
+    let input_lts = lts_from_bounds(input_visitor.into_vec()?, bounds_lts.into_iter());
+    let output_lts = output_visitor.into_vec()?;
 
     if let Some(body_id) = body {
         let mut checker = BodyLifetimeChecker {
@@ -190,19 +521,19 @@ fn could_use_elision<'a, 'tcx: 'a>(
         };
         checker.visit_expr(&cx.tcx.hir.body(body_id).value);
         if checker.lifetimes_used_in_body {
-            return false;
+            return None;
         }
     }
 
     // check for lifetimes from higher scopes
     for lt in input_lts.iter().chain(output_lts.iter()) {
         if !allowed_lts.contains(lt) {
-            return false;
+            return None;
         }
     }
 
     // no input lifetimes? easy case!
-    if input_lts.is_empty() {
+    let elidable = if input_lts.is_empty() {
         false
     } else if output_lts.is_empty() {
         // no output lifetimes, check distinctness of input lifetimes
@@ -212,7 +543,7 @@ fn could_use_elision<'a, 'tcx: 'a>(
             .iter()
             .all(|lt| *lt == RefLt::Unnamed || *lt == RefLt::Static);
         if unnamed_and_static {
-            return false;
+            return None;
         }
         // we have no output reference, so we only need all distinct lifetimes
         input_lts.len() == unique_lifetimes(&input_lts)
@@ -220,9 +551,8 @@ fn could_use_elision<'a, 'tcx: 'a>(
         // we have output references, so we need one input reference,
         // and all output lifetimes must be the same
         if unique_lifetimes(&output_lts) > 1 {
-            return false;
-        }
-        if input_lts.len() == 1 {
+            false
+        } else if input_lts.len() == 1 {
             match (&input_lts[0], &output_lts[0]) {
                 (&RefLt::Named(n1), &RefLt::Named(n2)) if n1 == n2 => true,
                 (&RefLt::Named(_), &RefLt::Unnamed) => true,
@@ -232,7 +562,286 @@ fn could_use_elision<'a, 'tcx: 'a>(
         } else {
             false
         }
+    };
+
+    // `async fn` doesn't support multiple elided lifetimes in its parameters
+    let supported = |lts: &HashSet<Name>| {
+        let elided_inputs = input_lts.iter().filter(|lt| match **lt {
+            RefLt::Unnamed => true,
+            RefLt::Named(name) => lts.contains(&name),
+            RefLt::Static => false,
+        });
+        asyncness == IsAsync::NotAsync || elided_inputs.count() <= 1
+    };
+
+    if elidable {
+        let lts = input_lts
+            .iter()
+            .chain(output_lts.iter())
+            .filter_map(|lt| match *lt {
+                RefLt::Named(name) => Some(name),
+                _ => None,
+            })
+            .collect();
+        if supported(&lts) {
+            return Some(Elision::Full(lts));
+        }
+    }
+
+    // a lifetime which isn't related to any other can still be anonymous
+    let used_once = |lt: &RefLt| input_lts.iter().filter(|&x| x == lt).count() == 1 && !output_lts.contains(lt);
+    let anonymous = input_lts
+        .iter()
+        .filter_map(|lt| match *lt {
+            RefLt::Named(name) if used_once(lt) => Some(name),
+            _ => None,
+        })
+        .collect::<HashSet<_>>();
+    if anonymous.is_empty() || !supported(&anonymous) {
+        None
+    } else {
+        Some(Elision::Anonymous(anonymous))
+    }
+}
+
+/// Returns the span and source of the signature of the function, i.e. of
+/// everything before the `where` clause or the body.
+fn signature(cx: &LateContext, decl: &FnDecl, output: Option<&Ty>, span: Span) -> Option<(Span, String)> {
+    if in_macro(span) {
+        return None;
+    }
+    let end = match (output, &decl.output) {
+        (Some(ty), _) => ty.span.hi(),
+        (None, &DefaultReturn(sp)) => sp.lo(),
+        (None, &Return(_)) => return None,
+    };
+    if end <= span.lo() || end > span.hi() {
+        return None;
+    }
+    let sig = snippet_opt(cx, span.with_hi(end))?;
+    let sig = sig.trim_right().to_string();
+    Some((span.with_hi(span.lo() + BytePos(sig.len() as u32)), sig))
+}
+
+/// Builds the signature `sig` at `sig_span` without the given lifetimes, in
+/// the generic parameters as well as in the types of the parameters and the
+/// return type.
+fn elided_signature<'tcx>(
+    inputs: &'tcx [Ty],
+    output: Option<&'tcx Ty>,
+    params: &[GenericParam],
+    params_span: Span,
+    elidable_lts: &HashSet<Name>,
+    sig_span: Span,
+    sig: &str,
+) -> Option<String> {
+    // the spans to replace, with their replacement
+    let mut edits = Vec::new();
+
+    let is_elided = |param: &GenericParam| match param.kind {
+        GenericParamKind::Lifetime { .. } => elidable_lts.contains(&param.name.ident().name),
+        GenericParamKind::Type { .. } => false,
+    };
+    if params.iter().all(|param| is_elided(param)) {
+        edits.push((params_span, ""));
+    } else {
+        for (i, pair) in params.windows(2).enumerate() {
+            if is_elided(&pair[0]) && !params[i + 1..].iter().all(|param| is_elided(param)) {
+                // `'a, T` becomes `T`
+                edits.push((pair[0].span.with_hi(pair[1].span.lo()), ""));
+            }
+        }
+        // `'a, 'b` becomes `'a`, lifetimes always come first
+        if let Some(kept) = params.iter().rposition(|param| !is_elided(param)) {
+            if kept + 1 < params.len() {
+                if !params[kept].bounds.is_empty() {
+                    return None;
+                }
+                let last = &params[params.len() - 1];
+                edits.push((last.span.with_lo(params[kept].span.hi()), ""));
+            }
+        }
+    }
+
+    let mut visitor = ElidableLifetimeVisitor {
+        lts: elidable_lts,
+        refs: Vec::new(),
+        paths: Vec::new(),
+    };
+    for input in inputs {
+        visitor.visit_ty(input);
+    }
+    if let Some(ty) = output {
+        visitor.visit_ty(ty);
     }
+    // `&'a T` becomes `&T`, `Foo<'a>` becomes `Foo<'_>`
+    edits.extend(visitor.refs.into_iter().map(|span| (span, "")));
+    edits.extend(visitor.paths.into_iter().map(|span| (span, "'_")));
+
+    apply_edits(edits, sig_span, sig)
+}
+
+/// Replaces the given spans of the signature `sig` at `sig_span`. Returns
+/// `None` if any of them is outside of the signature.
+fn apply_edits<S: AsRef<str>>(edits: Vec<(Span, S)>, sig_span: Span, sig: &str) -> Option<String> {
+    let mut edits = edits
+        .into_iter()
+        .map(|(span, replacement)| {
+            if in_macro(span) || span.lo() < sig_span.lo() || span.hi() > sig_span.hi() {
+                return None;
+            }
+            let lo = (span.lo() - sig_span.lo()).0 as usize;
+            let mut hi = (span.hi() - sig_span.lo()).0 as usize;
+            if replacement.as_ref().is_empty() && sig[..lo].ends_with('&') {
+                // remove the space after the lifetime of a reference
+                hi += sig[hi..].len() - sig[hi..].trim_left().len();
+            }
+            Some((lo, hi, replacement))
+        })
+        .collect::<Option<Vec<_>>>()?;
+    edits.sort_by_key(|&(lo, _, _)| lo);
+
+    let mut sugg = sig.to_string();
+    for &(lo, hi, ref replacement) in edits.iter().rev() {
+        sugg.replace_range(lo..hi, replacement.as_ref());
+    }
+    Some(sugg)
+}
+
+/// Lints the lifetime parameters which are bounded by another lifetime
+/// parameter of the function and only used for references in the parameter
+/// types, like `'b` in `fn f<'a, 'b: 'a>(x: &'a u8, y: &'b u8) -> &'a u8`.
+fn check_unifiable_lifetimes<'a, 'tcx: 'a>(
+    cx: &LateContext<'a, 'tcx>,
+    decl: &'tcx FnDecl,
+    output: Option<&'tcx Ty>,
+    body: Option<BodyId>,
+    generics: &'tcx Generics,
+    where_lts: &HashSet<Name>,
+    span: Span,
+) {
+    let lifetimes = generics
+        .params
+        .iter()
+        .filter_map(|param| match param.kind {
+            GenericParamKind::Lifetime { .. } => Some(param.name.ident().name),
+            GenericParamKind::Type { .. } => None,
+        })
+        .collect::<HashSet<_>>();
+
+    // the lifetimes with a single bound on another one, with that bound
+    let mut candidates = Vec::new();
+    for param in &generics.params {
+        if let GenericParamKind::Lifetime { .. } = param.kind {
+            if param.bounds.len() != 1 {
+                continue;
+            }
+            if let GenericBound::Outlives(ref bound) = param.bounds[0] {
+                let name = param.name.ident().name;
+                let target = bound.name.ident().name;
+                if target != name && lifetimes.contains(&target) && !where_lts.contains(&name) {
+                    candidates.push((name, target));
+                }
+            }
+        }
+    }
+    if candidates.is_empty() {
+        return;
+    }
+
+    if let Some(body_id) = body {
+        let mut checker = BodyLifetimeChecker {
+            lifetimes_used_in_body: false,
+        };
+        checker.visit_expr(&cx.tcx.hir.body(body_id).value);
+        if checker.lifetimes_used_in_body {
+            return;
+        }
+    }
+
+    let mut visitor = LifetimeUseVisitor {
+        refs: Vec::new(),
+        others: HashSet::new(),
+        abort: false,
+    };
+    for input in &decl.inputs {
+        match input.node {
+            TyRptr(ref lt, ref mut_ty) if !lt.is_elided() => {
+                visitor.refs.push((lt.name.ident().name, lt.span));
+                visitor.visit_ty(&mut_ty.ty);
+            },
+            _ => visitor.visit_ty(input),
+        }
+    }
+    if let Some(ty) = output {
+        visitor.visit_ty(ty);
+    }
+    for param in &generics.params {
+        walk_generic_param(&mut visitor, param);
+    }
+    if visitor.abort {
+        return;
+    }
+
+    candidates.retain(|&(name, _)| !visitor.others.contains(&name) && visitor.refs.iter().any(|&(lt, _)| lt == name));
+    // a lifetime replaced by another one can't be the replacement itself
+    let replaced = candidates.iter().map(|&(name, _)| name).collect::<HashSet<_>>();
+    candidates.retain(|&(_, target)| !replaced.contains(&target));
+    if candidates.is_empty() {
+        return;
+    }
+
+    let msg = "explicit lifetimes given in parameter types where they could be unified";
+    match signature(cx, decl, output, span) {
+        Some((sig_span, sig)) => span_lint_and_then(cx, UNIFIABLE_LIFETIMES, sig_span, msg, |db| {
+            let sugg = unified_signature(cx, generics, &visitor.refs, &candidates, sig_span, &sig);
+            if let Some(sugg) = sugg {
+                db.span_suggestion_with_applicability(
+                    sig_span,
+                    "unify the lifetimes",
+                    sugg,
+                    Applicability::MachineApplicable,
+                );
+            }
+        }),
+        None => span_lint(cx, UNIFIABLE_LIFETIMES, span, msg),
+    }
+}
+
+/// Builds the signature `sig` at `sig_span` without the replaced lifetime
+/// parameters, using their replacement for the references in the parameter
+/// types.
+fn unified_signature(
+    cx: &LateContext,
+    generics: &Generics,
+    refs: &[(Name, Span)],
+    replacements: &[(Name, Name)],
+    sig_span: Span,
+    sig: &str,
+) -> Option<String> {
+    let replacement = |name: Name| replacements.iter().find(|&&(lt, _)| lt == name).map(|&(_, target)| target);
+    let bound_span = |bound: &GenericBound| match *bound {
+        GenericBound::Trait(ref poly, _) => poly.span,
+        GenericBound::Outlives(ref lt) => lt.span,
+    };
+
+    // the span of a parameter doesn't include its bounds
+    let params = generics
+        .params
+        .iter()
+        .filter(|param| replacement(param.name.ident().name).is_none())
+        .map(|param| {
+            let span = param.bounds.last().map_or(param.span, |bound| param.span.to(bound_span(bound)));
+            snippet_opt(cx, span)
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    let mut edits = vec![(generics.span, format!("<{}>", params.join(", ")))];
+    edits.extend(
+        refs.iter()
+            .filter_map(|&(name, span)| replacement(name).map(|target| (span, target.to_string()))),
+    );
+    apply_edits(edits, sig_span, sig)
 }
 
 fn allowed_lts_from(named_generics: &[GenericParam]) -> HashSet<RefLt> {
@@ -378,54 +987,131 @@ impl<'a, 'tcx> Visitor<'tcx> for RefVisitor<'a, 'tcx> {
     }
 }
 
-/// Are any lifetimes mentioned in the `where` clause? If yes, we don't try to
-/// reason about elision.
-fn has_where_lifetimes<'a, 'tcx: 'a>(cx: &LateContext<'a, 'tcx>, where_clause: &'tcx WhereClause) -> bool {
+/// Collects the named lifetimes mentioned in the `where` clause, except for
+/// the ones introduced by higher-ranked predicates. Returns `None` if we can't
+/// reason about them.
+fn where_lifetimes<'a, 'tcx: 'a>(cx: &LateContext<'a, 'tcx>, where_clause: &'tcx WhereClause) -> Option<HashSet<Name>> {
+    let mut where_lts = HashSet::new();
     for predicate in &where_clause.predicates {
+        let mut visitor = RefVisitor::new(cx);
+        let mut allowed_lts = HashSet::new();
         match *predicate {
-            WherePredicate::RegionPredicate(..) => return true,
-            WherePredicate::BoundPredicate(ref pred) => {
-                // a predicate like F: Trait or F: for<'a> Trait<'a>
-                let mut visitor = RefVisitor::new(cx);
-                // walk the type F, it may not contain LT refs
-                walk_ty(&mut visitor, &pred.bounded_ty);
-                if !visitor.lts.is_empty() {
-                    return true;
-                }
-                // if the bounds define new lifetimes, they are fine to occur
-                let allowed_lts = allowed_lts_from(&pred.bound_generic_params);
-                // now walk the bounds
+            WherePredicate::RegionPredicate(ref pred) => {
+                // a predicate like 'a: 'b
+                visitor.visit_lifetime(&pred.lifetime);
                 for bound in pred.bounds.iter() {
                     walk_param_bound(&mut visitor, bound);
                 }
-                // and check that all lifetimes are allowed
-                match visitor.into_vec() {
-                    None => return false,
-                    Some(lts) => for lt in lts {
-                        if !allowed_lts.contains(&lt) {
-                            return true;
-                        }
-                    },
+            },
+            WherePredicate::BoundPredicate(ref pred) => {
+                // a predicate like T: 'a, F: Trait or for<'a> F: Trait<'a>
+                visitor.visit_ty(&pred.bounded_ty);
+                for bound in pred.bounds.iter() {
+                    walk_param_bound(&mut visitor, bound);
                 }
+                // if the predicate defines new lifetimes, they are fine to occur
+                allowed_lts = allowed_lts_from(&pred.bound_generic_params);
             },
             WherePredicate::EqPredicate(ref pred) => {
-                let mut visitor = RefVisitor::new(cx);
-                walk_ty(&mut visitor, &pred.lhs_ty);
-                walk_ty(&mut visitor, &pred.rhs_ty);
-                if !visitor.lts.is_empty() {
-                    return true;
-                }
+                visitor.visit_ty(&pred.lhs_ty);
+                visitor.visit_ty(&pred.rhs_ty);
             },
         }
+        for lt in visitor.into_vec()? {
+            if let RefLt::Named(name) = lt {
+                if !allowed_lts.contains(&lt) {
+                    where_lts.insert(name);
+                }
+            }
+        }
     }
-    false
+    Some(where_lts)
+}
+
+/// Collects the spans of the given lifetimes, for references and for other
+/// types respectively.
+struct ElidableLifetimeVisitor<'a> {
+    lts: &'a HashSet<Name>,
+    refs: Vec<Span>,
+    paths: Vec<Span>,
 }
 
-struct LifetimeChecker {
+impl<'a, 'tcx> Visitor<'tcx> for ElidableLifetimeVisitor<'a> {
+    fn visit_ty(&mut self, ty: &'tcx Ty) {
+        if let TyRptr(ref lt, ref mut_ty) = ty.node {
+            if !lt.is_elided() && self.lts.contains(&lt.name.ident().name) {
+                self.refs.push(lt.span);
+                self.visit_ty(&mut_ty.ty);
+                return;
+            }
+        }
+        walk_ty(self, ty);
+    }
+
+    fn visit_lifetime(&mut self, lifetime: &'tcx Lifetime) {
+        if !lifetime.is_elided() && self.lts.contains(&lifetime.name.ident().name) {
+            self.paths.push(lifetime.span);
+        }
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
+
+/// Collects the named lifetimes of references at the top level of the
+/// parameter types, which are added by the caller, and the ones used anywhere
+/// else.
+struct LifetimeUseVisitor {
+    refs: Vec<(Name, Span)>,
+    others: HashSet<Name>,
+    abort: bool,
+}
+
+impl<'tcx> Visitor<'tcx> for LifetimeUseVisitor {
+    fn visit_ty(&mut self, ty: &'tcx Ty) {
+        if let TyPath(QPath::Resolved(_, ref path)) = ty.node {
+            // the lifetimes captured by an `impl Trait` are not spelled out
+            if let Def::Existential(_) = path.def {
+                self.abort = true;
+            }
+        }
+        walk_ty(self, ty);
+    }
+
+    fn visit_lifetime(&mut self, lifetime: &'tcx Lifetime) {
+        if !lifetime.is_elided() {
+            self.others.insert(lifetime.name.ident().name);
+        }
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
+
+struct LifetimeChecker<'a, 'tcx: 'a> {
     map: HashMap<Name, Span>,
+    /// The map to visit nested items and bodies with, if the lifetimes are
+    /// in scope there.
+    hir: Option<&'a hir_map::Map<'tcx>>,
+}
+
+impl<'a, 'tcx> LifetimeChecker<'a, 'tcx> {
+    fn new(generics: &Generics, hir: Option<&'a hir_map::Map<'tcx>>) -> Self {
+        let map = generics
+            .params
+            .iter()
+            .filter_map(|par| match par.kind {
+                GenericParamKind::Lifetime { .. } => Some((par.name.ident().name, par.span)),
+                _ => None,
+            })
+            .collect();
+        Self { map, hir }
+    }
 }
 
-impl<'tcx> Visitor<'tcx> for LifetimeChecker {
+impl<'a, 'tcx> Visitor<'tcx> for LifetimeChecker<'a, 'tcx> {
     // for lifetimes as parameters of generics
     fn visit_lifetime(&mut self, lifetime: &'tcx Lifetime) {
         self.map.remove(&lifetime.name.ident().name);
@@ -442,27 +1128,237 @@ impl<'tcx> Visitor<'tcx> for LifetimeChecker {
         }
     }
     fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
-        NestedVisitorMap::None
+        match self.hir {
+            Some(hir) => NestedVisitorMap::All(hir),
+            None => NestedVisitorMap::None,
+        }
     }
 }
 
-fn report_extra_lifetimes<'a, 'tcx: 'a>(cx: &LateContext<'a, 'tcx>, func: &'tcx FnDecl, generics: &'tcx Generics) {
-    let hs = generics.params.iter()
-        .filter_map(|par| match par.kind {
-            GenericParamKind::Lifetime { .. } => Some((par.name.ident().name, par.span)),
-            _ => None,
-        })
-        .collect();
-    let mut checker = LifetimeChecker { map: hs };
+fn report_extra_lifetimes<'a, 'tcx: 'a>(
+    cx: &LateContext<'a, 'tcx>,
+    func: &'tcx FnDecl,
+    output: Option<&'tcx Ty>,
+    generics: &'tcx Generics,
+) {
+    let mut checker = LifetimeChecker::new(generics, None);
 
     walk_generics(&mut checker, generics);
-    walk_fn_decl(&mut checker, func);
+    for input in &func.inputs {
+        checker.visit_ty(input);
+    }
+    if let Some(ty) = output {
+        checker.visit_ty(ty);
+    }
 
     for &v in checker.map.values() {
         span_lint(cx, EXTRA_UNUSED_LIFETIMES, v, "this lifetime isn't used in the function definition");
     }
 }
 
+/// Reports the lifetimes of the generics of `item` which are neither used in
+/// the item itself nor in its associated items.
+fn report_extra_item_lifetimes<'a, 'tcx: 'a>(
+    cx: &LateContext<'a, 'tcx>,
+    item: &'tcx Item,
+    generics: &'tcx Generics,
+    desc: &str,
+) {
+    if in_macro(item.span) {
+        return;
+    }
+    let mut checker = LifetimeChecker::new(generics, Some(&cx.tcx.hir));
+
+    walk_item(&mut checker, item);
+
+    for &v in checker.map.values() {
+        span_lint(cx, EXTRA_UNUSED_LIFETIMES, v, &format!("this lifetime isn't used in the {}", desc));
+    }
+}
+
+/// Lints the lifetime bounds of `subject` which are trivially satisfied or,
+/// for `where` clauses, already in `declared`.
+fn check_trivial_lifetime_bounds(
+    cx: &LateContext,
+    subject: Name,
+    subject_span: Span,
+    bounds: &[GenericBound],
+    declared: Option<&HashSet<(Name, Name)>>,
+) {
+    let reason = |bound: &GenericBound| match *bound {
+        GenericBound::Outlives(ref lt) if in_macro(lt.span) => None,
+        GenericBound::Outlives(ref lt) => {
+            let name = lt.name.ident().name;
+            if subject == name || subject == keywords::StaticLifetime.name() {
+                Some("this lifetime bound is always satisfied")
+            } else if declared.map_or(false, |declared| declared.contains(&(subject, name))) {
+                Some("this lifetime bound is already declared in the generic parameters")
+            } else {
+                None
+            }
+        },
+        GenericBound::Trait(..) => None,
+    };
+    let msg = match bounds.iter().filter_map(|bound| reason(bound)).next() {
+        Some(msg) => msg,
+        None => return,
+    };
+    let span = |bound: &GenericBound| match *bound {
+        GenericBound::Trait(ref poly, _) => poly.span,
+        GenericBound::Outlives(ref lt) => lt.span,
+    };
+    let span_with_bounds = subject_span.to(span(&bounds[bounds.len() - 1]));
+    let remaining = bounds
+        .iter()
+        .filter(|bound| reason(bound).is_none())
+        .map(|bound| snippet(cx, span(bound), ".."))
+        .collect::<Vec<_>>();
+    span_lint_and_then(cx, TRIVIAL_LIFETIME_BOUNDS, span_with_bounds, msg, |db| {
+        let subject = snippet(cx, subject_span, "..");
+        if !remaining.is_empty() {
+            db.span_suggestion_with_applicability(
+                span_with_bounds,
+                "remove the bound",
+                format!("{}: {}", subject, remaining.join(" + ")),
+                Applicability::MachineApplicable,
+            );
+        } else if declared.is_none() {
+            db.span_suggestion_with_applicability(
+                span_with_bounds,
+                "remove the bound",
+                subject.into_owned(),
+                Applicability::MachineApplicable,
+            );
+        } else {
+            // the predicate would be left without any bounds
+            db.help("remove the predicate");
+        }
+    });
+}
+
+/// Looks for redundant `'static` bounds in the signature of an item, not in
+/// its body, where the default lifetime of trait objects is inferred.
+struct StaticBoundVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for StaticBoundVisitor<'a, 'tcx> {
+    fn visit_ty(&mut self, ty: &'tcx Ty) {
+        if_chain! {
+            if let TyPath(QPath::Resolved(None, ref path)) = ty.node;
+            if let Def::Struct(def_id) = path.def;
+            if match_def_path(self.cx.tcx, def_id, &paths::BOX);
+            if let Some(args) = path.segments.last().and_then(|seg| seg.args.as_ref());
+            if let Some(&GenericArg::Type(ref boxed)) = args.args.first();
+            if let TyTraitObject(ref traits, ref lt) = boxed.node;
+            if lt.name == LifetimeName::Static;
+            // a trait with a lifetime bound changes the default lifetime
+            if !traits.iter().any(|poly| has_named_lifetimes(self.cx, poly));
+            then {
+                let bounds = traits.iter().map(|poly| snippet(self.cx, poly.span, "..")).collect::<Vec<_>>();
+                let dyn_ = if snippet(self.cx, boxed.span, "..").starts_with("dyn ") {
+                    "dyn "
+                } else {
+                    ""
+                };
+                span_lint_and_then(
+                    self.cx,
+                    REDUNDANT_STATIC_BOUNDS,
+                    boxed.span,
+                    "`'static` is already the default lifetime of a boxed trait object",
+                    |db| {
+                        db.span_suggestion_with_applicability(
+                            boxed.span,
+                            "remove the `'static` bound",
+                            format!("{}{}", dyn_, bounds.join(" + ")),
+                            Applicability::MachineApplicable,
+                        );
+                    },
+                );
+            }
+        }
+        walk_ty(self, ty);
+    }
+
+    fn visit_generic_param(&mut self, param: &'tcx GenericParam) {
+        check_implied_static_bound(self.cx, &param.bounds);
+        walk_generic_param(self, param);
+    }
+
+    fn visit_where_predicate(&mut self, predicate: &'tcx WherePredicate) {
+        if let WherePredicate::BoundPredicate(ref pred) = *predicate {
+            check_implied_static_bound(self.cx, &pred.bounds);
+        }
+        walk_where_predicate(self, predicate);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
+
+fn has_named_lifetimes<'a, 'tcx: 'a>(cx: &'a LateContext<'a, 'tcx>, poly: &'tcx PolyTraitRef) -> bool {
+    let mut visitor = RefVisitor::new(cx);
+    visitor.visit_poly_trait_ref(poly, TraitBoundModifier::None);
+    visitor.lts.iter().any(|lt| matches!(lt, RefLt::Named(_)))
+}
+
+/// Lints a `'static` bound in `bounds` which is implied by one of the traits,
+/// like in `T: Any + 'static`.
+fn check_implied_static_bound(cx: &LateContext, bounds: &[GenericBound]) {
+    let span = |bound: &GenericBound| match *bound {
+        GenericBound::Trait(ref poly, _) => poly.span,
+        GenericBound::Outlives(ref lt) => lt.span,
+    };
+    let is_static = |bound: &&GenericBound| match **bound {
+        GenericBound::Outlives(ref lt) => lt.name == LifetimeName::Static,
+        GenericBound::Trait(..) => false,
+    };
+    if_chain! {
+        if bounds.iter().any(|bound| is_static(&bound));
+        // the span of `?Sized` doesn't include the `?`
+        if !bounds.iter().any(|bound| matches!(bound, GenericBound::Trait(_, TraitBoundModifier::Maybe)));
+        if let Some(implying) = bounds.iter().find(|bound| match **bound {
+            GenericBound::Trait(ref poly, _) => implies_static(cx, poly),
+            GenericBound::Outlives(_) => false,
+        });
+        then {
+            let other_bounds = bounds
+                .iter()
+                .filter(|bound| !is_static(bound))
+                .map(|bound| snippet(cx, span(bound), ".."))
+                .collect::<Vec<_>>();
+            let bounds_span = span(&bounds[0]).to(span(&bounds[bounds.len() - 1]));
+            span_lint_and_then(
+                cx,
+                REDUNDANT_STATIC_BOUNDS,
+                bounds_span,
+                &format!("`'static` is already implied by `{}`", snippet(cx, span(implying), "..")),
+                |db| {
+                    db.span_suggestion_with_applicability(
+                        bounds_span,
+                        "remove the `'static` bound",
+                        other_bounds.join(" + "),
+                        Applicability::MachineApplicable,
+                    );
+                },
+            );
+        }
+    }
+}
+
+/// Checks if the trait requires `Self: 'static`, like `Any`.
+fn implies_static(cx: &LateContext, poly: &PolyTraitRef) -> bool {
+    if let Def::Trait(def_id) = poly.trait_ref.path.def {
+        cx.tcx.super_predicates_of(def_id).predicates.iter().any(|pred| match *pred {
+            ty::Predicate::TypeOutlives(ref outlives) => *outlives.skip_binder().1 == ty::ReStatic,
+            _ => false,
+        })
+    } else {
+        false
+    }
+}
+
 struct BodyLifetimeChecker {
     lifetimes_used_in_body: bool,
 }
@@ -13,6 +13,7 @@ use rustc::middle::mem_categorization::Categorization;
 use rustc::middle::mem_categorization::cmt_;
 use rustc::ty::{self, Ty};
 use rustc::ty::subst::Subst;
+use rustc_errors::Applicability;
 use std::collections::{HashMap, HashSet};
 use std::iter::{once, Iterator};
 use syntax::ast;
@@ -21,15 +22,18 @@ use crate::utils::{sugg, sext};
 use crate::utils::usage::mutated_variables;
 use crate::consts::{constant, Constant};
 
-use crate::utils::{get_enclosing_block, get_parent_expr, higher, in_external_macro, is_integer_literal, is_refutable,
-            last_path_segment, match_trait_method, match_type, match_var, multispan_sugg, snippet, snippet_opt,
-            span_help_and_lint, span_lint, span_lint_and_sugg, span_lint_and_then};
+use crate::utils::{get_enclosing_block, get_parent_expr, higher, in_external_macro, in_macro, is_copy,
+            is_integer_literal, is_refutable, last_path_segment, match_trait_method, match_type, match_var,
+            multispan_sugg, snippet, snippet_opt, span_help_and_lint, span_lint, span_lint_and_sugg,
+            span_lint_and_then};
 use crate::utils::paths;
 
 /// **What it does:** Checks for for-loops that manually copy items between
 /// slices that could be optimized by having a memcpy.
 ///
-/// **Why is this bad?** It is not as fast as a memcpy.
+/// **Why is this bad?** It is not as fast as a memcpy. The loop can be replaced
+/// by `copy_from_slice` if the elements are `Copy`, or by `clone_from_slice`
+/// otherwise.
 ///
 /// **Known problems:** None.
 ///
@@ -376,6 +380,36 @@ declare_clippy_lint! {
     "variables used within while expression are not mutated in the body"
 }
 
+/// **What it does:** Checks for functions collecting an iterator when the
+/// collected value is only used to get its length, check for emptiness or
+/// membership, or to be iterated over again.
+///
+/// **Why is this bad?** The iterator adaptors `count()`, `next().is_none()`
+/// and `any()` give the same result without allocating the intermediate
+/// collection.
+///
+/// **Known problems:** When the collection is bound to a variable, the
+/// suggestion moves the iterator to where the binding is used. This changes
+/// the order of the side effects of the iterator chain, and does not compile
+/// if the iterator borrows something which is mutated in between.
+///
+/// **Example:**
+/// ```rust
+/// let len = iterator.collect::<Vec<_>>().len();
+/// // should be
+/// let len = iterator.count();
+///
+/// let v: Vec<_> = iterator.collect();
+/// if v.is_empty() { .. }
+/// // should be
+/// if iterator.next().is_none() { .. }
+/// ```
+declare_clippy_lint! {
+    pub NEEDLESS_COLLECT,
+    perf,
+    "collecting an iterator when collect is not needed"
+}
+
 #[derive(Copy, Clone)]
 pub struct Pass;
 
@@ -399,6 +433,7 @@ impl LintPass for Pass {
             NEVER_LOOP,
             MUT_RANGE_BOUND,
             WHILE_IMMUTABLE_CONDITION,
+            NEEDLESS_COLLECT,
         )
     }
 }
@@ -508,6 +543,12 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
         if let ExprWhile(ref cond, _, _) = expr.node {
             check_infinite_loop(cx, cond, expr);
         }
+
+        check_needless_collect(expr, cx);
+    }
+
+    fn check_block(&mut self, cx: &LateContext<'a, 'tcx>, block: &'tcx Block) {
+        check_needless_collect_indirect_usage(block, cx);
     }
 
     fn check_stmt(&mut self, cx: &LateContext<'a, 'tcx>, stmt: &'tcx Stmt) {
@@ -822,16 +863,18 @@ fn fetch_cloned_fixed_offset_var<'a, 'tcx>(
     get_fixed_offset_var(cx, expr, var)
 }
 
+/// Returns the destination and source of each indexed assignment in the loop
+/// body, and whether the copied elements are `Copy`.
 fn get_indexed_assignments<'a, 'tcx>(
     cx: &LateContext<'a, 'tcx>,
     body: &Expr,
     var: ast::NodeId,
-) -> Vec<(FixedOffsetVar, FixedOffsetVar)> {
+) -> Vec<(FixedOffsetVar, FixedOffsetVar, bool)> {
     fn get_assignment<'a, 'tcx>(
         cx: &LateContext<'a, 'tcx>,
         e: &Expr,
         var: ast::NodeId,
-    ) -> Option<(FixedOffsetVar, FixedOffsetVar)> {
+    ) -> Option<(FixedOffsetVar, FixedOffsetVar, bool)> {
         if let Expr_::ExprAssign(ref lhs, ref rhs) = e.node {
             match (get_fixed_offset_var(cx, lhs, var), fetch_cloned_fixed_offset_var(cx, rhs, var)) {
                 (Some(offset_left), Some(offset_right)) => {
@@ -839,7 +882,7 @@ fn get_indexed_assignments<'a, 'tcx>(
                     if offset_left.var_name == offset_right.var_name {
                         None
                     } else {
-                        Some((offset_left, offset_right))
+                        Some((offset_left, offset_right, is_copy(cx, cx.tables.expr_ty(lhs))))
                     }
                 },
                 _ => None,
@@ -939,7 +982,7 @@ fn detect_manual_memcpy<'a, 'tcx>(
 
             let big_sugg = manual_copies
                 .into_iter()
-                .map(|(dst_var, src_var)| {
+                .map(|(dst_var, src_var, elem_is_copy)| {
                     let start_str = Offset::positive(snippet_opt(cx, start.span).unwrap_or_else(|| "".into()));
                     let dst_offset = print_sum(&start_str, &dst_var.offset);
                     let dst_limit = print_limit(end, dst_var.offset, &dst_var.var_name);
@@ -951,7 +994,13 @@ fn detect_manual_memcpy<'a, 'tcx>(
                         format!("{}[{}..{}]", dst_var.var_name, dst_offset, dst_limit)
                     };
 
-                    format!("{}.clone_from_slice(&{}[{}..{}])", dst, src_var.var_name, src_offset, src_limit)
+                    let method = if elem_is_copy {
+                        "copy_from_slice"
+                    } else {
+                        "clone_from_slice"
+                    };
+
+                    format!("{}.{}(&{}[{}..{}])", dst, method, src_var.var_name, src_offset, src_limit)
                 })
                 .join("\n    ");
 
@@ -2226,3 +2275,174 @@ impl<'a, 'tcx> Visitor<'tcx> for VarCollectorVisitor<'a, 'tcx> {
         NestedVisitorMap::None
     }
 }
+
+const NEEDLESS_COLLECT_MSG: &str = "avoid using `collect()` when not needed";
+
+/// Checks if `ty` is a sequence collection, for which collecting and then
+/// counting or searching the elements is equivalent to doing so on the iterator.
+fn is_sequence_collection(cx: &LateContext, ty: Ty) -> bool {
+    match_type(cx, ty, &paths::VEC)
+        || match_type(cx, ty, &paths::VEC_DEQUE)
+        || match_type(cx, ty, &paths::LINKED_LIST)
+        || match_type(cx, ty, &paths::BINARY_HEAP)
+}
+
+/// Returns the iterator adaptor call equivalent to calling `method` on the
+/// collection, if any.
+fn needless_collect_replacement(cx: &LateContext, method: &PathSegment, args: &[Expr]) -> Option<String> {
+    match (&*method.ident.as_str(), args.len()) {
+        ("len", 1) => Some("count()".to_string()),
+        ("is_empty", 1) => Some("next().is_none()".to_string()),
+        ("contains", 2) => {
+            let contains_arg = snippet(cx, args[1].span, "??");
+            let (deref, pred) = if contains_arg.starts_with('&') {
+                ("", &contains_arg[1..])
+            } else {
+                ("&", &*contains_arg)
+            };
+            let binder = fresh_binder(pred);
+            Some(format!("any(|{}{}| {} == {})", deref, binder, binder, pred))
+        },
+        _ => None,
+    }
+}
+
+/// Returns a name for a closure argument which doesn't shadow any identifier
+/// used in `expr`.
+fn fresh_binder(expr: &str) -> String {
+    let is_used = |name: &str| expr.split(|c: char| c != '_' && !c.is_alphanumeric()).any(|word| word == name);
+    let mut binder = "x".to_string();
+    let mut i = 0;
+    while is_used(&binder) {
+        i += 1;
+        binder = format!("x{}", i);
+    }
+    binder
+}
+
+fn check_needless_collect<'a, 'tcx>(expr: &'tcx Expr, cx: &LateContext<'a, 'tcx>) {
+    if_chain! {
+        if let ExprMethodCall(ref method, _, ref args) = expr.node;
+        if let ExprMethodCall(ref chain_method, chain_span, ref chain_args) = args[0].node;
+        if chain_method.ident.name == "collect" && match_trait_method(cx, &args[0], &paths::ITERATOR);
+        if is_sequence_collection(cx, cx.tables.expr_ty(&args[0]));
+        then {
+            if method.ident.name == "into_iter" && args.len() == 1 {
+                let span = expr.span.with_lo(chain_args[0].span.hi());
+                span_lint_and_then(cx, NEEDLESS_COLLECT, span, NEEDLESS_COLLECT_MSG, |db| {
+                    db.span_suggestion_with_applicability(
+                        span,
+                        "remove these calls",
+                        String::new(),
+                        Applicability::MachineApplicable,
+                    );
+                });
+            } else if let Some(sugg) = needless_collect_replacement(cx, method, args) {
+                let span = expr.span.with_lo(chain_span.lo());
+                span_lint_and_then(cx, NEEDLESS_COLLECT, span, NEEDLESS_COLLECT_MSG, |db| {
+                    db.span_suggestion_with_applicability(
+                        span,
+                        "replace with",
+                        sugg,
+                        Applicability::MachineApplicable,
+                    );
+                });
+            }
+        }
+    }
+}
+
+fn check_needless_collect_indirect_usage<'a, 'tcx>(block: &'tcx Block, cx: &LateContext<'a, 'tcx>) {
+    for (i, stmt) in block.stmts.iter().enumerate() {
+        if_chain! {
+            if let StmtDecl(ref decl, _) = stmt.node;
+            if let DeclLocal(ref local) = decl.node;
+            if let PatKind::Binding(_, id, _, None) = local.pat.node;
+            if let Some(ref init) = local.init;
+            if let ExprMethodCall(ref method, method_span, ref args) = init.node;
+            if method.ident.name == "collect" && match_trait_method(cx, init, &paths::ITERATOR);
+            if is_sequence_collection(cx, cx.tables.pat_ty(&local.pat));
+            if !in_macro(stmt.span);
+            then {
+                let mut visitor = CollectUsageVisitor {
+                    cx,
+                    id,
+                    uses: 0,
+                    usage: None,
+                };
+                for stmt in &block.stmts[i + 1..] {
+                    visitor.visit_stmt(stmt);
+                }
+                if let Some(ref expr) = block.expr {
+                    visitor.visit_expr(expr);
+                }
+
+                if let (1, Some((span, replacement))) = (visitor.uses, visitor.usage) {
+                    let iter = snippet(cx, args[0].span, "..");
+                    let sugg = match replacement {
+                        Some(replacement) => format!("{}.{}", iter, replacement),
+                        None => iter.into_owned(),
+                    };
+                    span_lint_and_then(cx, NEEDLESS_COLLECT, method_span, NEEDLESS_COLLECT_MSG, |db| {
+                        db.span_suggestion_with_applicability(
+                            span,
+                            "use the original iterator instead",
+                            sugg,
+                            Applicability::MaybeIncorrect,
+                        );
+                        db.span_note(local.pat.span, "the binding can be removed afterwards");
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Counts the uses of a collected binding, remembering the span and the
+/// iterator adaptor replacing the use when it could be done on the iterator.
+struct CollectUsageVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    id: NodeId,
+    uses: usize,
+    usage: Option<(Span, Option<String>)>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for CollectUsageVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if var_def_id(self.cx, expr) == Some(self.id) {
+            self.uses += 1;
+            return;
+        }
+
+        if let Some((pat, arg, body)) = higher::for_loop(expr) {
+            if var_def_id(self.cx, arg) == Some(self.id) {
+                self.uses += 1;
+                self.usage = Some((arg.span, None));
+                self.visit_pat(pat);
+                self.visit_expr(body);
+                return;
+            }
+        }
+
+        if let ExprMethodCall(ref method, _, ref args) = expr.node {
+            if var_def_id(self.cx, &args[0]) == Some(self.id) {
+                self.uses += 1;
+                if method.ident.name == "into_iter" && args.len() == 1 {
+                    self.usage = Some((expr.span, None));
+                } else if let Some(replacement) = needless_collect_replacement(self.cx, method, args) {
+                    self.usage = Some((expr.span, Some(replacement)));
+                }
+                for arg in &args[1..] {
+                    self.visit_expr(arg);
+                }
+                return;
+            }
+        }
+
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::OnlyBodies(&self.cx.tcx.hir)
+    }
+}
@@ -0,0 +1,87 @@
+use rustc::lint::*;
+use rustc_errors::Applicability;
+use syntax::ast::*;
+use crate::utils::{in_macro, is_direct_expn_of, snippet_opt, span_lint_and_then};
+use crate::utils::sugg::Sugg;
+
+/// **What it does:** Detects `if`-then-`panic!` that can be replaced with
+/// `assert!`.
+///
+/// **Why is this bad?** `assert!` is simpler than `if`-then-`panic!`.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// let sad_people: Vec<&str> = vec![];
+/// if !sad_people.is_empty() {
+///     panic!("there are sad people: {:?}", sad_people);
+/// }
+/// ```
+/// Use instead:
+/// ```rust
+/// let sad_people: Vec<&str> = vec![];
+/// assert!(sad_people.is_empty(), "there are sad people: {:?}", sad_people);
+/// ```
+declare_clippy_lint! {
+    pub MANUAL_ASSERT,
+    pedantic,
+    "`panic!` and only a `panic!` in `if`-then statement"
+}
+
+#[derive(Copy, Clone)]
+pub struct ManualAssert;
+
+impl LintPass for ManualAssert {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MANUAL_ASSERT)
+    }
+}
+
+impl EarlyLintPass for ManualAssert {
+    fn check_expr(&mut self, cx: &EarlyContext, expr: &Expr) {
+        if_chain! {
+            if !in_macro(expr.span);
+            if let ExprKind::If(ref cond, ref then, None) = expr.node;
+            if then.stmts.len() == 1;
+            if let StmtKind::Expr(ref panic) | StmtKind::Semi(ref panic) = then.stmts[0].node;
+            if let Some(call_site) = is_direct_expn_of(panic.span, "panic");
+            if !in_macro(call_site);
+            if let Some(args) = snippet_opt(cx, call_site).and_then(|call| macro_args(&call));
+            then {
+                let cond_sugg = match cond.node {
+                    ExprKind::Unary(UnOp::Not, ref inner) => Sugg::ast(cx, inner, ".."),
+                    _ => !Sugg::ast(cx, cond, ".."),
+                };
+                let sugg = if args.is_empty() {
+                    format!("assert!({});", cond_sugg)
+                } else {
+                    format!("assert!({}, {});", cond_sugg, args)
+                };
+                span_lint_and_then(
+                    cx,
+                    MANUAL_ASSERT,
+                    expr.span,
+                    "only a `panic!` in `if`-then statement",
+                    |db| {
+                        db.span_suggestion_with_applicability(
+                            expr.span,
+                            "try",
+                            sugg,
+                            Applicability::MachineApplicable,
+                        );
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Returns the arguments in the snippet of a macro call like `panic!(..)`.
+fn macro_args(call: &str) -> Option<String> {
+    let start = call.find(|c| c == '(' || c == '[' || c == '{')?;
+    let args = call[start + 1..].trim_right();
+    // strip the closing delimiter
+    let args = args.get(..args.len().checked_sub(1)?)?;
+    Some(args.trim().to_string())
+}
@@ -0,0 +1,149 @@
+use rustc::hir::*;
+use rustc::lint::*;
+use semver::Version;
+use syntax::ast::Attribute;
+use syntax::attr;
+use syntax::codemap::Span;
+use crate::utils::{in_macro, meets_msrv, span_lint_and_then};
+use crate::utils::sugg::DiagnosticBuilderExt;
+
+/// **What it does:** Checks for manual implementations of the non-exhaustive
+/// pattern.
+///
+/// **Why is this bad?** Using the `#[non_exhaustive]` attribute expresses
+/// better the intent of the code and allows to use the type within its
+/// defining crate without a wildcard arm or a dummy field.
+///
+/// **Known problems:** The `#[non_exhaustive]` attribute still requires the
+/// `non_exhaustive` feature, it is only stable since Rust 1.40. Set the `msrv`
+/// configuration if the crate supports older versions. Only exported types are
+/// checked, as the attribute has no effect inside the defining crate.
+///
+/// **Example:**
+/// ```rust
+/// pub struct S {
+///     pub a: i32,
+///     pub b: i32,
+///     _c: (),
+/// }
+///
+/// pub enum E {
+///     A,
+///     B,
+///     #[doc(hidden)]
+///     _C,
+/// }
+/// ```
+/// Use instead:
+/// ```rust
+/// #[non_exhaustive]
+/// pub struct S {
+///     pub a: i32,
+///     pub b: i32,
+/// }
+///
+/// #[non_exhaustive]
+/// pub enum E {
+///     A,
+///     B,
+/// }
+/// ```
+declare_clippy_lint! {
+    pub MANUAL_NON_EXHAUSTIVE,
+    style,
+    "manual implementations of the non-exhaustive pattern can be simplified using #[non_exhaustive]"
+}
+
+pub struct ManualNonExhaustive {
+    msrv: Option<Version>,
+}
+
+impl ManualNonExhaustive {
+    pub fn new(msrv: Option<Version>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl LintPass for ManualNonExhaustive {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MANUAL_NON_EXHAUSTIVE)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ManualNonExhaustive {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if in_macro(item.span)
+            || !meets_msrv(self.msrv.as_ref(), &Version::new(1, 40, 0))
+            || !cx.access_levels.is_exported(item.id)
+            || attr::contains_name(&item.attrs, "non_exhaustive")
+        {
+            return;
+        }
+        let marker = match item.node {
+            ItemEnum(ref def, _) => find_marker_variant(def).map(|span| (span, "remove this variant")),
+            ItemStruct(ref data, _) => find_marker_field(data).map(|span| (span, "remove this field")),
+            _ => None,
+        };
+        if let Some((marker_span, help)) = marker {
+            span_lint_and_then(
+                cx,
+                MANUAL_NON_EXHAUSTIVE,
+                cx.tcx.def_span(cx.tcx.hir.local_def_id(item.id)),
+                "this seems like a manual implementation of the non-exhaustive pattern",
+                |db| {
+                    db.span_help(marker_span, help);
+                    db.suggest_item_with_attr(cx, item.span, "add the attribute", "#[non_exhaustive]");
+                },
+            );
+        }
+    }
+}
+
+/// Returns the span of the only `#[doc(hidden)]` unit variant of an enum with
+/// other variants, which is how non-exhaustive enums are usually emulated.
+fn find_marker_variant(def: &EnumDef) -> Option<Span> {
+    if def.variants.len() < 2 {
+        return None;
+    }
+    let mut markers = def
+        .variants
+        .iter()
+        .filter(|variant| variant.node.data.is_unit() && is_doc_hidden(&variant.node.attrs));
+    match (markers.next(), markers.next()) {
+        (Some(marker), None) => Some(marker.span),
+        _ => None,
+    }
+}
+
+/// Returns the span of the only private field of a struct when its type is
+/// `()` and all other fields are public, which is how non-exhaustive structs
+/// are usually emulated. Named fields also have to start with an underscore.
+fn find_marker_field(data: &VariantData) -> Option<Span> {
+    let fields = data.fields();
+    if data.is_unit() || fields.len() < 2 {
+        return None;
+    }
+    let mut private_fields = fields.iter().filter(|field| field.vis.node != VisibilityKind::Public);
+    match (private_fields.next(), private_fields.next()) {
+        (Some(field), None) if is_unit_ty(&field.ty) && (data.is_tuple() || field.ident.as_str().starts_with('_')) => {
+            Some(field.span)
+        },
+        _ => None,
+    }
+}
+
+fn is_unit_ty(ty: &Ty) -> bool {
+    match ty.node {
+        TyTup(ref tys) => tys.is_empty(),
+        _ => false,
+    }
+}
+
+fn is_doc_hidden(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        attr.check_name("doc") && match attr.meta_item_list() {
+            None => false,
+            Some(l) => attr::list_contains_name(&l[..], "hidden"),
+        }
+    })
+}
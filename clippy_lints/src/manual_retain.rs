@@ -0,0 +1,87 @@
+use rustc::hir::*;
+use rustc::lint::*;
+use crate::utils::{in_macro, match_trait_method, match_type, paths, snippet, span_lint_and_sugg, SpanlessEq};
+
+/// **What it does:** Checks for code to be replaced by `.retain()`.
+///
+/// **Why is this bad?** `.retain()` is simpler and avoids needless allocation.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// let mut vec = vec![0, 1, 2];
+/// vec = vec.iter().cloned().filter(|x| x % 2 == 0).collect();
+/// vec = vec.into_iter().filter(|x| x % 2 == 0).collect();
+/// ```
+/// Use instead:
+/// ```rust
+/// let mut vec = vec![0, 1, 2];
+/// vec.retain(|x| x % 2 == 0);
+/// ```
+declare_clippy_lint! {
+    pub MANUAL_RETAIN,
+    perf,
+    "filtering a collection into itself instead of calling `retain()`"
+}
+
+const RETAIN_TYPES: [&[&str]; 3] = [&paths::VEC, &paths::VEC_DEQUE, &paths::HASHSET];
+
+#[derive(Copy, Clone)]
+pub struct ManualRetain;
+
+impl LintPass for ManualRetain {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MANUAL_RETAIN)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ManualRetain {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_chain! {
+            if !in_macro(expr.span);
+            if let ExprAssign(ref lhs, ref collect_expr) = expr.node;
+            if let ExprMethodCall(ref collect, _, ref collect_args) = collect_expr.node;
+            if collect.ident.name == "collect" && collect_args.len() == 1;
+            if match_trait_method(cx, collect_expr, &paths::ITERATOR);
+            if let ExprMethodCall(ref filter, _, ref filter_args) = collect_args[0].node;
+            if filter.ident.name == "filter" && filter_args.len() == 2;
+            if match_trait_method(cx, &collect_args[0], &paths::ITERATOR);
+            if let Some(source) = filtered_collection(cx, &filter_args[0]);
+            if SpanlessEq::new(cx).eq_expr(lhs, source);
+            let ty = cx.tables.expr_ty(lhs);
+            if RETAIN_TYPES.iter().any(|path| match_type(cx, ty, path));
+            then {
+                span_lint_and_sugg(
+                    cx,
+                    MANUAL_RETAIN,
+                    expr.span,
+                    "this expression can be written more simply using `.retain()`",
+                    "consider calling `.retain()` instead",
+                    format!(
+                        "{}.retain({})",
+                        snippet(cx, lhs.span, ".."),
+                        snippet(cx, filter_args[1].span, "..")
+                    ),
+                );
+            }
+        }
+    }
+}
+
+/// Returns the collection iterated over by `expr` if it is `x.into_iter()` or
+/// `x.iter().cloned()`, which both yield the elements of `x` by value.
+fn filtered_collection<'e>(cx: &LateContext, expr: &'e Expr) -> Option<&'e Expr> {
+    if let ExprMethodCall(ref method, _, ref args) = expr.node {
+        if method.ident.name == "into_iter" {
+            return Some(&args[0]);
+        } else if method.ident.name == "cloned" && match_trait_method(cx, expr, &paths::ITERATOR) {
+            if let ExprMethodCall(ref iter, _, ref iter_args) = args[0].node {
+                if iter.ident.name == "iter" {
+                    return Some(&iter_args[0]);
+                }
+            }
+        }
+    }
+    None
+}
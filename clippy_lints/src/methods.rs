@@ -3,20 +3,37 @@ use rustc::lint::*;
 use rustc::ty::{self, Ty};
 use rustc::hir::def::Def;
 use std::borrow::Cow;
+use std::collections::HashSet;
 use std::fmt;
 use std::iter;
 use syntax::ast;
 use syntax::codemap::{Span, BytePos};
 use crate::utils::{get_arg_name, get_trait_def_id, implements_trait, in_external_macro, in_macro, is_copy, is_expn_of, is_self,
             is_self_ty, iter_input_pats, last_path_segment, match_def_path, match_path, match_qpath, match_trait_method,
-            match_type, method_chain_args, match_var, return_ty, remove_blocks, same_tys, single_segment_path, snippet,
-            span_lint, span_lint_and_sugg, span_lint_and_then, span_note_and_lint, walk_ptrs_ty, walk_ptrs_ty_depth};
-use crate::utils::paths;
+            match_type, method_chain_args, match_var, opt_def_id, return_ty, remove_blocks, same_tys, single_segment_path,
+            snippet, span_lint, span_lint_and_sugg, span_lint_and_then, span_note_and_lint, walk_ptrs_ty,
+            walk_ptrs_ty_depth};
+use crate::utils::{higher, paths};
 use crate::utils::sugg;
 use crate::consts::{constant, Constant};
 
-#[derive(Clone)]
-pub struct Pass;
+#[derive(Clone, Default)]
+pub struct Pass {
+    expensive_constructors: Vec<String>,
+    /// The loops and per-iteration closures the current expression is in.
+    loops: Vec<(ast::NodeId, Span)>,
+    /// Closures passed to `Iterator` methods, not visited yet.
+    iter_closures: HashSet<ast::NodeId>,
+}
+
+impl Pass {
+    pub fn new(expensive_constructors: Vec<String>) -> Self {
+        Self {
+            expensive_constructors,
+            ..Self::default()
+        }
+    }
+}
 
 /// **What it does:** Checks for `.unwrap()` calls on `Option`s.
 ///
@@ -673,6 +690,115 @@ declare_clippy_lint! {
     "using `fold` when a more succinct alternative exists"
 }
 
+/// **What it does:** Checks for occurrences where one vector gets extended
+/// instead of appended.
+///
+/// **Why is this bad?** Using `append` instead of `extend` is more concise and
+/// faster, since the elements are moved in bulk instead of one by one through
+/// the `Drain` iterator.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// let mut a = vec![1, 2, 3];
+/// let mut b = vec![4, 5, 6];
+///
+/// a.extend(b.drain(..));
+/// ```
+/// Use instead:
+/// ```rust
+/// let mut a = vec![1, 2, 3];
+/// let mut b = vec![4, 5, 6];
+///
+/// a.append(&mut b);
+/// ```
+declare_clippy_lint! {
+    pub EXTEND_WITH_DRAIN,
+    perf,
+    "using `vec.extend(other_vec.drain(..))` instead of `vec.append(&mut other_vec)`"
+}
+
+/// **What it does:** Detects `().hash(_)`.
+///
+/// **Why is this bad?** Hashing a unit value doesn't do anything as the
+/// implementation of `Hash` for `()` is a no-op.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// match my_enum {
+///     Empty => ().hash(&mut state),
+///     WithValue(x) => x.hash(&mut state),
+/// }
+/// ```
+/// Use instead:
+/// ```rust
+/// match my_enum {
+///     Empty => 0_u8.hash(&mut state),
+///     WithValue(x) => x.hash(&mut state),
+/// }
+/// ```
+declare_clippy_lint! {
+    pub UNIT_HASH,
+    correctness,
+    "hashing a unit value, which does nothing"
+}
+
+/// **What it does:** Checks for `Command::arg()` invocations that look like
+/// they should be multiple arguments instead, such as `arg("-t ext2")`.
+///
+/// **Why is this bad?** `Command::arg()` does not split arguments by space.
+/// An argument like `arg("-t ext2")` will be passed as a single argument to
+/// the command, which is likely not what was intended.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// std::process::Command::new("echo").arg("-n hello").spawn().unwrap();
+/// ```
+/// Use instead:
+/// ```rust
+/// std::process::Command::new("echo").args(["-n", "hello"]).spawn().unwrap();
+/// ```
+declare_clippy_lint! {
+    pub SUSPICIOUS_COMMAND_ARG_SPACE,
+    correctness,
+    "single command line argument that looks like it should be multiple arguments"
+}
+
+/// **What it does:** Checks for calls to `Regex::new` (and the other `regex`
+/// constructors) with constant arguments inside loops or closures passed to
+/// `Iterator` methods. Further constructors can be listed in the
+/// `expensive-constructors` configuration option.
+///
+/// **Why is this bad?** Compiling a regex is expensive, and the very same
+/// regex gets compiled again on each iteration.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// for line in text.lines() {
+///     let re = Regex::new("^[a-z]+$").unwrap();
+///     if re.is_match(line) { .. }
+/// }
+/// ```
+/// Use instead:
+/// ```rust
+/// let re = Regex::new("^[a-z]+$").unwrap();
+/// for line in text.lines() {
+///     if re.is_match(line) { .. }
+/// }
+/// ```
+declare_clippy_lint! {
+    pub EXPENSIVE_CONSTRUCTION_IN_LOOPS,
+    perf,
+    "constructing a regex or another expensive value with constant arguments inside a loop"
+}
+
 impl LintPass for Pass {
     fn get_lints(&self) -> LintArray {
         lint_array!(
@@ -705,7 +831,11 @@ impl LintPass for Pass {
             STRING_EXTEND_CHARS,
             ITER_CLONED_COLLECT,
             USELESS_ASREF,
-            UNNECESSARY_FOLD
+            UNNECESSARY_FOLD,
+            EXTEND_WITH_DRAIN,
+            UNIT_HASH,
+            SUSPICIOUS_COMMAND_ARG_SPACE,
+            EXPENSIVE_CONSTRUCTION_IN_LOOPS
         )
     }
 }
@@ -713,11 +843,23 @@ impl LintPass for Pass {
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
     #[allow(cyclomatic_complexity)]
     fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx hir::Expr) {
+        let is_loop = match expr.node {
+            hir::ExprLoop(..) | hir::ExprWhile(..) => true,
+            hir::ExprClosure(..) => self.iter_closures.remove(&expr.id),
+            _ => false,
+        };
+        if is_loop {
+            self.loops.push((expr.id, expr.span));
+        }
+
         if in_macro(expr.span) {
             return;
         }
 
         match expr.node {
+            hir::ExprCall(ref fun, ref args) => {
+                lint_expensive_construction_in_loop(cx, expr, fun, args, &self.loops, &self.expensive_constructors);
+            },
             hir::ExprMethodCall(ref method_call, ref method_span, ref args) => {
                 // Chain calls
                 // GET_UNWRAP needs to be checked before general `UNWRAP` lints
@@ -769,6 +911,18 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
                     lint_asref(cx, expr, "as_mut", arglists[0]);
                 } else if let Some(arglists) = method_chain_args(expr, &["fold"]) {
                     lint_unnecessary_fold(cx, expr, arglists[0]);
+                } else if let Some(arglists) = method_chain_args(expr, &["hash"]) {
+                    lint_unit_hash(cx, expr, arglists[0]);
+                } else if let Some(arglists) = method_chain_args(expr, &["arg"]) {
+                    lint_suspicious_command_arg_space(cx, expr, *method_span, arglists[0]);
+                }
+
+                if match_trait_method(cx, expr, &paths::ITERATOR) {
+                    for arg in &args[1..] {
+                        if let hir::ExprClosure(..) = arg.node {
+                            self.iter_closures.insert(arg.id);
+                        }
+                    }
                 }
 
                 lint_or_fun_call(cx, expr, *method_span, &method_call.ident.as_str(), args);
@@ -802,6 +956,12 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
         }
     }
 
+    fn check_expr_post(&mut self, _: &LateContext<'a, 'tcx>, expr: &'tcx hir::Expr) {
+        if self.loops.last().map_or(false, |&(id, _)| id == expr.id) {
+            self.loops.pop();
+        }
+    }
+
     fn check_impl_item(&mut self, cx: &LateContext<'a, 'tcx>, implitem: &'tcx hir::ImplItem) {
         if in_external_macro(cx, implitem.span) {
             return;
@@ -1126,8 +1286,7 @@ fn lint_clone_on_copy(cx: &LateContext, expr: &hir::Expr, arg: &hir::Expr, arg_t
     }
 
     if is_copy(cx, ty) {
-        let snip;
-        if let Some(snippet) = sugg::Sugg::hir_opt(cx, arg) {
+        let snip = if let Some(snippet) = sugg::Sugg::hir_opt(cx, arg) {
             if let ty::TyRef(..) = cx.tables.expr_ty(arg).sty {
                 let parent = cx.tcx.hir.get_parent_node(expr.id);
                 match cx.tcx.hir.get(parent) {
@@ -1150,13 +1309,13 @@ fn lint_clone_on_copy(cx: &LateContext, expr: &hir::Expr, arg: &hir::Expr, arg_t
                     },
                     _ => {},
                 }
-                snip = Some(("try dereferencing it", format!("{}", snippet.deref())));
+                Some(("try dereferencing it", format!("{}", snippet.deref())))
             } else {
-                snip = Some(("try removing the `clone` call", format!("{}", snippet)));
+                Some(("try removing the `clone` call", format!("{}", snippet)))
             }
         } else {
-            snip = None;
-        }
+            None
+        };
         span_lint_and_then(cx, CLONE_ON_COPY, expr.span, "using `clone` on a `Copy` type", |db| {
             if let Some((text, snip)) = snip {
                 db.span_suggestion(expr.span, text, snip);
@@ -1220,10 +1379,141 @@ fn lint_string_extend(cx: &LateContext, expr: &hir::Expr, args: &[hir::Expr]) {
     }
 }
 
+fn lint_extend_with_drain(cx: &LateContext, expr: &hir::Expr, args: &[hir::Expr]) {
+    let vec_ty = walk_ptrs_ty(cx.tables.expr_ty(&args[0]));
+    if_chain! {
+        if let Some(arglists) = method_chain_args(&args[1], &["drain"]);
+        let drain_args = arglists[0];
+        if drain_args.len() == 2;
+        let (src_ty, src_depth) = walk_ptrs_ty_depth(cx.tables.expr_ty(&drain_args[0]));
+        if match_type(cx, src_ty, &paths::VEC);
+        if same_tys(cx, vec_ty, src_ty);
+        if let Some(range) = higher::range(cx, &drain_args[1]);
+        if range.start.is_none() && range.end.is_none();
+        then {
+            // a `&mut Vec` can be passed to `append` as it is
+            let src = snippet(cx, drain_args[0].span, "..");
+            let src = if src_depth == 0 {
+                format!("&mut {}", src)
+            } else {
+                src.into_owned()
+            };
+            span_lint_and_sugg(
+                cx,
+                EXTEND_WITH_DRAIN,
+                expr.span,
+                "use of `extend` instead of `append` for adding the full range of a second vector",
+                "try this",
+                format!("{}.append({})", snippet(cx, args[0].span, ".."), src),
+            );
+        }
+    }
+}
+
 fn lint_extend(cx: &LateContext, expr: &hir::Expr, args: &[hir::Expr]) {
     let obj_ty = walk_ptrs_ty(cx.tables.expr_ty(&args[0]));
     if match_type(cx, obj_ty, &paths::STRING) {
         lint_string_extend(cx, expr, args);
+    } else if match_type(cx, obj_ty, &paths::VEC) {
+        lint_extend_with_drain(cx, expr, args);
+    }
+}
+
+fn lint_unit_hash(cx: &LateContext, expr: &hir::Expr, hash_args: &[hir::Expr]) {
+    if_chain! {
+        if hash_args.len() == 2;
+        if match_trait_method(cx, expr, &paths::HASH_TRAIT);
+        if let ty::TyTuple(tys) = cx.tables.expr_ty(&hash_args[0]).sty;
+        if tys.is_empty();
+        then {
+            let state = snippet(cx, hash_args[1].span, "..");
+            let msg = match hash_args[0].node {
+                hir::ExprTup(_) => "this call to `hash` on the unit type will do nothing".to_string(),
+                _ => format!(
+                    "this call to `hash` on the unit type will do nothing, `{}` evaluates to `()`",
+                    snippet(cx, hash_args[0].span, "..")
+                ),
+            };
+            span_lint_and_then(cx, UNIT_HASH, expr.span, &msg, |db| {
+                db.span_suggestion(
+                    expr.span,
+                    "remove the call to `hash` or consider using",
+                    format!("0_u8.hash({})", state),
+                );
+                db.note("the implementation of `Hash` for `()` is a no-op");
+            });
+        }
+    }
+}
+
+fn lint_suspicious_command_arg_space(cx: &LateContext, expr: &hir::Expr, method_span: Span, arg_args: &[hir::Expr]) {
+    if_chain! {
+        if arg_args.len() == 2;
+        if match_type(cx, walk_ptrs_ty(cx.tables.expr_ty(&arg_args[0])), &paths::STD_PROCESS_COMMAND);
+        if let hir::ExprLit(ref lit) = arg_args[1].node;
+        if let ast::LitKind::Str(ref s, _) = lit.node;
+        let s = s.as_str();
+        if s.starts_with('-');
+        let parts = s.split(' ').collect::<Vec<_>>();
+        if parts.len() == 2 && !parts[1].is_empty();
+        then {
+            span_lint_and_then(
+                cx,
+                SUSPICIOUS_COMMAND_ARG_SPACE,
+                arg_args[1].span,
+                "single argument that looks like it should be multiple arguments",
+                |db| {
+                    db.span_suggestion(
+                        method_span.with_hi(expr.span.hi()),
+                        "consider splitting the argument",
+                        format!("args([{:?}, {:?}])", parts[0], parts[1]),
+                    );
+                },
+            );
+        }
+    }
+}
+
+const REGEX_CONSTRUCTORS: [&[&str]; 6] = [
+    &paths::REGEX_NEW,
+    &paths::REGEX_BUILDER_NEW,
+    &paths::REGEX_BYTES_NEW,
+    &paths::REGEX_BYTES_BUILDER_NEW,
+    &paths::REGEX_SET_NEW,
+    &paths::REGEX_BYTES_SET_NEW,
+];
+
+fn lint_expensive_construction_in_loop(
+    cx: &LateContext,
+    expr: &hir::Expr,
+    fun: &hir::Expr,
+    args: &[hir::Expr],
+    loops: &[(ast::NodeId, Span)],
+    expensive_constructors: &[String],
+) {
+    if_chain! {
+        if let Some(&(_, loop_span)) = loops.last();
+        if let hir::ExprPath(ref qpath) = fun.node;
+        if let Some(def_id) = opt_def_id(cx.tables.qpath_def(qpath, fun.hir_id));
+        // the value may depend on the iteration otherwise
+        if args.iter().all(|arg| constant(cx, cx.tables, arg).is_some());
+        then {
+            let msg = if REGEX_CONSTRUCTORS.iter().any(|path| match_def_path(cx.tcx, def_id, path)) {
+                "compiling a regex in a loop".to_string()
+            } else {
+                let path = cx.tcx.item_path_str(def_id);
+                if !expensive_constructors.iter().any(|c| *c == path) {
+                    return;
+                }
+                format!("calling `{}` in a loop", path)
+            };
+            span_lint_and_then(cx, EXPENSIVE_CONSTRUCTION_IN_LOOPS, expr.span, &msg, |db| {
+                db.span_help(
+                    loop_span,
+                    "move the construction out of the loop, e.g. into a `lazy_static!` or a binding initialized once",
+                );
+            });
+        }
     }
 }
 
@@ -4,7 +4,8 @@ use std::char;
 use syntax::ast::*;
 use syntax::codemap::Span;
 use syntax::visit::FnKind;
-use crate::utils::{constants, in_external_macro, snippet, snippet_opt, span_help_and_lint, span_lint, span_lint_and_then};
+use crate::utils::{constants, in_external_macro, snippet, snippet_opt, span_help_and_lint, span_lint, span_lint_and_sugg,
+            span_lint_and_then};
 
 /// **What it does:** Checks for structure field patterns bound to wildcards.
 ///
@@ -94,21 +95,51 @@ declare_clippy_lint! {
 
 /// **What it does:** Warns if literal suffixes are not separated by an
 /// underscore.
+/// To enforce unseparated literal suffix style,
+/// see the `separated_literal_suffix` lint.
 ///
-/// **Why is this bad?** It is much less readable.
+/// **Why is this bad?** Suffix style should be consistent.
 ///
-/// **Known problems:** None.
+/// **Known problems:** This lint is only active while the
+/// `literal_suffix_style` configuration is `separated`, the default.
 ///
 /// **Example:**
 /// ```rust
 /// let y = 123832i32;
 /// ```
+/// Use instead:
+/// ```rust
+/// let y = 123832_i32;
+/// ```
 declare_clippy_lint! {
     pub UNSEPARATED_LITERAL_SUFFIX,
-    pedantic,
+    restriction,
     "literals whose suffix is not separated by an underscore"
 }
 
+/// **What it does:** Warns if literal suffixes are separated by an underscore.
+/// To enforce separated literal suffix style,
+/// see the `unseparated_literal_suffix` lint.
+///
+/// **Why is this bad?** Suffix style should be consistent.
+///
+/// **Known problems:** This lint is only active if the `literal_suffix_style`
+/// configuration is set to `unseparated`.
+///
+/// **Example:**
+/// ```rust
+/// let y = 123832_i32;
+/// ```
+/// Use instead:
+/// ```rust
+/// let y = 123832i32;
+/// ```
+declare_clippy_lint! {
+    pub SEPARATED_LITERAL_SUFFIX,
+    restriction,
+    "literals whose suffix is separated by an underscore"
+}
+
 /// **What it does:** Warns if an integral constant literal starts with `0`.
 ///
 /// **Why is this bad?** In some languages (including the infamous C language
@@ -169,7 +200,9 @@ declare_clippy_lint! {
 }
 
 #[derive(Copy, Clone)]
-pub struct MiscEarly;
+pub struct MiscEarly {
+    separate_literal_suffix: bool,
+}
 
 impl LintPass for MiscEarly {
     fn get_lints(&self) -> LintArray {
@@ -180,6 +213,7 @@ impl LintPass for MiscEarly {
             DOUBLE_NEG,
             MIXED_CASE_HEX_LITERALS,
             UNSEPARATED_LITERAL_SUFFIX,
+            SEPARATED_LITERAL_SUFFIX,
             ZERO_PREFIXED_LITERAL,
             BUILTIN_TYPE_SHADOW
         )
@@ -349,6 +383,10 @@ impl EarlyLintPass for MiscEarly {
 }
 
 impl MiscEarly {
+    pub fn new(separate_literal_suffix: bool) -> Self {
+        Self { separate_literal_suffix }
+    }
+
     fn check_lit(self, cx: &EarlyContext, lit: &Lit) {
         if_chain! {
             if let LitKind::Int(value, ..) = lit.node;
@@ -356,16 +394,8 @@ impl MiscEarly {
             if let Some(firstch) = src.chars().next();
             if char::to_digit(firstch, 10).is_some();
             then {
-                let mut prev = '\0';
-                for ch in src.chars() {
-                    if ch == 'i' || ch == 'u' {
-                        if prev != '_' {
-                            span_lint(cx, UNSEPARATED_LITERAL_SUFFIX, lit.span,
-                                        "integer type suffix should be separated by an underscore");
-                        }
-                        break;
-                    }
-                    prev = ch;
+                if let Some(suffix_start) = src.find(|c| c == 'i' || c == 'u') {
+                    check_literal_suffix(cx, lit, &src, suffix_start, "integer", self.separate_literal_suffix);
                 }
                 if src.starts_with("0x") {
                     let mut seen = (false, false);
@@ -409,18 +439,37 @@ impl MiscEarly {
             if let Some(firstch) = src.chars().next();
             if char::to_digit(firstch, 10).is_some();
             then {
-                let mut prev = '\0';
-                for ch in src.chars() {
-                    if ch == 'f' {
-                        if prev != '_' {
-                            span_lint(cx, UNSEPARATED_LITERAL_SUFFIX, lit.span,
-                                        "float type suffix should be separated by an underscore");
-                        }
-                        break;
-                    }
-                    prev = ch;
+                if let Some(suffix_start) = src.find('f') {
+                    check_literal_suffix(cx, lit, &src, suffix_start, "float", self.separate_literal_suffix);
                 }
             }
         }
     }
 }
+
+/// Lints the suffix of a literal, starting at `suffix_start` in its source,
+/// if whether it is separated by an underscore doesn't match the configured
+/// style.
+fn check_literal_suffix(cx: &EarlyContext, lit: &Lit, src: &str, suffix_start: usize, sugg_type: &str, separate: bool) {
+    let (number, suffix) = src.split_at(suffix_start);
+    let is_separated = number.ends_with('_');
+    if is_separated && !separate {
+        span_lint_and_sugg(
+            cx,
+            SEPARATED_LITERAL_SUFFIX,
+            lit.span,
+            &format!("{} type suffix should not be separated by an underscore", sugg_type),
+            "remove the underscore",
+            format!("{}{}", number.trim_right_matches('_'), suffix),
+        );
+    } else if !is_separated && separate {
+        span_lint_and_sugg(
+            cx,
+            UNSEPARATED_LITERAL_SUFFIX,
+            lit.span,
+            &format!("{} type suffix should be separated by an underscore", sugg_type),
+            "add an underscore",
+            format!("{}_{}", number, suffix),
+        );
+    }
+}
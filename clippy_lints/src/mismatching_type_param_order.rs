@@ -0,0 +1,108 @@
+use rustc::hir::*;
+use rustc::hir::def::Def;
+use rustc::lint::*;
+use rustc::ty;
+use crate::utils::{in_macro, span_help_and_lint};
+
+/// **What it does:** Checks for type parameters which are positioned
+/// inconsistently between a type definition and impl block. Specifically, a
+/// parameter in an impl block which has the same name as a parameter in the
+/// type def, but is in a different place.
+///
+/// **Why is this bad?** Type parameters are determined by their position
+/// rather than their name. Mixing up names can lead to confusion and bugs,
+/// since `A` in the impl block stands for what is called `B` in the type.
+///
+/// **Known problems:** Only type parameters used directly as generic arguments
+/// of the implemented type are checked.
+///
+/// **Example:**
+/// ```rust
+/// struct Foo<A, B> {
+///     x: A,
+///     y: B,
+/// }
+/// // inside the impl, B refers to Foo::A
+/// impl<B, A> Foo<B, A> {}
+/// ```
+/// Use instead:
+/// ```rust
+/// struct Foo<A, B> {
+///     x: A,
+///     y: B,
+/// }
+/// impl<A, B> Foo<A, B> {}
+/// ```
+declare_clippy_lint! {
+    pub MISMATCHING_TYPE_PARAM_ORDER,
+    pedantic,
+    "type parameter positioned inconsistently between type def and impl block"
+}
+
+#[derive(Copy, Clone)]
+pub struct TypeParamMismatch;
+
+impl LintPass for TypeParamMismatch {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MISMATCHING_TYPE_PARAM_ORDER)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for TypeParamMismatch {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if in_macro(item.span) {
+            return;
+        }
+        if_chain! {
+            if let ItemImpl(_, _, _, ref generics, _, ref self_ty, _) = item.node;
+            if !generics.params.is_empty();
+            if let TyPath(QPath::Resolved(None, ref path)) = self_ty.node;
+            if let Def::Struct(def_id) | Def::Enum(def_id) | Def::Union(def_id) = path.def;
+            if let Some(segment) = path.segments.last();
+            if let Some(ref args) = segment.args;
+            then {
+                let impl_params: Vec<_> = generics
+                    .params
+                    .iter()
+                    .filter(|param| matches!(param.kind, GenericParamKind::Type { .. }))
+                    .map(|param| param.name.ident().name)
+                    .collect();
+                let type_params: Vec<_> = cx
+                    .tcx
+                    .generics_of(def_id)
+                    .params
+                    .iter()
+                    .filter(|param| matches!(param.kind, ty::GenericParamDefKind::Type { .. }))
+                    .map(|param| param.name.as_symbol())
+                    .collect();
+
+                let type_args = args.args.iter().filter_map(|arg| match *arg {
+                    GenericArg::Type(ref ty) => Some(ty),
+                    GenericArg::Lifetime(_) => None,
+                });
+                for (ty, &expected) in type_args.zip(&type_params) {
+                    if_chain! {
+                        if let TyPath(QPath::Resolved(None, ref arg_path)) = ty.node;
+                        if let Def::TyParam(_) = arg_path.def;
+                        if arg_path.segments.len() == 1;
+                        let name = arg_path.segments[0].ident.name;
+                        if name != expected && impl_params.contains(&name) && type_params.contains(&name);
+                        then {
+                            span_help_and_lint(
+                                cx,
+                                MISMATCHING_TYPE_PARAM_ORDER,
+                                ty.span,
+                                &format!("`{}` has a similarly named generic type parameter", name),
+                                &format!(
+                                    "try `{}`, or a name that does not conflict with `{}`'s generic params",
+                                    expected,
+                                    segment.ident
+                                ),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
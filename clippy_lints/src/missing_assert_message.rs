@@ -0,0 +1,157 @@
+use rustc::hir::*;
+use rustc::lint::*;
+use std::collections::HashSet;
+use syntax::ast;
+use syntax::attr;
+use syntax::codemap::Span;
+use crate::utils::{in_macro, snippet_opt, span_help_and_lint};
+
+/// **What it does:** Checks assertions without a custom panic message.
+///
+/// **Why is this bad?** Without a good custom message, it'd be hard to
+/// understand what went wrong when the assertion fails. A good custom message
+/// should be concise and provide context about what was expected.
+///
+/// Assertions in test functions and in `#[cfg(test)]` items are not checked,
+/// as the test name usually says enough.
+///
+/// **Known problems:** The arguments of the macro are found by looking at the
+/// source text, which may fail for unusual literals.
+///
+/// **Example:**
+/// ```rust
+/// fn call(service: Service) {
+///     assert!(service.ready);
+/// }
+/// ```
+/// Use instead:
+/// ```rust
+/// fn call(service: Service) {
+///     assert!(service.ready, "`service.poll_ready()` must be called first to ensure that service is ready");
+/// }
+/// ```
+declare_clippy_lint! {
+    pub MISSING_ASSERT_MESSAGE,
+    restriction,
+    "checks assertions without a custom panic message"
+}
+
+/// The assertion macros and their number of arguments without a message.
+const ASSERT_MACROS: [(&str, usize); 6] = [
+    ("assert", 1),
+    ("debug_assert", 1),
+    ("assert_eq", 2),
+    ("assert_ne", 2),
+    ("debug_assert_eq", 2),
+    ("debug_assert_ne", 2),
+];
+
+#[derive(Default)]
+pub struct MissingAssertMessage {
+    /// Whether the current node is test code, for each level of attributes.
+    in_test_stack: Vec<bool>,
+    /// Call sites of the assertions which were already checked.
+    spans: HashSet<Span>,
+}
+
+impl MissingAssertMessage {
+    fn in_test(&self) -> bool {
+        *self.in_test_stack.last().unwrap_or(&false)
+    }
+}
+
+impl LintPass for MissingAssertMessage {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MISSING_ASSERT_MESSAGE)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for MissingAssertMessage {
+    fn check_crate(&mut self, _: &LateContext<'a, 'tcx>, _: &'tcx Crate) {
+        self.spans.clear();
+    }
+
+    fn enter_lint_attrs(&mut self, _: &LateContext<'a, 'tcx>, attrs: &'tcx [ast::Attribute]) {
+        let in_test = self.in_test() || attrs.iter().any(|attr| {
+            attr.check_name("test") || (attr.check_name("cfg") && match attr.meta_item_list() {
+                None => false,
+                Some(l) => attr::list_contains_name(&l[..], "test"),
+            })
+        });
+        self.in_test_stack.push(in_test);
+    }
+
+    fn exit_lint_attrs(&mut self, _: &LateContext<'a, 'tcx>, _: &'tcx [ast::Attribute]) {
+        self.in_test_stack.pop().expect("empty in_test_stack");
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if self.in_test() {
+            return;
+        }
+        if_chain! {
+            if let Some(expn_info) = expr.span.ctxt().outer().expn_info();
+            // assertions expanded from other macros can't be looked at
+            if !in_macro(expn_info.call_site);
+            if let Some(&(_, n_args)) = ASSERT_MACROS.iter().find(|&&(name, _)| expn_info.format.name() == name);
+            if self.spans.insert(expn_info.call_site);
+            if let Some(snippet) = snippet_opt(cx, expn_info.call_site);
+            if count_macro_args(&snippet) == Some(n_args);
+            then {
+                span_help_and_lint(
+                    cx,
+                    MISSING_ASSERT_MESSAGE,
+                    expn_info.call_site,
+                    "assert without any message",
+                    "consider describing why the failing assert is problematic",
+                );
+            }
+        }
+    }
+}
+
+/// Counts the top-level arguments of a macro invocation like `foo!(a, (b, c))`.
+fn count_macro_args(snippet: &str) -> Option<usize> {
+    let start = snippet.find(|c| c == '(' || c == '[' || c == '{')?;
+    let mut chars = snippet[start + 1..].chars();
+    let mut depth = 0;
+    let mut count = 0;
+    // whether there is something after the last comma
+    let mut pending = false;
+    while let Some(c) = chars.next() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' if depth == 0 => return Some(if pending { count + 1 } else { count }),
+            ')' | ']' | '}' => depth -= 1,
+            ',' if depth == 0 => {
+                count += 1;
+                pending = false;
+                continue;
+            },
+            '"' => while let Some(c) = chars.next() {
+                match c {
+                    '\\' => {
+                        chars.next();
+                    },
+                    '"' => break,
+                    _ => (),
+                }
+            },
+            '\'' => {
+                // skip character literals like `','`, but not lifetimes
+                let rest = chars.as_str();
+                if rest.starts_with('\\') {
+                    let end = rest.get(2..)?.find('\'')?;
+                    chars = rest[end + 3..].chars();
+                } else if rest.chars().nth(1) == Some('\'') {
+                    chars.next();
+                    chars.next();
+                }
+            },
+            _ if c.is_whitespace() => continue,
+            _ => (),
+        }
+        pending = true;
+    }
+    None
+}
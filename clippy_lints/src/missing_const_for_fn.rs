@@ -0,0 +1,256 @@
+use rustc::hir::*;
+use rustc::hir::def::Def;
+use rustc::hir::def_id::DefId;
+use rustc::hir::intravisit::{walk_expr, walk_stmt, FnKind, NestedVisitorMap, Visitor};
+use rustc::hir::map::Node::NodeItem;
+use rustc::lint::*;
+use rustc::ty::{self, Ty};
+use rustc_target::spec::abi::Abi;
+use semver::Version;
+use syntax::ast::{NodeId, CRATE_NODE_ID};
+use syntax::codemap::{BytePos, Span};
+use crate::utils::{in_macro, meets_msrv, snippet_opt, span_lint_and_then};
+
+/// **What it does:** Suggests the use of `const` in functions and methods where
+/// possible.
+///
+/// **Why is this bad?** Not having the function const prevents callers of the
+/// function from being const as well.
+///
+/// **Known problems:** Const functions are currently still being worked on, so
+/// this lint only fires for crates enabling the `const_fn` feature, and the
+/// rules it applies mirror the minimal subset accepted by the compiler today:
+/// no local bindings, no branching or loops, no floating point, no mutable
+/// references and only calls to other `const fn`s.
+///
+/// Also, the lint only runs one pass over the code, so a function only calling
+/// functions that this lint suggests making `const` is not reported.
+///
+/// `const fn` is only stable since Rust 1.31, set the `msrv` configuration if
+/// the crate supports older versions.
+///
+/// **Example:**
+/// ```rust
+/// fn new() -> Self {
+///     Self { random_number: 42 }
+/// }
+/// ```
+///
+/// Could be a const fn:
+///
+/// ```rust
+/// const fn new() -> Self {
+///     Self { random_number: 42 }
+/// }
+/// ```
+declare_clippy_lint! {
+    pub MISSING_CONST_FOR_FN,
+    nursery,
+    "Lint functions definitions that could be made `const fn`"
+}
+
+pub struct MissingConstForFn {
+    msrv: Option<Version>,
+}
+
+impl MissingConstForFn {
+    pub fn new(msrv: Option<Version>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl LintPass for MissingConstForFn {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MISSING_CONST_FOR_FN)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for MissingConstForFn {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'a, 'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl,
+        body: &'tcx Body,
+        span: Span,
+        node_id: NodeId,
+    ) {
+        // `const fn` can't be declared without the feature gate
+        if !cx.tcx.features().const_fn
+            || !meets_msrv(self.msrv.as_ref(), &Version::new(1, 31, 0))
+            || in_macro(span)
+        {
+            return;
+        }
+
+        match kind {
+            FnKind::ItemFn(name, _, header, ..) => {
+                if name == "main" && cx.tcx.hir.get_parent(node_id) == CRATE_NODE_ID {
+                    return;
+                }
+                if !is_plain_header(header) {
+                    return;
+                }
+            },
+            FnKind::Method(_, sig, ..) => {
+                if !is_plain_header(sig.header) {
+                    return;
+                }
+                // trait methods can't be `const`
+                if let Some(NodeItem(item)) = cx.tcx.hir.find(cx.tcx.hir.get_parent_node(node_id)) {
+                    if matches!(item.node, ItemImpl(_, _, _, _, Some(_), _, _) | ItemTrait(..)) {
+                        return;
+                    }
+                }
+            },
+            FnKind::Closure(..) => return,
+        }
+
+        let fn_def_id = cx.tcx.hir.local_def_id(node_id);
+        if !has_const_signature(cx, fn_def_id) {
+            return;
+        }
+
+        let mut visitor = ConstEligibility {
+            cx,
+            tables: cx.tcx.body_tables(body.id()),
+            eligible: true,
+        };
+        visitor.visit_expr(&body.value);
+        if !visitor.eligible {
+            return;
+        }
+
+        let fn_span = match snippet_opt(cx, span).and_then(|snip| snip.find("fn ")) {
+            Some(pos) => Span::new(
+                span.lo() + BytePos(pos as u32),
+                span.lo() + BytePos(pos as u32 + 2),
+                span.ctxt(),
+            ),
+            None => return,
+        };
+        span_lint_and_then(cx, MISSING_CONST_FOR_FN, fn_span, "this could be a `const fn`", |db| {
+            db.span_suggestion(fn_span, "make the function `const`", "const fn".to_string());
+        });
+    }
+}
+
+/// `unsafe`, `extern` and already `const` functions are out of scope.
+fn is_plain_header(header: FnHeader) -> bool {
+    header.constness == Constness::NotConst && header.unsafety == Unsafety::Normal && header.abi == Abi::Rust
+        && header.asyncness == IsAsync::NotAsync
+}
+
+/// Checks the bounds and argument/return types against the rules for `const fn`.
+fn has_const_signature<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, fn_def_id: DefId) -> bool {
+    let sized_trait = cx.tcx.lang_items().sized_trait();
+    let has_trait_bounds = cx.param_env.caller_bounds.iter().any(|pred| match *pred {
+        ty::Predicate::Trait(ref poly_trait_ref) => Some(poly_trait_ref.def_id()) != sized_trait,
+        _ => false,
+    });
+    if has_trait_bounds {
+        return false;
+    }
+
+    let fn_sig = cx.tcx.fn_sig(fn_def_id);
+    let fn_sig = cx.tcx.erase_late_bound_regions(&fn_sig);
+    // arguments are dropped at the end of the function, which `const fn` can't do
+    if fn_sig.inputs().iter().any(|ty| ty.needs_drop(cx.tcx, cx.param_env)) {
+        return false;
+    }
+    fn_sig.inputs_and_output.iter().all(|ty| is_const_ty(ty))
+}
+
+fn is_const_ty(ty: Ty) -> bool {
+    ty.walk().all(|ty| match ty.sty {
+        ty::TyFloat(_) | ty::TyFnPtr(_) | ty::TyDynamic(..) | ty::TyAnon(..) | ty::TyRef(_, _, MutMutable) => false,
+        _ => true,
+    })
+}
+
+struct ConstEligibility<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    tables: &'a ty::TypeckTables<'tcx>,
+    eligible: bool,
+}
+
+impl<'a, 'tcx> ConstEligibility<'a, 'tcx> {
+    fn is_const_expr(&self, expr: &'tcx Expr) -> bool {
+        if !is_const_ty(self.tables.expr_ty(expr)) {
+            return false;
+        }
+
+        match expr.node {
+            ExprBox(..) |
+            ExprLoop(..) |
+            ExprWhile(..) |
+            ExprIf(..) |
+            ExprMatch(..) |
+            ExprClosure(..) |
+            ExprYield(..) |
+            ExprInlineAsm(..) |
+            ExprAssign(..) |
+            ExprAssignOp(..) |
+            ExprBreak(..) |
+            ExprContinue(..) |
+            ExprRet(..) |
+            ExprAddrOf(MutMutable, _) => false,
+            // overloaded operators are trait method calls
+            ExprBinary(op, ..) => !self.tables.is_method_call(expr) && op.node != BiAnd && op.node != BiOr,
+            ExprUnary(UnDeref, ref inner) => {
+                !self.tables.is_method_call(expr) && !self.tables.expr_ty(inner).is_unsafe_ptr()
+            },
+            ExprUnary(..) | ExprIndex(..) => !self.tables.is_method_call(expr),
+            ExprCast(ref inner, _) => {
+                let from = self.tables.expr_ty(inner);
+                !from.is_unsafe_ptr() && !from.is_fn()
+            },
+            ExprCall(ref callee, _) => {
+                if let ExprPath(ref qpath) = callee.node {
+                    match self.tables.qpath_def(qpath, callee.hir_id) {
+                        Def::Fn(def_id) | Def::Method(def_id) => self.cx.tcx.is_const_fn(def_id),
+                        Def::StructCtor(..) | Def::VariantCtor(..) => true,
+                        _ => false,
+                    }
+                } else {
+                    false
+                }
+            },
+            ExprMethodCall(..) => {
+                let def_id = self.tables.type_dependent_defs()[expr.hir_id].def_id();
+                self.cx.tcx.is_const_fn(def_id)
+            },
+            ExprPath(ref qpath) => !matches!(self.tables.qpath_def(qpath, expr.hir_id), Def::Static(..)),
+            ExprBlock(ref block, _) => block.rules == DefaultBlock,
+            _ => true,
+        }
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for ConstEligibility<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if !self.eligible {
+            return;
+        }
+        if !self.is_const_expr(expr) {
+            self.eligible = false;
+            return;
+        }
+        walk_expr(self, expr);
+    }
+
+    fn visit_stmt(&mut self, stmt: &'tcx Stmt) {
+        // local bindings are not allowed in `const fn` yet
+        if let StmtDecl(ref decl, _) = stmt.node {
+            if let DeclLocal(_) = decl.node {
+                self.eligible = false;
+                return;
+            }
+        }
+        walk_stmt(self, stmt);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
@@ -0,0 +1,72 @@
+use rustc::hir::*;
+use rustc::lint::*;
+use std::collections::HashSet;
+use crate::utils::{in_macro, is_automatically_derived, span_note_and_lint};
+
+/// **What it does:** Checks if a provided method is used implicitly by a trait
+/// implementation.
+///
+/// **Why is this bad?** Some codebases want every method to be written out
+/// explicitly, for example types that wrap or proxy another value and have to
+/// forward every call, including the ones with a default implementation.
+///
+/// **Known problems:** This is a restriction lint, most code relies on default
+/// methods on purpose and should not enable it.
+///
+/// **Example:**
+/// ```rust
+/// trait Trait {
+///     fn required();
+///
+///     fn provided() {}
+/// }
+///
+/// impl Trait for Type {
+///     fn required() { /* ... */ }
+/// }
+/// ```
+/// Use instead:
+/// ```rust
+/// impl Trait for Type {
+///     fn required() { /* ... */ }
+///
+///     fn provided() { /* ... */ }
+/// }
+/// ```
+declare_clippy_lint! {
+    pub MISSING_TRAIT_METHODS,
+    restriction,
+    "trait implementation uses default provided method"
+}
+
+#[derive(Copy, Clone)]
+pub struct MissingTraitMethods;
+
+impl LintPass for MissingTraitMethods {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MISSING_TRAIT_METHODS)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for MissingTraitMethods {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if in_macro(item.span) || is_automatically_derived(&item.attrs) {
+            return;
+        }
+        if let ItemImpl(_, _, _, _, Some(ref trait_ref), _, ref impl_items) = item.node {
+            let implemented: HashSet<_> = impl_items.iter().map(|impl_item| impl_item.ident.name).collect();
+            for method in cx.tcx.provided_trait_methods(trait_ref.path.def.def_id()) {
+                if !implemented.contains(&method.ident.name) {
+                    span_note_and_lint(
+                        cx,
+                        MISSING_TRAIT_METHODS,
+                        item.span,
+                        &format!("missing trait method provided by default: `{}`", method.ident),
+                        cx.tcx.def_span(method.def_id),
+                        "implement the method",
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use rustc::hir::*;
+use rustc::hir::intravisit::FnKind;
+use rustc::lint::*;
+use rustc::ty::{self, Ty};
+use syntax::ast::NodeId;
+use syntax_pos::Span;
+use crate::utils::{in_macro, match_def_path, paths, span_lint};
+
+/// **What it does:** Checks for sets/maps with mutable key types.
+///
+/// **Why is this bad?** All of `HashMap`, `HashSet`, `BTreeMap` and
+/// `BTreeSet` rely on either the hash or the order of keys being unchanging,
+/// so having types with interior mutability is a bad idea.
+///
+/// **Known problems:** Types which only use interior mutability for caching
+/// and don't change their hash or order are false positives, they can be
+/// listed in the `ignore_interior_mutability` configuration option.
+///
+/// **Example:**
+/// ```rust
+/// use std::cmp::{PartialEq, Eq};
+/// use std::collections::HashSet;
+/// use std::hash::{Hash, Hasher};
+/// use std::sync::atomic::AtomicUsize;
+///
+/// struct Bad(AtomicUsize);
+/// impl PartialEq for Bad {
+///     fn eq(&self, rhs: &Self) -> bool {
+///          ..
+///     }
+/// }
+///
+/// impl Eq for Bad {}
+///
+/// impl Hash for Bad {
+///     fn hash<H: Hasher>(&self, h: &mut H) {
+///         ..
+///     }
+/// }
+///
+/// fn main() {
+///     let _: HashSet<Bad> = HashSet::new();
+/// }
+/// ```
+declare_clippy_lint! {
+    pub MUTABLE_KEY_TYPE,
+    correctness,
+    "Check for mutable `Map`/`Set` key type"
+}
+
+/// The types which own their type arguments through a raw pointer, and share
+/// their hash and order.
+const POINTER_OWNERS: [&[&str]; 10] = [
+    &paths::ARC,
+    &paths::BINARY_HEAP,
+    &paths::BTREEMAP,
+    &paths::BTREESET,
+    &paths::HASHMAP,
+    &paths::HASHSET,
+    &paths::LINKED_LIST,
+    &paths::RC,
+    &paths::VEC,
+    &paths::VEC_DEQUE,
+];
+
+#[derive(Clone)]
+pub struct MutableKeyType {
+    ignore_interior_mutability: Vec<String>,
+}
+
+impl MutableKeyType {
+    pub fn new(ignore_interior_mutability: Vec<String>) -> Self {
+        Self {
+            ignore_interior_mutability,
+        }
+    }
+
+    fn check_sig<'a, 'tcx>(&self, cx: &LateContext<'a, 'tcx>, decl: &FnDecl, node_id: NodeId) {
+        let fn_sig = cx.tcx.fn_sig(cx.tcx.hir.local_def_id(node_id));
+        let fn_sig = cx.tcx.erase_late_bound_regions(&fn_sig);
+        for (hir_ty, &ty) in decl.inputs.iter().zip(fn_sig.inputs()) {
+            self.check_ty(cx, hir_ty.span, ty);
+        }
+        if let FunctionRetTy::Return(ref hir_ty) = decl.output {
+            self.check_ty(cx, hir_ty.span, fn_sig.output());
+        }
+    }
+
+    /// Looks through references for a map or set type, and lints if its key
+    /// has interior mutability.
+    fn check_ty<'a, 'tcx>(&self, cx: &LateContext<'a, 'tcx>, span: Span, ty: Ty<'tcx>) {
+        let ty = match ty.sty {
+            ty::TyRef(_, inner, _) => inner,
+            _ => ty,
+        };
+        if let ty::TyAdt(def, substs) = ty.sty {
+            let is_keyed = [&paths::HASHMAP[..], &paths::HASHSET, &paths::BTREEMAP, &paths::BTREESET]
+                .iter()
+                .any(|path| match_def_path(cx.tcx, def.did, path));
+            if is_keyed && self.is_interior_mut_ty(cx, substs.type_at(0), &mut Vec::new()) {
+                span_lint(cx, MUTABLE_KEY_TYPE, span, "mutable key type");
+            }
+        }
+    }
+
+    /// Determines if a type contains an `UnsafeCell`, which would let its hash
+    /// or order change. Raw pointers are not followed, but the content of smart
+    /// pointers and collections is.
+    fn is_interior_mut_ty<'a, 'tcx>(
+        &self,
+        cx: &LateContext<'a, 'tcx>,
+        ty: Ty<'tcx>,
+        seen: &mut Vec<Ty<'tcx>>,
+    ) -> bool {
+        match ty.sty {
+            ty::TyRef(_, inner, _) | ty::TyArray(inner, _) | ty::TySlice(inner) => {
+                self.is_interior_mut_ty(cx, inner, seen)
+            },
+            ty::TyTuple(tys) => tys.iter().any(|ty| self.is_interior_mut_ty(cx, ty, seen)),
+            ty::TyAdt(def, substs) => {
+                // recursive types
+                if seen.contains(&ty) {
+                    return false;
+                }
+                seen.push(ty);
+
+                let path = cx.tcx.item_path_str(def.did);
+                if self.ignore_interior_mutability.iter().any(|ignored| *ignored == path) {
+                    false
+                } else if Some(def.did) == cx.tcx.lang_items().unsafe_cell_type() {
+                    true
+                } else if ty.is_box() || POINTER_OWNERS.iter().any(|path| match_def_path(cx.tcx, def.did, path)) {
+                    substs.types().any(|ty| self.is_interior_mut_ty(cx, ty, seen))
+                } else {
+                    def.all_fields()
+                        .any(|field| self.is_interior_mut_ty(cx, field.ty(cx.tcx, substs), seen))
+                }
+            },
+            _ => false,
+        }
+    }
+}
+
+impl LintPass for MutableKeyType {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MUTABLE_KEY_TYPE)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for MutableKeyType {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'a, 'tcx>,
+        kind: FnKind<'tcx>,
+        decl: &'tcx FnDecl,
+        _: &'tcx Body,
+        span: Span,
+        node_id: NodeId,
+    ) {
+        if in_macro(span) {
+            return;
+        }
+        if let FnKind::Closure(..) = kind {
+            return;
+        }
+        self.check_sig(cx, decl, node_id);
+    }
+
+    fn check_local(&mut self, cx: &LateContext<'a, 'tcx>, local: &'tcx Local) {
+        if in_macro(local.span) {
+            return;
+        }
+        self.check_ty(cx, local.span, cx.tables.pat_ty(&local.pat));
+    }
+}
@@ -0,0 +1,155 @@
+use rustc::hir::*;
+use rustc::hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc::lint::*;
+use rustc_errors::Applicability;
+use syntax_pos::Span;
+use crate::utils::{in_macro, match_trait_method, paths, snippet, span_lint_and_then};
+
+/// **What it does:** Checks for usage of `for_each` that would be more simply
+/// written as a `for` loop.
+///
+/// **Why is this bad?** `for_each` may be used after applying iterator
+/// transformers like `filter` for better readability and performance. It may
+/// also be used to fit a simple operation on one line. But when none of these
+/// apply, a simple `for` loop is more idiomatic, and unlike a closure it
+/// supports `?`, `break` and returning from the enclosing function.
+///
+/// **Known problems:** `return` in the closure body is rewritten to `continue`,
+/// so closures returning a value, or returning from inside a loop, are not
+/// linted.
+///
+/// **Example:**
+/// ```rust
+/// let v = vec![0, 1, 2];
+/// v.iter().for_each(|elem| {
+///     println!("{}", elem);
+/// })
+/// ```
+/// Use instead:
+/// ```rust
+/// let v = vec![0, 1, 2];
+/// for elem in v.iter() {
+///     println!("{}", elem);
+/// }
+/// ```
+declare_clippy_lint! {
+    pub NEEDLESS_FOR_EACH,
+    pedantic,
+    "using `for_each` where a `for` loop would be simpler"
+}
+
+#[derive(Copy, Clone)]
+pub struct NeedlessForEach;
+
+impl LintPass for NeedlessForEach {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(NEEDLESS_FOR_EACH)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for NeedlessForEach {
+    fn check_stmt(&mut self, cx: &LateContext<'a, 'tcx>, stmt: &'tcx Stmt) {
+        let expr = match stmt.node {
+            StmtExpr(ref expr, _) | StmtSemi(ref expr, _) => expr,
+            _ => return,
+        };
+
+        if_chain! {
+            if !in_macro(stmt.span);
+            if let ExprMethodCall(ref method, _, ref for_each_args) = expr.node;
+            if method.ident.name == "for_each";
+            if for_each_args.len() == 2;
+            if match_trait_method(cx, expr, &paths::ITERATOR);
+            // Only lint simple chains like `v.iter().for_each(..)`, a longer
+            // chain of adapters often reads better with `for_each`.
+            if let ExprMethodCall(_, _, ref iter_args) = for_each_args[0].node;
+            if is_simple_receiver(&iter_args[0]);
+            if let ExprClosure(_, _, body_id, ..) = for_each_args[1].node;
+            let body = cx.tcx.hir.body(body_id);
+            if body.arguments.len() == 1;
+            if let ExprBlock(..) = body.value.node;
+            then {
+                let mut ret_collector = RetCollector::default();
+                ret_collector.visit_expr(&body.value);
+                if ret_collector.ret_with_value || ret_collector.ret_in_loop {
+                    return;
+                }
+
+                let body_snippet = replace_returns(cx, body.value.span, &ret_collector.spans);
+                let sugg = format!(
+                    "for {} in {} {}",
+                    snippet(cx, body.arguments[0].pat.span, ".."),
+                    snippet(cx, for_each_args[0].span, ".."),
+                    body_snippet,
+                );
+                span_lint_and_then(cx, NEEDLESS_FOR_EACH, stmt.span, "needless use of `for_each`", |db| {
+                    db.span_suggestion_with_applicability(stmt.span, "try", sugg, Applicability::MaybeIncorrect);
+                    if !ret_collector.spans.is_empty() {
+                        db.note("`return` in the closure is replaced with `continue`");
+                    }
+                });
+            }
+        }
+    }
+}
+
+fn is_simple_receiver(expr: &Expr) -> bool {
+    match expr.node {
+        ExprPath(..) | ExprArray(..) | ExprCall(..) => true,
+        _ => false,
+    }
+}
+
+/// Returns the snippet of `body` with each of `returns` replaced by
+/// `continue`.
+fn replace_returns(cx: &LateContext, body: Span, returns: &[Span]) -> String {
+    let mut snip = snippet(cx, body, "..").into_owned();
+    // replace from the end so that earlier offsets stay valid
+    for ret in returns.iter().rev() {
+        let lo = (ret.lo() - body.lo()).0 as usize;
+        let hi = (ret.hi() - body.lo()).0 as usize;
+        if hi <= snip.len() {
+            snip.replace_range(lo..hi, "continue");
+        }
+    }
+    snip
+}
+
+/// Collects the `return` expressions of a closure body, ignoring nested
+/// closures.
+#[derive(Default)]
+struct RetCollector {
+    spans: Vec<Span>,
+    ret_with_value: bool,
+    /// Set if a `return` is inside a loop of the closure body, where
+    /// `continue` would continue that loop instead.
+    ret_in_loop: bool,
+    loop_depth: usize,
+}
+
+impl<'tcx> Visitor<'tcx> for RetCollector {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if let ExprRet(ref value) = expr.node {
+            if value.is_some() {
+                self.ret_with_value = true;
+            }
+            if self.loop_depth > 0 {
+                self.ret_in_loop = true;
+            }
+            self.spans.push(expr.span);
+        }
+
+        match expr.node {
+            ExprLoop(..) | ExprWhile(..) => {
+                self.loop_depth += 1;
+                walk_expr(self, expr);
+                self.loop_depth -= 1;
+            },
+            _ => walk_expr(self, expr),
+        }
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
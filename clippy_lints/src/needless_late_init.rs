@@ -0,0 +1,234 @@
+use rustc::hir::*;
+use rustc::hir::def::Def;
+use rustc::hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc::lint::*;
+use syntax::ast::NodeId;
+use syntax_pos::Span;
+use crate::utils::{in_macro, multispan_sugg, snippet, snippet_opt, span_lint_and_then};
+
+/// **What it does:** Checks for late initializations that can be replaced by a
+/// `let` statement with an initializer.
+///
+/// **Why is this bad?** Assigning in the `let` statement is less repetitive.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// let a;
+/// a = 1;
+///
+/// let b;
+/// match 3 {
+///     0 => b = "zero",
+///     1 => b = "one",
+///     _ => b = "many",
+/// }
+///
+/// let c;
+/// if true {
+///     c = 1;
+/// } else {
+///     c = -1;
+/// }
+/// ```
+/// Use instead:
+/// ```rust
+/// let a = 1;
+///
+/// let b = match 3 {
+///     0 => "zero",
+///     1 => "one",
+///     _ => "many",
+/// };
+///
+/// let c = if true {
+///     1
+/// } else {
+///     -1
+/// };
+/// ```
+declare_clippy_lint! {
+    pub NEEDLESS_LATE_INIT,
+    style,
+    "late initializations that can be replaced by a `let` statement with an initializer"
+}
+
+#[derive(Copy, Clone)]
+pub struct NeedlessLateInit;
+
+impl LintPass for NeedlessLateInit {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(NEEDLESS_LATE_INIT)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for NeedlessLateInit {
+    fn check_block(&mut self, cx: &LateContext<'a, 'tcx>, block: &'tcx Block) {
+        for (i, stmt) in block.stmts.iter().enumerate() {
+            if_chain! {
+                if !in_macro(stmt.span);
+                if let StmtDecl(ref decl, _) = stmt.node;
+                if let DeclLocal(ref local) = decl.node;
+                if local.init.is_none();
+                if let PatKind::Binding(_, canonical_id, ident, None) = local.pat.node;
+                // the first statement using the binding is the one to merge the declaration into
+                if let Some(usage) = block.stmts[i + 1..].iter().find(|s| used_in_stmt(cx, canonical_id, s));
+                if !in_macro(usage.span);
+                if let StmtExpr(ref expr, _) | StmtSemi(ref expr, _) = usage.node;
+                then {
+                    let next = &block.stmts[i + 1];
+                    // also remove the indentation of the next statement if nothing is in between
+                    let remove_span = match snippet_opt(cx, stmt.span.between(next.span)) {
+                        Some(ref gap) if gap.trim().is_empty() => stmt.span.with_hi(next.span.lo()),
+                        _ => stmt.span,
+                    };
+                    let binding = match local.ty {
+                        Some(ref ty) => format!(
+                            "{}: {}",
+                            snippet(cx, local.pat.span, ".."),
+                            snippet(cx, ty.span, "..")
+                        ),
+                        None => snippet(cx, local.pat.span, "..").into_owned(),
+                    };
+
+                    if let ExprAssign(ref var, ref value) = expr.node {
+                        if is_local(cx, var, canonical_id) && !used_in_expr(cx, canonical_id, value) {
+                            span_lint_and_then(cx, NEEDLESS_LATE_INIT, stmt.span, "unneeded late initialization", |db| {
+                                multispan_sugg(
+                                    db,
+                                    "join the assignment with the declaration".to_string(),
+                                    vec![
+                                        (remove_span, String::new()),
+                                        (expr.span, format!("let {} = {}", binding, snippet(cx, value.span, ".."))),
+                                    ],
+                                );
+                            });
+                        }
+                        continue;
+                    }
+
+                    match expr.node {
+                        ExprIf(..) | ExprMatch(..) => (),
+                        _ => continue,
+                    }
+                    let mut assignments = Vec::new();
+                    if !collect_assignments(cx, canonical_id, expr, &mut assignments) {
+                        continue;
+                    }
+                    span_lint_and_then(cx, NEEDLESS_LATE_INIT, stmt.span, "unneeded late initialization", |db| {
+                        let mut suggs = vec![
+                            (remove_span, String::new()),
+                            (expr.span.with_hi(expr.span.lo()), format!("let {} = ", binding)),
+                        ];
+                        suggs.extend(
+                            assignments
+                                .iter()
+                                .map(|&(span, value)| (span, snippet(cx, value.span, "..").into_owned())),
+                        );
+                        if let StmtExpr(..) = usage.node {
+                            suggs.push((expr.span.with_lo(expr.span.hi()), ";".to_string()));
+                        }
+                        multispan_sugg(db, format!("declare `{}` here", ident.name), suggs);
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Collects the assignments to the binding which produce the value of every
+/// branch of `expr`, together with the span to replace by the assigned value.
+/// Returns `false` if some branch does not end with such an assignment.
+fn collect_assignments<'a, 'tcx>(
+    cx: &LateContext<'a, 'tcx>,
+    id: NodeId,
+    expr: &'tcx Expr,
+    assignments: &mut Vec<(Span, &'tcx Expr)>,
+) -> bool {
+    match expr.node {
+        ExprAssign(ref var, ref value) if is_local(cx, var, id) && !used_in_expr(cx, id, value) => {
+            assignments.push((expr.span, value));
+            true
+        },
+        ExprBlock(ref block, _) => {
+            if let Some(ref tail) = block.expr {
+                return !block.stmts.iter().any(|s| used_in_stmt(cx, id, s))
+                    && collect_assignments(cx, id, tail, assignments);
+            }
+            let (last, rest) = match block.stmts.split_last() {
+                Some(split) => split,
+                None => return false,
+            };
+            if rest.iter().any(|s| used_in_stmt(cx, id, s)) {
+                return false;
+            }
+            match last.node {
+                // the semicolon has to go too, or the block would be of type `()`
+                StmtSemi(ref e, _) => match e.node {
+                    ExprAssign(ref var, ref value) if is_local(cx, var, id) && !used_in_expr(cx, id, value) => {
+                        assignments.push((last.span, value));
+                        true
+                    },
+                    _ => false,
+                },
+                StmtExpr(ref e, _) => collect_assignments(cx, id, e, assignments),
+                StmtDecl(..) => false,
+            }
+        },
+        ExprIf(ref cond, ref then, Some(ref els)) => {
+            !used_in_expr(cx, id, cond)
+                && collect_assignments(cx, id, then, assignments)
+                && collect_assignments(cx, id, els, assignments)
+        },
+        ExprMatch(ref scrutinee, ref arms, MatchSource::Normal)
+        | ExprMatch(ref scrutinee, ref arms, MatchSource::IfLetDesugar { contains_else_clause: true }) => {
+            !arms.is_empty() && !used_in_expr(cx, id, scrutinee) && arms.iter().all(|arm| {
+                arm.guard.as_ref().map_or(true, |guard| !used_in_expr(cx, id, guard))
+                    && collect_assignments(cx, id, &arm.body, assignments)
+            })
+        },
+        _ => false,
+    }
+}
+
+fn is_local(cx: &LateContext, expr: &Expr, id: NodeId) -> bool {
+    if let ExprPath(ref qpath) = expr.node {
+        if let Def::Local(local_id) = cx.tables.qpath_def(qpath, expr.hir_id) {
+            return local_id == id;
+        }
+    }
+    false
+}
+
+struct UsedVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    id: NodeId,
+    used: bool,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for UsedVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if is_local(self.cx, expr, self.id) {
+            self.used = true;
+            return;
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
+
+fn used_in_expr<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, id: NodeId, expr: &'tcx Expr) -> bool {
+    let mut v = UsedVisitor { cx, id, used: false };
+    v.visit_expr(expr);
+    v.used
+}
+
+fn used_in_stmt<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, id: NodeId, stmt: &'tcx Stmt) -> bool {
+    let mut v = UsedVisitor { cx, id, used: false };
+    v.visit_stmt(stmt);
+    v.used
+}
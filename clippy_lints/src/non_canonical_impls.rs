@@ -0,0 +1,246 @@
+use rustc::hir::*;
+use rustc::hir::def::Def;
+use rustc::lint::*;
+use rustc::ty;
+use syntax::ast::Name;
+use syntax::symbol::keywords;
+use crate::utils::{get_arg_name, get_trait_def_id, implements_trait, in_macro, is_automatically_derived, is_copy,
+            match_def_path, match_var, opt_def_id, paths, remove_blocks, span_lint, span_lint_and_sugg};
+
+/// **What it does:** Checks for `PartialOrd` implementations on types which
+/// also implement `Ord`, where `partial_cmp` is not written as
+/// `Some(self.cmp(other))`.
+///
+/// **Why is this bad?** `partial_cmp` and `cmp` have to agree with each other.
+/// Writing the comparison twice makes it easy for the two to drift apart, and
+/// code like sorting behaves inconsistently when they do. Delegating to `cmp`
+/// keeps a single source of truth.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// #[derive(Eq, PartialEq)]
+/// struct A(u32);
+///
+/// impl Ord for A {
+///     fn cmp(&self, other: &Self) -> Ordering {
+///         self.0.cmp(&other.0)
+///     }
+/// }
+///
+/// impl PartialOrd for A {
+///     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+///         self.0.partial_cmp(&other.0)
+///     }
+/// }
+/// ```
+/// Use instead:
+/// ```rust
+/// impl PartialOrd for A {
+///     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+///         Some(self.cmp(other))
+///     }
+/// }
+/// ```
+declare_clippy_lint! {
+    pub NON_CANONICAL_PARTIAL_ORD_IMPL,
+    correctness,
+    "non-canonical implementation of `PartialOrd` on an `Ord` type"
+}
+
+/// **What it does:** Checks for `Clone` implementations on `Copy` types where
+/// `clone` is not written as `*self`, and for `clone_from` implementations
+/// which just do `*self = source.clone()`.
+///
+/// **Why is this bad?** A `Copy` type is expected to be cloned by copying it.
+/// Doing anything else in `clone` is confusing at best, and a bug at worst
+/// since code can't rely on the two to agree. A `clone_from` written as
+/// `*self = source.clone()` is the default implementation and can be removed.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// #[derive(Eq, PartialEq)]
+/// struct A(u32);
+///
+/// impl Clone for A {
+///     fn clone(&self) -> Self {
+///         Self(self.0)
+///     }
+/// }
+///
+/// impl Copy for A {}
+/// ```
+/// Use instead:
+/// ```rust
+/// #[derive(Eq, PartialEq)]
+/// struct A(u32);
+///
+/// impl Clone for A {
+///     fn clone(&self) -> Self {
+///         *self
+///     }
+/// }
+///
+/// impl Copy for A {}
+/// ```
+/// or `#[derive(Clone, Copy)]` on the type.
+declare_clippy_lint! {
+    pub NON_CANONICAL_CLONE_IMPL,
+    style,
+    "non-canonical implementation of `Clone` on a `Copy` type"
+}
+
+#[derive(Copy, Clone)]
+pub struct NonCanonicalImpls;
+
+impl LintPass for NonCanonicalImpls {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(NON_CANONICAL_PARTIAL_ORD_IMPL, NON_CANONICAL_CLONE_IMPL)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for NonCanonicalImpls {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if in_macro(item.span) || is_automatically_derived(&item.attrs) {
+            return;
+        }
+        if let ItemImpl(_, _, _, _, Some(ref trait_ref), _, ref impl_items) = item.node {
+            let trait_id = trait_ref.path.def.def_id();
+            if Some(trait_id) == cx.tcx.lang_items().partial_ord_trait() {
+                check_partial_ord(cx, item, impl_items);
+            } else if Some(trait_id) == cx.tcx.lang_items().clone_trait() {
+                check_clone(cx, item, impl_items);
+            }
+        }
+    }
+}
+
+fn check_partial_ord<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, item: &'tcx Item, impl_items: &[ImplItemRef]) {
+    let impl_def_id = cx.tcx.hir.local_def_id(item.id);
+    let self_ty = cx.tcx.type_of(impl_def_id);
+    if_chain! {
+        // `PartialOrd<Rhs>` for another `Rhs` can't be forwarded to `cmp`
+        if let Some(impl_trait_ref) = cx.tcx.impl_trait_ref(impl_def_id);
+        if impl_trait_ref.substs.type_at(1) == self_ty;
+        if let Some(ord_trait) = get_trait_def_id(cx, &paths::ORD);
+        if implements_trait(cx, self_ty, ord_trait, &[]);
+        if let Some(impl_item) = impl_items.iter().find(|impl_item| impl_item.ident.name == "partial_cmp");
+        if let ImplItemKind::Method(_, body_id) = cx.tcx.hir.impl_item(impl_item.id).node;
+        then {
+            let body = cx.tcx.hir.body(body_id);
+            let tables = cx.tcx.body_tables(body_id);
+            let other = body.arguments.get(1).and_then(|arg| get_arg_name(&arg.pat));
+            if is_some_cmp(cx, tables, remove_blocks(&body.value), other) {
+                return;
+            }
+            let msg = "non-canonical implementation of `partial_cmp` on an `Ord` type";
+            if let Some(other) = other {
+                span_lint_and_sugg(
+                    cx,
+                    NON_CANONICAL_PARTIAL_ORD_IMPL,
+                    body.value.span,
+                    msg,
+                    "change this to",
+                    format!("{{ Some(self.cmp({})) }}", other),
+                );
+            } else {
+                span_lint(cx, NON_CANONICAL_PARTIAL_ORD_IMPL, body.value.span, msg);
+            }
+        }
+    }
+}
+
+fn check_clone<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, item: &'tcx Item, impl_items: &[ImplItemRef]) {
+    let self_ty = cx.tcx.type_of(cx.tcx.hir.local_def_id(item.id));
+    let self_name = keywords::SelfValue.name();
+    for impl_item in impl_items {
+        let body_id = match cx.tcx.hir.impl_item(impl_item.id).node {
+            ImplItemKind::Method(_, body_id) => body_id,
+            _ => continue,
+        };
+        let body = cx.tcx.hir.body(body_id);
+        if impl_item.ident.name == "clone" && is_copy(cx, self_ty) {
+            let is_deref_self = match remove_blocks(&body.value).node {
+                ExprUnary(UnDeref, ref inner) => match_var(inner, self_name),
+                _ => false,
+            };
+            if !is_deref_self {
+                span_lint_and_sugg(
+                    cx,
+                    NON_CANONICAL_CLONE_IMPL,
+                    body.value.span,
+                    "non-canonical implementation of `clone` on a `Copy` type",
+                    "change this to",
+                    "{ *self }".to_string(),
+                );
+            }
+        } else if impl_item.ident.name == "clone_from" {
+            if_chain! {
+                if let Some(source) = body.arguments.get(1).and_then(|arg| get_arg_name(&arg.pat));
+                if let ExprBlock(ref block, _) = body.value.node;
+                if block.expr.is_none() && block.stmts.len() == 1;
+                if let StmtSemi(ref expr, _) = block.stmts[0].node;
+                if let ExprAssign(ref lhs, ref rhs) = expr.node;
+                if let ExprUnary(UnDeref, ref lhs) = lhs.node;
+                if match_var(lhs, self_name);
+                if let ExprMethodCall(ref method, _, ref args) = rhs.node;
+                if method.ident.name == "clone" && args.len() == 1;
+                if match_var(&args[0], source);
+                then {
+                    span_lint_and_sugg(
+                        cx,
+                        NON_CANONICAL_CLONE_IMPL,
+                        impl_item.span,
+                        "unnecessary implementation of `clone_from`, it is the same as the default one",
+                        "remove it",
+                        String::new(),
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Returns true if `expr` is `Some(self.cmp(other))` or
+/// `Some(Ord::cmp(self, other))`.
+fn is_some_cmp<'a, 'tcx>(
+    cx: &LateContext<'a, 'tcx>,
+    tables: &ty::TypeckTables<'tcx>,
+    expr: &Expr,
+    other: Option<Name>,
+) -> bool {
+    let other = match other {
+        Some(other) => other,
+        None => return false,
+    };
+    if_chain! {
+        if let ExprCall(ref callee, ref args) = expr.node;
+        if args.len() == 1;
+        if let ExprPath(ref qpath) = callee.node;
+        if let Def::VariantCtor(def_id, ..) = tables.qpath_def(qpath, callee.hir_id);
+        if match_def_path(cx.tcx, def_id, &paths::OPTION_SOME);
+        then {
+            let (cmp_def_id, cmp_args) = match args[0].node {
+                ExprMethodCall(_, _, ref cmp_args) => {
+                    (tables.type_dependent_defs()[args[0].hir_id].def_id(), cmp_args)
+                },
+                ExprCall(ref cmp, ref cmp_args) => match cmp.node {
+                    ExprPath(ref qpath) => match opt_def_id(tables.qpath_def(qpath, cmp.hir_id)) {
+                        Some(def_id) => (def_id, cmp_args),
+                        None => return false,
+                    },
+                    _ => return false,
+                },
+                _ => return false,
+            };
+            return match_def_path(cx.tcx, cmp_def_id, &paths::ORD_CMP)
+                && cmp_args.len() == 2
+                && match_var(&cmp_args[0], keywords::SelfValue.name())
+                && match_var(&cmp_args[1], other);
+        }
+    }
+    false
+}
@@ -0,0 +1,92 @@
+use rustc::hir::*;
+use rustc::lint::*;
+use syntax::ast::{LitIntType, LitKind};
+use crate::utils::{last_path_segment, match_type, paths, snippet_opt, span_lint_and_sugg, walk_ptrs_ty};
+
+/// **What it does:** Checks for non-octal values used to set Unix file
+/// permissions, like `set_mode(644)`.
+///
+/// **Why is this bad?** Unix file permissions are usually written in octal
+/// notation, so a decimal literal like `644` was most likely meant to be
+/// `0o644`. As decimal, it results in totally different permissions.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// use std::fs::OpenOptions;
+/// use std::os::unix::fs::OpenOptionsExt;
+///
+/// let mut options = OpenOptions::new();
+/// options.mode(644);
+/// ```
+/// Use instead:
+/// ```rust
+/// use std::fs::OpenOptions;
+/// use std::os::unix::fs::OpenOptionsExt;
+///
+/// let mut options = OpenOptions::new();
+/// options.mode(0o644);
+/// ```
+declare_clippy_lint! {
+    pub NON_OCTAL_UNIX_PERMISSIONS,
+    correctness,
+    "use of non-octal value to set unix file permissions, which will be translated into octal"
+}
+
+#[derive(Copy, Clone)]
+pub struct NonOctalUnixPermissions;
+
+impl LintPass for NonOctalUnixPermissions {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(NON_OCTAL_UNIX_PERMISSIONS)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for NonOctalUnixPermissions {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        match expr.node {
+            ExprMethodCall(ref path, _, ref args) if args.len() == 2 => {
+                let obj_ty = walk_ptrs_ty(cx.tables.expr_ty(&args[0]));
+                if (path.ident.name == "mode"
+                    && (match_type(cx, obj_ty, &paths::OPEN_OPTIONS) || match_type(cx, obj_ty, &paths::DIR_BUILDER)))
+                    || (path.ident.name == "set_mode" && match_type(cx, obj_ty, &paths::PERMISSIONS))
+                {
+                    check_mode(cx, &args[1]);
+                }
+            },
+            ExprCall(ref func, ref args) if args.len() == 1 => {
+                if_chain! {
+                    if let ExprPath(ref qpath) = func.node;
+                    if last_path_segment(qpath).ident.name == "from_mode";
+                    if match_type(cx, cx.tables.expr_ty(expr), &paths::PERMISSIONS);
+                    then {
+                        check_mode(cx, &args[0]);
+                    }
+                }
+            },
+            _ => (),
+        }
+    }
+}
+
+/// Lints `mode` if it is an unsuffixed decimal literal made of octal digits.
+fn check_mode(cx: &LateContext, mode: &Expr) {
+    if_chain! {
+        if let ExprLit(ref lit) = mode.node;
+        if let LitKind::Int(_, LitIntType::Unsuffixed) = lit.node;
+        if let Some(snip) = snippet_opt(cx, lit.span);
+        if snip.len() >= 3 && !snip.starts_with('0');
+        if snip.chars().all(|c| c == '_' || (c >= '0' && c <= '7'));
+        then {
+            span_lint_and_sugg(
+                cx,
+                NON_OCTAL_UNIX_PERMISSIONS,
+                lit.span,
+                "using a non-octal value to set unix file permissions",
+                "consider using an octal literal instead",
+                format!("0o{}", snip),
+            );
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::ty;
+use crate::utils::{implements_trait, in_macro, span_lint_and_then};
+
+/// **What it does:** Warns about fields in struct implementing `Send` that are
+/// not `Send` themselves.
+///
+/// **Why is this bad?** Sending the struct to another thread will transfer the
+/// ownership of the fields to the new thread. Fields like `Rc` or raw pointers
+/// are not safe to be used from another thread, so an `unsafe impl Send` for a
+/// type containing them is very likely unsound.
+///
+/// **Known problems:** Types which manage the thread safety of their raw
+/// pointers themselves (for example a `Vec`-like container) are reported too.
+/// Such an implementation has to be double-checked by hand, and the lint can
+/// then be allowed on the `impl`.
+///
+/// **Example:**
+/// ```rust
+/// use std::rc::Rc;
+///
+/// struct ExampleStruct<T> {
+///     rc_is_not_send: Rc<String>,
+///     unbounded_generic_field: T,
+/// }
+///
+/// // This impl is unsound because it allows sending `!Send` types through `ExampleStruct`
+/// unsafe impl<T> Send for ExampleStruct<T> {}
+/// ```
+/// Use thread-safe types like `std::sync::Arc` and specify correct bounds
+/// for generic type parameters (`T: Send`).
+declare_clippy_lint! {
+    pub NON_SEND_FIELDS_IN_SEND_TY,
+    correctness,
+    "there is a field that is not safe to be sent to another thread in a `Send` struct"
+}
+
+#[derive(Copy, Clone)]
+pub struct NonSendFieldsInSendTy;
+
+impl LintPass for NonSendFieldsInSendTy {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(NON_SEND_FIELDS_IN_SEND_TY)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for NonSendFieldsInSendTy {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if in_macro(item.span) {
+            return;
+        }
+        if_chain! {
+            if let ItemImpl(Unsafety::Unsafe, ImplPolarity::Positive, _, _, Some(ref trait_ref), _, _) = item.node;
+            if let Some(send_trait) = cx.tcx.lang_items().send_trait();
+            if trait_ref.path.def.def_id() == send_trait;
+            let self_ty = cx.tcx.type_of(cx.tcx.hir.local_def_id(item.id));
+            if let ty::TyAdt(adt_def, substs) = self_ty.sty;
+            then {
+                // fields of generic types are checked against the bounds of the `Send` impl
+                let non_send_fields: Vec<_> = adt_def
+                    .all_fields()
+                    .filter_map(|field| {
+                        let field_ty = field.ty(cx.tcx, substs);
+                        if implements_trait(cx, field_ty, send_trait, &[]) {
+                            return None;
+                        }
+                        cx.tcx.hir.span_if_local(field.did).map(|span| (span, field.ident, field_ty))
+                    })
+                    .collect();
+
+                if non_send_fields.is_empty() {
+                    return;
+                }
+
+                span_lint_and_then(
+                    cx,
+                    NON_SEND_FIELDS_IN_SEND_TY,
+                    item.span,
+                    &format!("some fields in `{}` are not safe to be sent to another thread", self_ty),
+                    |db| {
+                        for (span, ident, field_ty) in non_send_fields {
+                            db.span_note(
+                                span,
+                                &format!("it is not safe to send field `{}` to another thread", ident),
+                            );
+                            let help = if let ty::TyParam(_) = field_ty.sty {
+                                format!("add `{}: Send` bound in `Send` impl", field_ty)
+                            } else {
+                                format!("use a thread-safe type that implements `Send` instead of `{}`", field_ty)
+                            };
+                            db.help(&help);
+                        }
+                    },
+                );
+            }
+        }
+    }
+}
@@ -3,7 +3,7 @@ use rustc::lint::*;
 use syntax::ast::LitKind;
 use syntax::ptr::P;
 use syntax::ext::quote::rt::Span;
-use crate::utils::{is_direct_expn_of, is_expn_of, match_def_path, opt_def_id, paths, resolve_node, span_lint};
+use crate::utils::{in_macro, is_direct_expn_of, is_expn_of, match_def_path, opt_def_id, paths, resolve_node, span_lint};
 
 /// **What it does:** Checks for missing parameters in `panic!`.
 ///
@@ -24,6 +24,24 @@ declare_clippy_lint! {
     "missing parameters in `panic!` calls"
 }
 
+/// **What it does:** Checks for usage of `panic!`.
+///
+/// **Why is this bad?** This macro should not be present in production code,
+/// errors should be handled or returned instead.
+///
+/// **Known problems:** `panic!` calls in other macros, like `assert!`, are
+/// not linted.
+///
+/// **Example:**
+/// ```rust
+/// panic!("even with a good reason");
+/// ```
+declare_clippy_lint! {
+    pub PANIC,
+    restriction,
+    "`panic!` should not be present in production code"
+}
+
 /// **What it does:** Checks for usage of `unimplemented!`.
 ///
 /// **Why is this bad?** This macro should not be present in production code
@@ -40,12 +58,45 @@ declare_clippy_lint! {
     "`unimplemented!` should not be present in production code"
 }
 
+/// **What it does:** Checks for usage of `todo!`.
+///
+/// **Why is this bad?** This macro should not be present in production code
+///
+/// **Known problems:** `todo!` is not part of the standard library, any
+/// `todo!` macro which panics is linted.
+///
+/// **Example:**
+/// ```rust
+/// todo!();
+/// ```
+declare_clippy_lint! {
+    pub TODO,
+    restriction,
+    "`todo!` should not be present in production code"
+}
+
+/// **What it does:** Checks for usage of `unreachable!`.
+///
+/// **Why is this bad?** This macro can cause code to panic
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// unreachable!();
+/// ```
+declare_clippy_lint! {
+    pub UNREACHABLE,
+    restriction,
+    "`unreachable!` should not be present in production code"
+}
+
 #[allow(missing_copy_implementations)]
 pub struct Pass;
 
 impl LintPass for Pass {
     fn get_lints(&self) -> LintArray {
-        lint_array!(PANIC_PARAMS, UNIMPLEMENTED)
+        lint_array!(PANIC_PARAMS, PANIC, UNIMPLEMENTED, TODO, UNREACHABLE)
     }
 }
 
@@ -57,7 +108,8 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
             if let ExprCall(ref fun, ref params) = ex.node;
             if let ExprPath(ref qpath) = fun.node;
             if let Some(fun_def_id) = opt_def_id(resolve_node(cx, qpath, fun.hir_id));
-            if match_def_path(cx.tcx, fun_def_id, &paths::BEGIN_PANIC);
+            if match_def_path(cx.tcx, fun_def_id, &paths::BEGIN_PANIC)
+                || match_def_path(cx.tcx, fun_def_id, &paths::BEGIN_PANIC_FMT);
             if params.len() == 2;
             then {
                 if is_expn_of(expr.span, "unimplemented").is_some() {
@@ -65,7 +117,18 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
                     span_lint(cx, UNIMPLEMENTED, span,
                               "`unimplemented` should not be present in production code");
                 } else {
-                    match_panic(params, expr, cx);
+                    if is_expn_of(expr.span, "todo").is_some() {
+                        let span = get_outer_span(expr);
+                        span_lint(cx, TODO, span, "`todo` should not be present in production code");
+                    } else if is_expn_of(expr.span, "unreachable").is_some() {
+                        let span = get_outer_span(expr);
+                        span_lint(cx, UNREACHABLE, span, "usage of the `unreachable!` macro");
+                    } else if let Some(span) = panic_call_site(expr) {
+                        span_lint(cx, PANIC, span, "`panic` should not be present in production code");
+                    }
+                    if match_def_path(cx.tcx, fun_def_id, &paths::BEGIN_PANIC) {
+                        match_panic(params, expr, cx);
+                    }
                 }
             }
         }
@@ -84,6 +147,21 @@ fn get_outer_span(expr: &Expr) -> Span {
     }
 }
 
+/// Returns the span of the outermost `panic!` call which `expr` is expanded
+/// from, unless it is called by another macro like `assert!`.
+fn panic_call_site(expr: &Expr) -> Option<Span> {
+    let mut span = is_direct_expn_of(expr.span, "panic")?;
+    // `panic!()` calls `panic!("explicit panic")`
+    while let Some(outer) = is_direct_expn_of(span, "panic") {
+        span = outer;
+    }
+    if in_macro(span) {
+        None
+    } else {
+        Some(span)
+    }
+}
+
 fn match_panic(params: &P<[Expr]>, expr: &Expr, cx: &LateContext) {
     if_chain! {
         if let ExprLit(ref lit) = params[0].node;
@@ -0,0 +1,219 @@
+use std::cmp;
+
+use rustc::hir::*;
+use rustc::hir::map::*;
+use rustc::hir::intravisit::FnKind;
+use rustc::lint::*;
+use rustc::ty::TypeVariants;
+use rustc::session::config::Config as SessionConfig;
+use rustc_target::spec::abi::Abi;
+use rustc_target::abi::LayoutOf;
+use syntax::ast::NodeId;
+use syntax_pos::Span;
+use crate::utils::{in_macro, is_copy, is_self, span_lint_and_sugg, snippet};
+
+/// **What it does:** Checks for functions taking arguments by reference, where
+/// the argument type is `Copy` and small enough to be more efficient to always
+/// pass by value.
+///
+/// **Why is this bad?** In many calling conventions instances of structs will
+/// be passed through registers if they fit into two or less general purpose
+/// registers.
+///
+/// **Known problems:** This lint is target register size dependent, it is
+/// limited to 32-bit to try and reduce portability problems between 32 and
+/// 64-bit, but if you are compiling for 8 or 16-bit targets then the limit
+/// will be different.
+///
+/// The configuration option `trivial_copy_size_limit` can be set to override
+/// this limit for a project, and `avoid_breaking_exported_api` to skip the
+/// functions of the exported API.
+///
+/// **Example:**
+/// ```rust
+/// fn foo(v: &u32) {
+///     assert_eq!(v, 42);
+/// }
+/// // should be
+/// fn foo(v: u32) {
+///     assert_eq!(v, 42);
+/// }
+/// ```
+declare_clippy_lint! {
+    pub TRIVIALLY_COPY_PASS_BY_REF,
+    perf,
+    "functions taking small copyable arguments by reference"
+}
+
+/// **What it does:** Checks for functions taking arguments by value, where
+/// the argument type is `Copy` and large enough to be worth considering
+/// passing by reference.
+///
+/// **Why is this bad?** Arguments passed by value might result in an
+/// unnecessary shallow copy, taking up more space in the stack and requiring
+/// a call to `memcpy`, which can be expensive.
+///
+/// **Known problems:** The size of a type is target dependent, so the lint
+/// might fire on some targets only. Changing the argument to a reference is
+/// a breaking change for public functions.
+///
+/// The configuration option `pass_by_value_size_limit` can be set to override
+/// the default limit of 256 bytes for a project, and `avoid_breaking_exported_api`
+/// to skip the functions of the exported API.
+///
+/// **Example:**
+/// ```rust
+/// #[derive(Clone, Copy)]
+/// struct TooLarge([u8; 2048]);
+///
+/// fn foo(v: TooLarge) {}
+/// // should be
+/// fn foo(v: &TooLarge) {}
+/// ```
+declare_clippy_lint! {
+    pub LARGE_TYPES_PASSED_BY_VALUE,
+    pedantic,
+    "functions taking large arguments by value"
+}
+
+pub struct PassByRefOrValue {
+    ref_min_size: u64,
+    value_max_size: u64,
+    avoid_breaking_exported_api: bool,
+}
+
+impl PassByRefOrValue {
+    pub fn new(
+        ref_min_size: Option<u64>,
+        value_max_size: u64,
+        avoid_breaking_exported_api: bool,
+        target: &SessionConfig,
+    ) -> Self {
+        let ref_min_size = ref_min_size.unwrap_or_else(|| {
+            let bit_width = target.usize_ty.bit_width().expect("usize should have a width") as u64;
+            // Cap the calculated bit width at 32-bits to reduce
+            // portability problems between 32 and 64-bit targets
+            let bit_width = cmp::min(bit_width, 32);
+            let byte_width = bit_width / 8;
+            // Use a limit of 2 times the register bit width
+            byte_width * 2
+        });
+        Self {
+            ref_min_size,
+            value_max_size,
+            avoid_breaking_exported_api,
+        }
+    }
+}
+
+impl LintPass for PassByRefOrValue {
+    fn get_lints(&self) -> LintArray {
+        lint_array![TRIVIALLY_COPY_PASS_BY_REF, LARGE_TYPES_PASSED_BY_VALUE]
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for PassByRefOrValue {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'a, 'tcx>,
+        kind: FnKind<'tcx>,
+        decl: &'tcx FnDecl,
+        body: &'tcx Body,
+        span: Span,
+        node_id: NodeId,
+    ) {
+        if in_macro(span) {
+            return;
+        }
+
+        match kind {
+            FnKind::ItemFn(.., header, _, attrs) => {
+                if header.abi != Abi::Rust {
+                    return;
+                }
+                for a in attrs {
+                    if a.meta_item_list().is_some() && a.name() == "proc_macro_derive" {
+                        return;
+                    }
+                }
+            },
+            FnKind::Method(..) => (),
+            _ => return,
+        }
+
+        // Exclude non-inherent impls
+        if let Some(NodeItem(item)) = cx.tcx.hir.find(cx.tcx.hir.get_parent_node(node_id)) {
+            if matches!(item.node, ItemImpl(_, _, _, _, Some(_), _, _) |
+                ItemTrait(..))
+            {
+                return;
+            }
+        }
+
+        if self.avoid_breaking_exported_api && cx.access_levels.is_exported(node_id) {
+            return;
+        }
+
+        let fn_def_id = cx.tcx.hir.local_def_id(node_id);
+
+        let fn_sig = cx.tcx.fn_sig(fn_def_id);
+        let fn_sig = cx.tcx.erase_late_bound_regions(&fn_sig);
+
+        for ((input, &ty), arg) in decl.inputs.iter().zip(fn_sig.inputs()).zip(&body.arguments) {
+            // All spans generated from a proc-macro invocation are the same...
+            if span == input.span {
+                return;
+            }
+
+            match ty.sty {
+                TypeVariants::TyRef(_, ty, Mutability::MutImmutable) => {
+                    if_chain! {
+                        if is_copy(cx, ty);
+                        if let Some(size) = cx.layout_of(ty).ok().map(|l| l.size.bytes());
+                        if size <= self.ref_min_size;
+                        if let Ty_::TyRptr(_, MutTy { ty: ref decl_ty, .. }) = input.node;
+                        then {
+                            let value_type = if is_self(arg) {
+                                "self".into()
+                            } else {
+                                snippet(cx, decl_ty.span, "_").into()
+                            };
+                            span_lint_and_sugg(
+                                cx,
+                                TRIVIALLY_COPY_PASS_BY_REF,
+                                input.span,
+                                "this argument is passed by reference, but would be more efficient if passed by value",
+                                "consider passing by value instead",
+                                value_type);
+                        }
+                    }
+                },
+                TypeVariants::TyAdt(..) | TypeVariants::TyArray(..) | TypeVariants::TyTuple(..) => {
+                    if_chain! {
+                        // `self` can't be turned into a reference in the signature alone
+                        if !is_self(arg);
+                        if is_copy(cx, ty);
+                        if let Some(size) = cx.layout_of(ty).ok().map(|l| l.size.bytes());
+                        if size > self.value_max_size;
+                        then {
+                            span_lint_and_sugg(
+                                cx,
+                                LARGE_TYPES_PASSED_BY_VALUE,
+                                input.span,
+                                &format!(
+                                    "this argument ({} byte) is passed by value, but might be more efficient if \
+                                     passed by reference (limit: {} byte)",
+                                    size,
+                                    self.value_max_size
+                                ),
+                                "consider passing by reference instead",
+                                format!("&{}", snippet(cx, input.span, "_")),
+                            );
+                        }
+                    }
+                },
+                _ => (),
+            }
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use rustc::hir::*;
+use rustc::lint::*;
+use std::path::Path;
+use syntax::ast::LitKind;
+use crate::utils::{match_type, paths, span_lint_and_sugg, walk_ptrs_ty};
+
+/// **What it does:** Checks for [push](https://doc.rust-lang.org/std/path/struct.PathBuf.html#method.push)
+/// calls on `PathBuf` that can cause overwrites.
+///
+/// **Why is this bad?** Calling `push` with a root path at the start can
+/// overwrite the previous defined path.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// use std::path::PathBuf;
+///
+/// let mut x = PathBuf::from("/foo");
+/// x.push("/bar");
+/// assert_eq!(x, PathBuf::from("/bar"));
+/// ```
+/// Could be written:
+///
+/// ```rust
+/// use std::path::PathBuf;
+///
+/// let mut x = PathBuf::from("/foo");
+/// x.push("bar");
+/// assert_eq!(x, PathBuf::from("/foo/bar"));
+/// ```
+declare_clippy_lint! {
+    pub PATH_BUF_PUSH_OVERWRITE,
+    nursery,
+    "calling `push` with file system root on `PathBuf` can overwrite it"
+}
+
+#[derive(Copy, Clone)]
+pub struct PathBufPushOverwrite;
+
+impl LintPass for PathBufPushOverwrite {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(PATH_BUF_PUSH_OVERWRITE)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for PathBufPushOverwrite {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_chain! {
+            if let ExprMethodCall(ref path, _, ref args) = expr.node;
+            if path.ident.name == "push";
+            if args.len() == 2;
+            if match_type(cx, walk_ptrs_ty(cx.tables.expr_ty(&args[0])), &paths::PATH_BUF);
+            if let ExprLit(ref lit) = args[1].node;
+            if let LitKind::Str(ref pushed, _) = lit.node;
+            let pushed = pushed.as_str();
+            if Path::new(&*pushed).has_root();
+            then {
+                span_lint_and_sugg(
+                    cx,
+                    PATH_BUF_PUSH_OVERWRITE,
+                    lit.span,
+                    "calling `push` with '/' or '\\' (file system root) will overwrite the previous path definition",
+                    "try",
+                    format!("\"{}\"", pushed.trim_left_matches(|c| c == '/' || c == '\\')),
+                );
+            }
+        }
+    }
+}
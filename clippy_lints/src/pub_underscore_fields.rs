@@ -0,0 +1,65 @@
+use rustc::lint::*;
+use syntax::ast::*;
+use crate::utils::{in_macro, span_help_and_lint};
+
+/// **What it does:** Checks for struct fields which are both `pub` and named
+/// with a leading underscore.
+///
+/// **Why is this bad?** A leading underscore marks a binding as intentionally
+/// unused, while making the field `pub` exposes it for use by other code. The
+/// two conventions contradict each other.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// pub struct Foo {
+///     pub _bar: u8,
+/// }
+/// ```
+/// Use instead:
+/// ```rust
+/// pub struct Foo {
+///     pub bar: u8,
+/// }
+/// ```
+declare_clippy_lint! {
+    pub PUB_UNDERSCORE_FIELDS,
+    pedantic,
+    "struct field prefixed with an underscore and marked as public"
+}
+
+#[derive(Copy, Clone)]
+pub struct PubUnderscoreFields;
+
+impl LintPass for PubUnderscoreFields {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(PUB_UNDERSCORE_FIELDS)
+    }
+}
+
+impl EarlyLintPass for PubUnderscoreFields {
+    fn check_item(&mut self, cx: &EarlyContext, item: &Item) {
+        if in_macro(item.span) {
+            return;
+        }
+        if let ItemKind::Struct(ref variant_data, _) = item.node {
+            for field in variant_data.fields() {
+                if_chain! {
+                    if field.vis.node == VisibilityKind::Public;
+                    if let Some(ident) = field.ident;
+                    if ident.as_str().starts_with('_');
+                    then {
+                        span_help_and_lint(
+                            cx,
+                            PUB_UNDERSCORE_FIELDS,
+                            field.span,
+                            "field marked as public but also inferred as unused because it's prefixed with `_`",
+                            "consider removing the underscore, or making the field private",
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
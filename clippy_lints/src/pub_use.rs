@@ -0,0 +1,93 @@
+use rustc::lint::*;
+use syntax::ast::*;
+use crate::utils::{in_macro, span_help_and_lint};
+
+/// **What it does:** Restricts the usage of `pub use ...`
+///
+/// **Why is this bad?** `pub use` is usually fine, but a project may wish to
+/// define all public items at their canonical path instead of re-exporting
+/// them from elsewhere.
+///
+/// **Known problems:** None.
+///
+/// The configuration option `allowed_pub_use_modules` can be set to the
+/// modules (by name, or by path relative to the crate root) that are meant to
+/// re-export items, like a prelude. It defaults to `["prelude"]`.
+///
+/// **Example:**
+/// ```rust
+/// pub mod outer {
+///     mod inner {
+///         pub struct Test {}
+///     }
+///     pub use self::inner::Test;
+/// }
+///
+/// use outer::Test;
+/// ```
+/// Use instead:
+/// ```rust
+/// pub mod outer {
+///     pub struct Test {}
+/// }
+///
+/// use outer::Test;
+/// ```
+declare_clippy_lint! {
+    pub PUB_USE,
+    restriction,
+    "restricts the usage of `pub use`"
+}
+
+pub struct PubUse {
+    allowed_modules: Vec<String>,
+    modules: Vec<String>,
+}
+
+impl PubUse {
+    pub fn new(allowed_modules: Vec<String>) -> Self {
+        Self {
+            allowed_modules,
+            modules: Vec::new(),
+        }
+    }
+
+    fn in_allowed_module(&self) -> bool {
+        let path = self.modules.join("::");
+        self.allowed_modules.iter().any(|allowed| {
+            *allowed == path || (!allowed.contains("::") && self.modules.last() == Some(allowed))
+        })
+    }
+}
+
+impl LintPass for PubUse {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(PUB_USE)
+    }
+}
+
+impl EarlyLintPass for PubUse {
+    fn check_item(&mut self, cx: &EarlyContext, item: &Item) {
+        match item.node {
+            ItemKind::Use(_) => {
+                if item.vis.node == VisibilityKind::Public && !in_macro(item.span) && !self.in_allowed_module() {
+                    span_help_and_lint(
+                        cx,
+                        PUB_USE,
+                        item.span,
+                        "using `pub use`",
+                        "move the exported item to a public module instead",
+                    );
+                }
+            },
+            ItemKind::Mod(_) => self.modules.push(item.ident.to_string()),
+            _ => (),
+        }
+    }
+
+    fn check_item_post(&mut self, _: &EarlyContext, item: &Item) {
+        if let ItemKind::Mod(_) = item.node {
+            self.modules.pop();
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use rustc::hir::*;
+use rustc::hir::def::Def;
+use rustc::hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc::lint::*;
+use rustc_errors::Applicability;
+use syntax::ast::NodeId;
+use crate::utils::{match_trait_method, match_type, paths, snippet, span_lint, span_lint_and_then};
+
+/// **What it does:** Checks for reading into a zero-length `Vec`, right after
+/// creating it with `Vec::new()`, `Vec::with_capacity()` or `vec![]`.
+///
+/// **Why is this bad?** `Read::read` and `Read::read_exact` fill the slice
+/// they are given, and an empty `Vec` derefs to an empty slice whatever its
+/// capacity. Such a read will always return 0 bytes, which is most likely
+/// not what was intended.
+///
+/// **Known problems:** Only the statement directly following the creation of
+/// the `Vec` is checked.
+///
+/// **Example:**
+/// ```rust
+/// use std::io;
+/// fn foo<F: io::Read>(mut f: F) {
+///     let mut data = Vec::with_capacity(100);
+///     f.read(&mut data).unwrap();
+/// }
+/// ```
+/// Use instead:
+/// ```rust
+/// use std::io;
+/// fn foo<F: io::Read>(mut f: F) {
+///     let mut data = Vec::with_capacity(100);
+///     data.resize(100, 0);
+///     f.read(&mut data).unwrap();
+/// }
+/// ```
+declare_clippy_lint! {
+    pub READ_ZERO_BYTE_VEC,
+    correctness,
+    "checks for reads into a zero-length `Vec`"
+}
+
+#[derive(Copy, Clone)]
+pub struct ReadZeroByteVec;
+
+impl LintPass for ReadZeroByteVec {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(READ_ZERO_BYTE_VEC)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ReadZeroByteVec {
+    fn check_block(&mut self, cx: &LateContext<'a, 'tcx>, block: &'tcx Block) {
+        for (idx, stmt) in block.stmts.iter().enumerate() {
+            if_chain! {
+                if let StmtDecl(ref decl, _) = stmt.node;
+                if let DeclLocal(ref local) = decl.node;
+                if let PatKind::Binding(_, canonical_id, ident, None) = local.pat.node;
+                if let Some(ref init) = local.init;
+                if let ExprCall(ref func, ref args) = init.node;
+                if let ExprPath(QPath::TypeRelative(_, ref method)) = func.node;
+                if match_type(cx, cx.tables.expr_ty(init), &paths::VEC);
+                then {
+                    // the statement right after the `let`, or the trailing expression
+                    let (next_span, next_expr) = match block.stmts.get(idx + 1) {
+                        Some(next) => match stmt_expr(next) {
+                            Some(e) => (next.span, e),
+                            None => continue,
+                        },
+                        None => match block.expr {
+                            Some(ref e) => (e.span, &**e),
+                            None => continue,
+                        },
+                    };
+
+                    let mut visitor = ReadVecVisitor {
+                        cx,
+                        id: canonical_id,
+                        found: false,
+                    };
+                    visitor.visit_expr(next_expr);
+                    if !visitor.found {
+                        continue;
+                    }
+
+                    if method.ident.name == "with_capacity" && args.len() == 1 {
+                        span_lint_and_then(cx, READ_ZERO_BYTE_VEC, next_span, "reading zero byte data to `Vec`", |db| {
+                            db.span_suggestion_with_applicability(
+                                next_span,
+                                "try",
+                                format!(
+                                    "{}.resize({}, 0); {}",
+                                    ident.name,
+                                    snippet(cx, args[0].span, ".."),
+                                    snippet(cx, next_span, "..")
+                                ),
+                                Applicability::MaybeIncorrect,
+                            );
+                        });
+                    } else if method.ident.name == "new" {
+                        span_lint(cx, READ_ZERO_BYTE_VEC, next_span, "reading zero byte data to `Vec`");
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Returns the expression of an expression statement, or the initializer of
+/// a `let` statement.
+fn stmt_expr(stmt: &Stmt) -> Option<&Expr> {
+    match stmt.node {
+        StmtExpr(ref e, _) | StmtSemi(ref e, _) => Some(e),
+        StmtDecl(ref decl, _) => match decl.node {
+            DeclLocal(ref local) => local.init.as_ref().map(|e| &**e),
+            DeclItem(_) => None,
+        },
+    }
+}
+
+/// Looks for a call to `Read::read` or `Read::read_exact` with `&mut v` as
+/// argument, where `v` is the local with the given `id`.
+struct ReadVecVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    id: NodeId,
+    found: bool,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for ReadVecVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if_chain! {
+            if let ExprMethodCall(ref method, _, ref args) = expr.node;
+            if method.ident.name == "read" || method.ident.name == "read_exact";
+            if args.len() == 2;
+            if let ExprAddrOf(MutMutable, ref inner) = args[1].node;
+            if let ExprPath(ref qpath) = inner.node;
+            if let Def::Local(id) = self.cx.tables.qpath_def(qpath, inner.hir_id);
+            if id == self.id;
+            if match_trait_method(self.cx, expr, &paths::IO_READ);
+            then {
+                self.found = true;
+                return;
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
@@ -0,0 +1,159 @@
+use rustc::hir::*;
+use rustc::hir::def::Def;
+use rustc::lint::*;
+use syntax::symbol::keywords;
+use crate::utils::{match_def_path, match_var, opt_def_id, paths, span_lint};
+
+/// **What it does:** Checks for format trait implementations (e.g. `Display`)
+/// which format `self` with the very same trait, e.g. with
+/// `write!(f, "{}", self)` in `Display::fmt`.
+///
+/// **Why is this bad?** This calls the implementation again, which results in
+/// an infinite recursion and a stack overflow at runtime.
+///
+/// **Known problems:** Only `self` itself is checked, not bindings referring to
+/// it.
+///
+/// **Example:**
+/// ```rust
+/// use std::fmt;
+///
+/// struct Structure(i32);
+/// impl fmt::Display for Structure {
+///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+///         write!(f, "{}", self)
+///     }
+/// }
+/// ```
+/// Use instead:
+/// ```rust
+/// use std::fmt;
+///
+/// struct Structure(i32);
+/// impl fmt::Display for Structure {
+///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+///         write!(f, "{}", self.0)
+///     }
+/// }
+/// ```
+declare_clippy_lint! {
+    pub RECURSIVE_FORMAT_IMPL,
+    correctness,
+    "formatting `self` in its own format trait implementation, causing infinite recursion"
+}
+
+#[derive(Copy, Clone, PartialEq)]
+enum FormatTrait {
+    Debug,
+    Display,
+}
+
+impl FormatTrait {
+    fn name(self) -> &'static str {
+        match self {
+            FormatTrait::Debug => "Debug",
+            FormatTrait::Display => "Display",
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct RecursiveFormatImpl {
+    /// The trait of the `fmt` method being checked, if any.
+    format_trait: Option<FormatTrait>,
+}
+
+impl LintPass for RecursiveFormatImpl {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(RECURSIVE_FORMAT_IMPL)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for RecursiveFormatImpl {
+    fn check_impl_item(&mut self, cx: &LateContext<'a, 'tcx>, impl_item: &'tcx ImplItem) {
+        if_chain! {
+            if impl_item.ident.name == "fmt";
+            if let ImplItemKind::Method(..) = impl_item.node;
+            let impl_def_id = cx.tcx.hir.get_parent_did(impl_item.id);
+            if let Some(trait_ref) = cx.tcx.impl_trait_ref(impl_def_id);
+            then {
+                if match_def_path(cx.tcx, trait_ref.def_id, &paths::DISPLAY_TRAIT) {
+                    self.format_trait = Some(FormatTrait::Display);
+                } else if match_def_path(cx.tcx, trait_ref.def_id, &paths::DEBUG_TRAIT) {
+                    self.format_trait = Some(FormatTrait::Debug);
+                }
+            }
+        }
+    }
+
+    fn check_impl_item_post(&mut self, _: &LateContext<'a, 'tcx>, impl_item: &'tcx ImplItem) {
+        if impl_item.ident.name == "fmt" {
+            self.format_trait = None;
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        // `format_args!("{}", self)` expands to
+        // `match (&self,) { (arg0,) => [ArgumentV1::new(arg0, Display::fmt)] }`
+        if_chain! {
+            if let Some(format_trait) = self.format_trait;
+            if let ExprMatch(ref matchee, ref arms, _) = expr.node;
+            if let ExprTup(ref values) = matchee.node;
+            if arms.len() == 1 && arms[0].pats.len() == 1;
+            if let PatKind::Tuple(ref pats, None) = arms[0].pats[0].node;
+            if pats.len() == values.len();
+            if let ExprArray(ref args) = arms[0].body.node;
+            then {
+                for arg in args {
+                    if_chain! {
+                        if let ExprCall(ref fun, ref fun_args) = arg.node;
+                        if fun_args.len() == 2;
+                        if let ExprPath(ref qpath) = fun.node;
+                        if let Some(fun_def_id) = opt_def_id(cx.tables.qpath_def(qpath, fun.hir_id));
+                        if match_def_path(cx.tcx, fun_def_id, &paths::FMT_ARGUMENTV1_NEW);
+                        if Some(format_trait) == get_format_trait(cx, &fun_args[1]);
+                        if let ExprPath(ref qpath) = fun_args[0].node;
+                        if let Def::Local(id) = cx.tables.qpath_def(qpath, fun_args[0].hir_id);
+                        if let Some(idx) = pats.iter().position(|pat| match pat.node {
+                            PatKind::Binding(_, binding_id, _, None) => binding_id == id,
+                            _ => false,
+                        });
+                        if is_self(&values[idx]);
+                        then {
+                            span_lint(
+                                cx,
+                                RECURSIVE_FORMAT_IMPL,
+                                values[idx].span,
+                                &format!(
+                                    "using `self` as `{0}` in `impl {0}` will cause infinite recursion",
+                                    format_trait.name()
+                                ),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Returns the trait of a `Trait::fmt` path passed to `ArgumentV1::new`.
+fn get_format_trait(cx: &LateContext, expr: &Expr) -> Option<FormatTrait> {
+    if let ExprPath(ref qpath) = expr.node {
+        let def_id = opt_def_id(cx.tables.qpath_def(qpath, expr.hir_id))?;
+        if match_def_path(cx.tcx, def_id, &paths::DISPLAY_FMT_METHOD) {
+            return Some(FormatTrait::Display);
+        } else if match_def_path(cx.tcx, def_id, &paths::DEBUG_FMT_METHOD) {
+            return Some(FormatTrait::Debug);
+        }
+    }
+    None
+}
+
+/// Checks if `expr` is `self`, possibly behind references or dereferences.
+fn is_self(expr: &Expr) -> bool {
+    match expr.node {
+        ExprAddrOf(_, ref inner) | ExprUnary(UnDeref, ref inner) => is_self(inner),
+        _ => match_var(expr, keywords::SelfValue.name()),
+    }
+}
@@ -0,0 +1,260 @@
+use rustc::hir::def_id::DefId;
+use rustc::hir::intravisit::FnKind;
+use rustc::hir::{Body, FnDecl};
+use rustc::lint::*;
+use rustc::mir::{
+    self, traversal,
+    visit::{PlaceContext, Visitor},
+    TerminatorKind,
+};
+use rustc::ty;
+use rustc_errors::Applicability;
+use syntax::ast::NodeId;
+use syntax::codemap::{BytePos, Span};
+use crate::utils::paths;
+use crate::utils::{
+    in_macro, is_copy, match_def_path, match_type, snippet_opt, span_lint, span_lint_and_then, walk_ptrs_ty_depth,
+};
+
+/// **What it does:** Checks for a redundant `clone()` (and its relatives) which clones an owned
+/// value that is going to be dropped without further use.
+///
+/// **Why is this bad?** It is not always possible for the compiler to eliminate useless
+/// allocations and deallocations generated by redundant `clone()`s.
+///
+/// **Known problems:**
+///
+/// * Suggestions made by this lint could require NLL to be enabled.
+/// * The analysis is conservative: it gives up on loops, and only follows borrows of the
+/// original value that are stored directly in a local.
+///
+/// **Example:**
+/// ```rust
+/// {
+///     let x = Foo::new();
+///     call(x.clone());
+///     call(x.clone()); // this can just pass `x`
+/// }
+///
+/// ["lorem", "ipsum"].join(" ").to_string()
+/// ```
+declare_clippy_lint! {
+    pub REDUNDANT_CLONE,
+    nursery,
+    "`clone()` of an owned value that is going to be dropped immediately"
+}
+
+pub struct RedundantClone;
+
+impl LintPass for RedundantClone {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(REDUNDANT_CLONE)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for RedundantClone {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'a, 'tcx>,
+        _: FnKind<'tcx>,
+        _: &'tcx FnDecl,
+        body: &'tcx Body,
+        _: Span,
+        _: NodeId,
+    ) {
+        let def_id = cx.tcx.hir.body_owner_def_id(body.id());
+        let mir = cx.tcx.optimized_mir(def_id);
+
+        for (bb, bbdata) in mir.basic_blocks().iter_enumerated() {
+            let terminator = match bbdata.terminator {
+                Some(ref terminator) => terminator,
+                None => continue,
+            };
+
+            // Give up on loops
+            if terminator
+                .successors()
+                .any(|s| traversal::Preorder::new(mir, *s).any(|(tbb, _)| tbb == bb))
+            {
+                continue;
+            }
+
+            let (fn_def_id, arg, arg_ty) = match is_call_with_ref_arg(cx, mir, &terminator.kind) {
+                Some(call) => call,
+                None => continue,
+            };
+
+            let from_borrow = match_def_path(cx.tcx, fn_def_id, &paths::CLONE)
+                || match_def_path(cx.tcx, fn_def_id, &paths::TO_OWNED_METHOD)
+                || (match_def_path(cx.tcx, fn_def_id, &paths::TO_STRING_METHOD)
+                    && match_type(cx, arg_ty, &paths::STRING));
+
+            if !from_borrow {
+                continue;
+            }
+
+            // _1 in MIR `{ _2 = &_1; clone(move _2); }`
+            let cloned = match find_stmt_assigns_ref_to(arg, bbdata.statements.iter().rev()) {
+                Some(cloned) => cloned,
+                None => continue,
+            };
+
+            // The original and every local that holds a borrow of it must be dead
+            // after the call, otherwise moving it instead would not compile.
+            let mut possessors = vec![cloned];
+            possessors.extend(borrowers_of(mir, cloned, arg));
+
+            let used_later = traversal::ReversePostorder::new(mir, bb).skip(1).any(|(tbb, tdata)| {
+                let mut vis = LocalUseVisitor {
+                    locals: &possessors,
+                    used_other_than_drop: false,
+                };
+                vis.visit_basic_block_data(tbb, tdata);
+                vis.used_other_than_drop
+            });
+
+            if used_later {
+                continue;
+            }
+
+            let span = terminator.source_info.span;
+            if_chain! {
+                if !in_macro(span);
+                if let Some(snip) = snippet_opt(cx, span);
+                if let Some(dot) = snip.rfind('.');
+                then {
+                    let dot = BytePos(dot as u32);
+                    let sugg_span = span.with_lo(span.lo() + dot);
+
+                    span_lint_and_then(cx, REDUNDANT_CLONE, sugg_span, "redundant clone", |db| {
+                        db.span_suggestion_with_applicability(
+                            sugg_span,
+                            "remove this",
+                            String::new(),
+                            Applicability::MaybeIncorrect,
+                        );
+                        db.span_note(
+                            span.with_hi(span.lo() + dot),
+                            "this value is dropped without further use",
+                        );
+                    });
+                } else {
+                    span_lint(cx, REDUNDANT_CLONE, span, "redundant clone");
+                }
+            }
+        }
+    }
+}
+
+/// If `kind` is `y = func(x: &T)` where `T: !Copy`, returns `(DefId of func, x, T)`.
+fn is_call_with_ref_arg<'tcx>(
+    cx: &LateContext<'_, 'tcx>,
+    mir: &'tcx mir::Mir<'tcx>,
+    kind: &'tcx TerminatorKind<'tcx>,
+) -> Option<(DefId, mir::Local, ty::Ty<'tcx>)> {
+    if_chain! {
+        if let TerminatorKind::Call { ref func, ref args, .. } = *kind;
+        if args.len() == 1;
+        if let mir::Operand::Move(mir::Place::Local(local)) = args[0];
+        if let ty::TyFnDef(def_id, _) = func.ty(mir, cx.tcx).sty;
+        if let (inner_ty, 1) = walk_ptrs_ty_depth(args[0].ty(mir, cx.tcx));
+        if !is_copy(cx, inner_ty);
+        then {
+            Some((def_id, local, inner_ty))
+        } else {
+            None
+        }
+    }
+}
+
+/// Finds the first `to = &from`, and returns `Some(from)`.
+fn find_stmt_assigns_ref_to<'a, 'tcx: 'a>(
+    to: mir::Local,
+    mut stmts: impl Iterator<Item = &'a mir::Statement<'tcx>>,
+) -> Option<mir::Local> {
+    stmts.find_map(|stmt| {
+        if let mir::StatementKind::Assign(mir::Place::Local(local), ref v) = stmt.kind {
+            if local == to {
+                if let mir::Rvalue::Ref(_, _, mir::Place::Local(r)) = *v {
+                    return Some(r);
+                }
+            }
+        }
+        None
+    })
+}
+
+/// Returns the locals other than `except` which are assigned a borrow of `local`,
+/// together with the locals those borrows are moved or copied into.
+fn borrowers_of(mir: &mir::Mir<'_>, local: mir::Local, except: mir::Local) -> Vec<mir::Local> {
+    let mut borrowers = Vec::new();
+    let mut changed = true;
+
+    while changed {
+        changed = false;
+        for bbdata in mir.basic_blocks().iter() {
+            for stmt in &bbdata.statements {
+                if let mir::StatementKind::Assign(mir::Place::Local(dest), ref v) = stmt.kind {
+                    if dest == except || dest == local || borrowers.contains(&dest) {
+                        continue;
+                    }
+
+                    let borrows = match *v {
+                        mir::Rvalue::Ref(_, _, mir::Place::Local(r)) => r == local,
+                        mir::Rvalue::Use(mir::Operand::Copy(mir::Place::Local(r)))
+                        | mir::Rvalue::Use(mir::Operand::Move(mir::Place::Local(r))) => borrowers.contains(&r),
+                        _ => false,
+                    };
+
+                    if borrows {
+                        borrowers.push(dest);
+                        changed = true;
+                    }
+                }
+            }
+        }
+    }
+
+    borrowers
+}
+
+struct LocalUseVisitor<'a> {
+    locals: &'a [mir::Local],
+    used_other_than_drop: bool,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for LocalUseVisitor<'a> {
+    fn visit_basic_block_data(&mut self, block: mir::BasicBlock, data: &mir::BasicBlockData<'tcx>) {
+        let statements = &data.statements;
+        for (statement_index, statement) in statements.iter().enumerate() {
+            self.visit_statement(block, statement, mir::Location { block, statement_index });
+
+            // Once flagged, skip remaining statements
+            if self.used_other_than_drop {
+                return;
+            }
+        }
+
+        if let Some(ref terminator) = data.terminator {
+            self.visit_terminator(
+                block,
+                terminator,
+                mir::Location {
+                    block,
+                    statement_index: statements.len(),
+                },
+            );
+        }
+    }
+
+    fn visit_local(&mut self, local: &mir::Local, ctx: PlaceContext<'tcx>, _: mir::Location) {
+        match ctx {
+            PlaceContext::Drop | PlaceContext::StorageDead => return,
+            _ => {},
+        }
+
+        if self.locals.contains(local) {
+            self.used_other_than_drop = true;
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use rustc::lint::*;
+use syntax::ast::*;
+use crate::utils::{in_macro, is_expn_of, span_help_and_lint};
+
+/// **What it does:** Checks for `else` blocks that can be removed without
+/// changing semantics.
+///
+/// **Why is this bad?** The `else` block adds unnecessary indentation and
+/// verbosity.
+///
+/// **Known problems:** Some may prefer to keep the `else` block for clarity.
+///
+/// **Example:**
+/// ```rust
+/// fn my_func(count: u32) {
+///     if count == 0 {
+///         print!("Nothing to do");
+///         return;
+///     } else {
+///         print!("Moving on...");
+///     }
+/// }
+/// ```
+/// Use instead:
+/// ```rust
+/// fn my_func(count: u32) {
+///     if count == 0 {
+///         print!("Nothing to do");
+///         return;
+///     }
+///     print!("Moving on...");
+/// }
+/// ```
+declare_clippy_lint! {
+    pub REDUNDANT_ELSE,
+    pedantic,
+    "`else` branch that can be removed without changing semantics"
+}
+
+#[derive(Copy, Clone)]
+pub struct RedundantElse;
+
+impl LintPass for RedundantElse {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(REDUNDANT_ELSE)
+    }
+}
+
+impl EarlyLintPass for RedundantElse {
+    fn check_stmt(&mut self, cx: &EarlyContext, stmt: &Stmt) {
+        if in_macro(stmt.span) {
+            return;
+        }
+        // only `if` statements, the value of an `if` expression depends on the `else` block
+        let mut expr = match stmt.node {
+            StmtKind::Expr(ref expr) | StmtKind::Semi(ref expr) => &**expr,
+            _ => return,
+        };
+        // walk the `else if` chain, every branch has to diverge
+        loop {
+            let (then, els) = match expr.node {
+                ExprKind::If(_, ref then, Some(ref els)) | ExprKind::IfLet(_, _, ref then, Some(ref els)) => {
+                    (then, els)
+                },
+                _ => return,
+            };
+            if !block_diverges(then) {
+                return;
+            }
+            match els.node {
+                ExprKind::If(..) | ExprKind::IfLet(..) => expr = els,
+                ExprKind::Block(..) => {
+                    span_help_and_lint(
+                        cx,
+                        REDUNDANT_ELSE,
+                        els.span,
+                        "redundant else block",
+                        "remove the `else` block and move the contents out",
+                    );
+                    return;
+                },
+                _ => return,
+            }
+        }
+    }
+}
+
+/// Checks if the last statement of the block always leaves the enclosing
+/// scope.
+fn block_diverges(block: &Block) -> bool {
+    match block.stmts.last().map(|stmt| &stmt.node) {
+        Some(&StmtKind::Expr(ref expr)) | Some(&StmtKind::Semi(ref expr)) => expr_diverges(expr),
+        Some(&StmtKind::Mac(_)) | Some(&StmtKind::Local(_)) | Some(&StmtKind::Item(_)) | None => false,
+    }
+}
+
+fn expr_diverges(expr: &Expr) -> bool {
+    if ["panic", "unreachable", "unimplemented"]
+        .iter()
+        .any(|name| is_expn_of(expr.span, name).is_some())
+    {
+        return true;
+    }
+    match expr.node {
+        ExprKind::Ret(_) | ExprKind::Break(..) | ExprKind::Continue(_) => true,
+        ExprKind::Block(ref block, _) => block_diverges(block),
+        ExprKind::If(_, ref then, Some(ref els)) | ExprKind::IfLet(_, _, ref then, Some(ref els)) => {
+            block_diverges(then) && expr_diverges(els)
+        },
+        ExprKind::Match(_, ref arms) => !arms.is_empty() && arms.iter().all(|arm| expr_diverges(&arm.body)),
+        _ => false,
+    }
+}
@@ -0,0 +1,96 @@
+use rustc::hir::*;
+use rustc::hir::def::Def;
+use rustc::hir::def_id::DefId;
+use rustc::lint::*;
+use syntax::ast::{LitIntType, LitKind};
+use crate::utils::{in_macro, snippet, span_lint_and_sugg};
+
+/// **What it does:** Warns about needless / redundant type annotations.
+///
+/// **Why is this bad?** Code without type annotations is shorter and in most
+/// cases more idiomatic and easier to modify.
+///
+/// **Known problems:** This lint doesn't support:
+///
+/// - Generic functions and methods, like `Vec::new()`, as the annotation may
+///   be what fixes the type parameters
+/// - Numeric literals without a suffix
+///
+/// **Example:**
+/// ```rust
+/// let foo: String = String::new();
+/// ```
+/// Use instead:
+/// ```rust
+/// let foo = String::new();
+/// ```
+declare_clippy_lint! {
+    pub REDUNDANT_TYPE_ANNOTATIONS,
+    restriction,
+    "warns about needless / redundant type annotations"
+}
+
+#[derive(Copy, Clone)]
+pub struct RedundantTypeAnnotations;
+
+impl LintPass for RedundantTypeAnnotations {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(REDUNDANT_TYPE_ANNOTATIONS)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for RedundantTypeAnnotations {
+    fn check_local(&mut self, cx: &LateContext<'a, 'tcx>, local: &'tcx Local) {
+        if_chain! {
+            if !in_macro(local.span);
+            if let Some(ref ty) = local.ty;
+            if let Some(ref init) = local.init;
+            if is_unambiguous(cx, init);
+            // a coercion like `let x: &[u8] = &[1, 2];` needs the annotation
+            if cx.tcx.erase_regions(&cx.tables.expr_ty(init)) == cx.tcx.erase_regions(&cx.tables.pat_ty(&local.pat));
+            then {
+                let span = local.pat.span.to(ty.span);
+                span_lint_and_sugg(
+                    cx,
+                    REDUNDANT_TYPE_ANNOTATIONS,
+                    span,
+                    "redundant type annotation",
+                    "remove the type annotation",
+                    snippet(cx, local.pat.span, "..").into_owned(),
+                );
+            }
+        }
+    }
+}
+
+/// Checks if the type of `expr` doesn't depend on the expected type, i.e. it
+/// is a suffixed literal or a call to a function without generic parameters.
+fn is_unambiguous(cx: &LateContext, expr: &Expr) -> bool {
+    match expr.node {
+        ExprLit(ref lit) => match lit.node {
+            LitKind::Int(_, LitIntType::Unsuffixed) | LitKind::FloatUnsuffixed(_) => false,
+            _ => true,
+        },
+        ExprCall(ref func, _) => {
+            if let ExprPath(ref qpath) = func.node {
+                match cx.tables.qpath_def(qpath, func.hir_id) {
+                    Def::Fn(def_id) | Def::Method(def_id) => !is_generic(cx, def_id),
+                    _ => false,
+                }
+            } else {
+                false
+            }
+        },
+        ExprMethodCall(..) => match cx.tables.type_dependent_defs().get(expr.hir_id) {
+            Some(&Def::Method(def_id)) => !is_generic(cx, def_id),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Checks if the function or its parent (an `impl` or a trait) has generic
+/// parameters.
+fn is_generic(cx: &LateContext, def_id: DefId) -> bool {
+    cx.tcx.generics_of(def_id).count() != 0
+}
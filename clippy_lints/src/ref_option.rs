@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+use rustc::hir::*;
+use rustc::hir::def::Def;
+use rustc::hir::def_id::DefId;
+use rustc::hir::map::NodeItem;
+use rustc::lint::*;
+use rustc::ty;
+use syntax::ast::NodeId;
+use syntax::codemap::Span;
+use rustc_errors::Applicability;
+use crate::utils::{in_macro, match_type, paths, snippet, snippet_opt, span_lint_node_and_then};
+use crate::utils::sugg::Sugg;
+
+/// **What it does:** Checks for functions taking an argument of type
+/// `&Option<T>`.
+///
+/// **Why is this bad?** `Option<&T>` is more flexible for the caller: it can be
+/// built from an `Option<T>` with `.as_ref()`, from a plain `&T` with `Some`,
+/// and from `None` without an owned `Option` to borrow. It is also a single
+/// pointer-sized value instead of a reference to an `Option`.
+///
+/// **Known problems:** Only the signature and call sites passing `&expr`
+/// directly in the same crate are suggested to be changed. Other call sites
+/// and uses of the argument in the function body have to be updated by hand.
+///
+/// **Example:**
+/// ```rust
+/// fn foo(a: &Option<String>) {}
+/// ```
+/// Use instead:
+/// ```rust
+/// fn foo(a: Option<&String>) {}
+/// ```
+declare_clippy_lint! {
+    pub REF_OPTION,
+    pedantic,
+    "function signature uses `&Option<T>` instead of `Option<&T>`"
+}
+
+/// An `&Option<T>` argument found in a function signature.
+struct RefOptionArg {
+    fn_id: NodeId,
+    fn_def_id: DefId,
+    idx: usize,
+    span: Span,
+    sugg: String,
+}
+
+#[derive(Default)]
+pub struct RefOption {
+    args: Vec<RefOptionArg>,
+    /// Arguments of the form `&expr` passed to local functions, indexed by
+    /// the callee and the argument position, with their replacement.
+    call_sites: HashMap<(DefId, usize), Vec<(Span, String)>>,
+}
+
+impl LintPass for RefOption {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(REF_OPTION)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for RefOption {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if let ItemFn(ref decl, ..) = item.node {
+            self.check_fn(cx, decl, item.id, item.span);
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx ImplItem) {
+        if let ImplItemKind::Method(ref sig, _) = item.node {
+            if let Some(NodeItem(it)) = cx.tcx.hir.find(cx.tcx.hir.get_parent(item.id)) {
+                if let ItemImpl(_, _, _, _, Some(_), _, _) = it.node {
+                    return; // ignore trait impls
+                }
+            }
+            self.check_fn(cx, &sig.decl, item.id, item.span);
+        }
+    }
+
+    fn check_trait_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx TraitItem) {
+        if let TraitItemKind::Method(ref sig, _) = item.node {
+            self.check_fn(cx, &sig.decl, item.id, item.span);
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_macro(expr.span) {
+            return;
+        }
+        let (callee, args) = match expr.node {
+            ExprCall(ref func, ref args) => {
+                if let ExprPath(ref qpath) = func.node {
+                    match cx.tables.qpath_def(qpath, func.hir_id) {
+                        Def::Fn(def_id) | Def::Method(def_id) => (def_id, args),
+                        _ => return,
+                    }
+                } else {
+                    return;
+                }
+            },
+            ExprMethodCall(_, _, ref args) => (cx.tables.type_dependent_defs()[expr.hir_id].def_id(), args),
+            _ => return,
+        };
+        if !callee.is_local() {
+            return;
+        }
+        for (idx, arg) in args.iter().enumerate() {
+            if let ExprAddrOf(MutImmutable, ref inner) = arg.node {
+                let sugg = format!("{}.as_ref()", Sugg::hir(cx, inner, "..").maybe_par());
+                self.call_sites
+                    .entry((callee, idx))
+                    .or_insert_with(Vec::new)
+                    .push((arg.span, sugg));
+            }
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'a, 'tcx>, _: &'tcx Crate) {
+        for arg in &self.args {
+            let mut call_sites = self.call_sites.get(&(arg.fn_def_id, arg.idx)).cloned().unwrap_or_default();
+            call_sites.sort_by_key(|&(span, _)| span);
+            // the lint is emitted at the end of the crate, so the lint level of the function is used
+            span_lint_node_and_then(
+                cx,
+                REF_OPTION,
+                arg.fn_id,
+                arg.span,
+                "it is more idiomatic to use `Option<&T>` instead of `&Option<T>`",
+                |db| {
+                    // uses of the argument in the body, and call sites in other crates or
+                    // through function pointers, are not updated
+                    db.span_suggestion_with_applicability(
+                        arg.span,
+                        "change this to",
+                        arg.sugg.clone(),
+                        Applicability::MaybeIncorrect,
+                    );
+                    for (span, sugg) in call_sites {
+                        db.span_suggestion_with_applicability(
+                            span,
+                            &format!("change `{}` to", snippet(cx, span, "..")),
+                            sugg,
+                            Applicability::MaybeIncorrect,
+                        );
+                    }
+                },
+            );
+        }
+    }
+}
+
+impl RefOption {
+    fn check_fn(&mut self, cx: &LateContext, decl: &FnDecl, fn_id: NodeId, span: Span) {
+        if in_macro(span) {
+            return;
+        }
+        let fn_def_id = cx.tcx.hir.local_def_id(fn_id);
+        let sig = cx.tcx.fn_sig(fn_def_id);
+        let fn_ty = sig.skip_binder();
+
+        for (idx, (arg, ty)) in decl.inputs.iter().zip(fn_ty.inputs()).enumerate() {
+            if_chain! {
+                if let ty::TyRef(_, ty, MutImmutable) = ty.sty;
+                if match_type(cx, ty, &paths::OPTION);
+                if let TyRptr(_, MutTy { ty: ref inner, .. }) = arg.node;
+                if let TyPath(QPath::Resolved(_, ref path)) = inner.node;
+                if let Some(&PathSegment { args: Some(ref parameters), .. }) = path.segments.last();
+                if let Some(&GenericArg::Type(ref opt_ty)) = parameters.args.iter().next();
+                if let Some(opt_ty) = snippet_opt(cx, opt_ty.span);
+                then {
+                    self.args.push(RefOptionArg {
+                        fn_id,
+                        fn_def_id,
+                        idx,
+                        span: arg.span,
+                        sugg: format!("Option<&{}>", opt_ty),
+                    });
+                }
+            }
+        }
+    }
+}
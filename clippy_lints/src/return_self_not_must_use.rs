@@ -0,0 +1,76 @@
+use rustc::hir::*;
+use rustc::lint::*;
+use syntax::attr;
+use crate::utils::{in_external_macro, is_must_use_ty, return_ty, same_tys, span_help_and_lint};
+
+/// **What it does:** Checks for public methods taking `self` and returning
+/// `Self` which are not marked `#[must_use]`, neither on the method nor on the
+/// `Self` type.
+///
+/// **Why is this bad?** Such methods are usually builder-style methods which
+/// return a modified copy of `self`. Calling them without using the returned
+/// value is almost always a bug, since the change is silently thrown away.
+///
+/// **Known problems:** Only public methods of inherent impls are checked.
+///
+/// **Example:**
+/// ```rust
+/// pub struct Bar;
+///
+/// impl Bar {
+///     // Bad
+///     pub fn bar(self) -> Self {
+///         self
+///     }
+///
+///     // Good
+///     #[must_use]
+///     pub fn baz(self) -> Self {
+///         self
+///     }
+/// }
+/// ```
+declare_clippy_lint! {
+    pub RETURN_SELF_NOT_MUST_USE,
+    pedantic,
+    "missing `#[must_use]` annotation on a method returning `Self`"
+}
+
+#[derive(Copy, Clone)]
+pub struct ReturnSelfNotMustUse;
+
+impl LintPass for ReturnSelfNotMustUse {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(RETURN_SELF_NOT_MUST_USE)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ReturnSelfNotMustUse {
+    fn check_impl_item(&mut self, cx: &LateContext<'a, 'tcx>, impl_item: &'tcx ImplItem) {
+        if in_external_macro(cx, impl_item.span) || !cx.access_levels.is_exported(impl_item.id) {
+            return;
+        }
+        let parent = cx.tcx.hir.get_parent(impl_item.id);
+        let item = cx.tcx.hir.expect_item(parent);
+        if_chain! {
+            // trait methods get their attributes from the trait definition
+            if let ItemImpl(_, _, _, _, None, _, _) = item.node;
+            if let ImplItemKind::Method(ref sig, _) = impl_item.node;
+            if sig.decl.has_implicit_self;
+            if !attr::contains_name(&impl_item.attrs, "must_use");
+            let self_ty = cx.tcx.type_of(cx.tcx.hir.local_def_id(item.id));
+            let ret_ty = return_ty(cx, impl_item.id);
+            if same_tys(cx, ret_ty, self_ty);
+            if !is_must_use_ty(cx, ret_ty);
+            then {
+                span_help_and_lint(
+                    cx,
+                    RETURN_SELF_NOT_MUST_USE,
+                    impl_item.span,
+                    "missing `#[must_use]` attribute on a method returning `Self`",
+                    "consider adding the `#[must_use]` attribute to the method or directly to the `Self` type",
+                );
+            }
+        }
+    }
+}
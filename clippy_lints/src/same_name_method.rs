@@ -0,0 +1,103 @@
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::ty;
+use std::collections::HashMap;
+use crate::utils::{in_macro, is_automatically_derived, span_note_and_lint};
+
+/// **What it does:** Checks for methods which have the same name as a method
+/// of a trait implemented by the same type.
+///
+/// **Why is this bad?** Method calls silently prefer the inherent method, so
+/// `x.foo()` and `Trait::foo(&x)` run different code. This is confusing to
+/// readers, and behavior changes if the inherent method is removed or its
+/// receiver changes.
+///
+/// **Known problems:** Only trait implementations in the current crate are
+/// checked. The lint is emitted for the trait implementation, so it has to be
+/// allowed there.
+///
+/// **Example:**
+/// ```rust
+/// trait T {
+///     fn foo(&self) {}
+/// }
+///
+/// struct S;
+///
+/// impl T for S {
+///     fn foo(&self) {}
+/// }
+///
+/// impl S {
+///     fn foo(&self) {}
+/// }
+/// ```
+declare_clippy_lint! {
+    pub SAME_NAME_METHOD,
+    restriction,
+    "two methods with the same name from the type itself and a trait it implements"
+}
+
+#[derive(Copy, Clone)]
+pub struct SameNameMethod;
+
+impl LintPass for SameNameMethod {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(SAME_NAME_METHOD)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for SameNameMethod {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if in_macro(item.span) || is_automatically_derived(&item.attrs) {
+            return;
+        }
+        if let ItemImpl(_, _, _, _, Some(ref trait_ref), _, ref impl_items) = item.node {
+            let self_ty = cx.tcx.type_of(cx.tcx.hir.local_def_id(item.id));
+            let adt_def = match self_ty.sty {
+                ty::TyAdt(adt_def, _) => adt_def,
+                _ => return,
+            };
+
+            // all the methods of the type itself, by name
+            let inherent_methods: HashMap<_, _> = cx
+                .tcx
+                .inherent_impls(adt_def.did)
+                .iter()
+                .flat_map(|&impl_def_id| cx.tcx.associated_items(impl_def_id))
+                .filter(|assoc_item| assoc_item.kind == ty::AssociatedKind::Method)
+                .map(|assoc_item| (assoc_item.ident.name, assoc_item.def_id))
+                .collect();
+            if inherent_methods.is_empty() {
+                return;
+            }
+
+            let trait_methods = cx
+                .tcx
+                .associated_items(trait_ref.path.def.def_id())
+                .filter(|assoc_item| assoc_item.kind == ty::AssociatedKind::Method);
+            for trait_method in trait_methods {
+                let inherent_span = match inherent_methods.get(&trait_method.ident.name) {
+                    Some(&def_id) if def_id.is_local() => cx.tcx.def_span(def_id),
+                    _ => continue,
+                };
+                // point to the method in the trait impl, or to the impl for provided methods
+                let existing_span = impl_items
+                    .iter()
+                    .find(|impl_item| impl_item.ident.name == trait_method.ident.name)
+                    .map_or_else(
+                        || cx.tcx.def_span(cx.tcx.hir.local_def_id(item.id)),
+                        |impl_item| cx.tcx.def_span(cx.tcx.hir.local_def_id(impl_item.id.node_id)),
+                    );
+                span_note_and_lint(
+                    cx,
+                    SAME_NAME_METHOD,
+                    inherent_span,
+                    "method's name is the same as an existing method in a trait",
+                    existing_span,
+                    &format!("existing `{}` defined here", trait_method.ident),
+                );
+            }
+        }
+    }
+}
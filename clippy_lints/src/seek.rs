@@ -0,0 +1,144 @@
+use rustc::hir::*;
+use rustc::hir::def::Def;
+use rustc::hir::map::Node;
+use rustc::lint::*;
+use rustc_errors::Applicability;
+use semver::Version;
+use crate::utils::{in_macro, is_integer_literal, match_def_path, match_trait_method, meets_msrv, paths, snippet,
+                   span_lint_and_then};
+
+/// **What it does:** Checks for usage of `seek(SeekFrom::Current(0))`.
+///
+/// **Why is this bad?** Readability. There is a specific method that was
+/// implemented for this exact scenario.
+///
+/// **Known problems:** `stream_position` is only available since Rust 1.51,
+/// set the `msrv` configuration if the crate supports older versions.
+///
+/// **Example:**
+/// ```rust
+/// fn foo<T: std::io::Seek>(t: &mut T) {
+///     t.seek(std::io::SeekFrom::Current(0));
+/// }
+/// ```
+/// Use instead:
+/// ```rust
+/// fn foo<T: std::io::Seek>(t: &mut T) {
+///     t.stream_position();
+/// }
+/// ```
+declare_clippy_lint! {
+    pub SEEK_FROM_CURRENT,
+    complexity,
+    "use dedicated method for seek from current position"
+}
+
+/// **What it does:** Checks for jumps to the start of a stream that
+/// discard the new position, like `seek(SeekFrom::Start(0));`.
+///
+/// **Why is this bad?** Readability. There is a specific method that was
+/// implemented for this exact scenario.
+///
+/// **Known problems:** `rewind` is only available since Rust 1.55, set the
+/// `msrv` configuration if the crate supports older versions.
+///
+/// **Example:**
+/// ```rust
+/// fn foo<T: std::io::Seek>(t: &mut T) {
+///     t.seek(std::io::SeekFrom::Start(0));
+/// }
+/// ```
+/// Use instead:
+/// ```rust
+/// fn foo<T: std::io::Seek>(t: &mut T) {
+///     t.rewind();
+/// }
+/// ```
+declare_clippy_lint! {
+    pub SEEK_TO_START_INSTEAD_OF_REWIND,
+    complexity,
+    "jumping to the start of stream using `seek` method"
+}
+
+pub struct Seek {
+    msrv: Option<Version>,
+}
+
+impl Seek {
+    pub fn new(msrv: Option<Version>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl LintPass for Seek {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(SEEK_FROM_CURRENT, SEEK_TO_START_INSTEAD_OF_REWIND)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Seek {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_chain! {
+            if !in_macro(expr.span);
+            if let ExprMethodCall(ref method, method_span, ref args) = expr.node;
+            if method.ident.name == "seek" && args.len() == 2;
+            if match_trait_method(cx, expr, &paths::IO_SEEK);
+            if let ExprCall(ref func, ref func_args) = args[1].node;
+            if func_args.len() == 1 && is_integer_literal(&func_args[0], 0);
+            if let ExprPath(ref qpath) = func.node;
+            if let Def::VariantCtor(def_id, _) = cx.tables.qpath_def(qpath, func.hir_id);
+            then {
+                if match_def_path(cx.tcx, def_id, &paths::SEEK_FROM_CURRENT)
+                    && meets_msrv(self.msrv.as_ref(), &Version::new(1, 51, 0))
+                {
+                    span_lint_and_then(
+                        cx,
+                        SEEK_FROM_CURRENT,
+                        expr.span,
+                        "using `SeekFrom::Current` to start from current position",
+                        |db| {
+                            db.span_suggestion_with_applicability(
+                                expr.span,
+                                "replace with",
+                                format!("{}.stream_position()", snippet(cx, args[0].span, "..")),
+                                Applicability::MachineApplicable,
+                            );
+                        },
+                    );
+                } else if match_def_path(cx.tcx, def_id, &paths::SEEK_FROM_START)
+                    && meets_msrv(self.msrv.as_ref(), &Version::new(1, 55, 0))
+                    && is_result_discarded(cx, expr)
+                {
+                    let span = method_span.with_hi(expr.span.hi());
+                    span_lint_and_then(
+                        cx,
+                        SEEK_TO_START_INSTEAD_OF_REWIND,
+                        span,
+                        "used `seek` to go to the start of the stream",
+                        |db| {
+                            db.span_suggestion_with_applicability(
+                                span,
+                                "replace with",
+                                "rewind()".to_string(),
+                                Applicability::MachineApplicable,
+                            );
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Checks if `expr` is a statement on its own, as `rewind` doesn't return the
+/// new position like `seek` does.
+fn is_result_discarded(cx: &LateContext, expr: &Expr) -> bool {
+    let parent = cx.tcx.hir.get_parent_node(expr.id);
+    match cx.tcx.hir.find(parent) {
+        Some(Node::NodeStmt(stmt)) => match stmt.node {
+            StmtSemi(ref e, _) => e.id == expr.id,
+            _ => false,
+        },
+        _ => false,
+    }
+}
@@ -0,0 +1,86 @@
+use rustc::hir::*;
+use rustc::lint::*;
+use crate::utils::{in_macro, snippet, span_lint, SpanlessEq};
+
+/// **What it does:** Checks for explicit self-assignments.
+///
+/// **Why is this bad?** Self-assignments are redundant and unlikely to be
+/// intentional.
+///
+/// **Known problems:** If expression contains any deref coercions or
+/// indexing operations they are assumed not to have any side effects.
+/// Overloaded dereferences (`*x` on a type implementing `Deref`) are never
+/// considered the same place, as wrappers around volatile memory rely on them.
+///
+/// **Example:**
+/// ```rust
+/// struct Event {
+///     id: usize,
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// fn copy_position(a: &mut Event, b: &Event) {
+///     a.x = b.x;
+///     a.y = a.y;
+/// }
+/// ```
+declare_clippy_lint! {
+    pub SELF_ASSIGNMENT,
+    correctness,
+    "explicit self-assignment"
+}
+
+#[derive(Copy, Clone)]
+pub struct SelfAssignment;
+
+impl LintPass for SelfAssignment {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(SELF_ASSIGNMENT)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for SelfAssignment {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_chain! {
+            if !in_macro(expr.span);
+            if let ExprAssign(ref lhs, ref rhs) = expr.node;
+            if is_plain_place(cx, lhs);
+            if SpanlessEq::new(cx).ignore_fn().eq_expr(lhs, rhs);
+            then {
+                span_lint(
+                    cx,
+                    SELF_ASSIGNMENT,
+                    expr.span,
+                    &format!(
+                        "self-assignment of `{}` to `{}`",
+                        snippet(cx, rhs.span, ".."),
+                        snippet(cx, lhs.span, "..")
+                    ),
+                );
+            }
+        }
+    }
+}
+
+/// Checks if `expr` is a place made of locals, fields, built-in dereferences
+/// and indexing with side-effect free indices, so that assigning it to
+/// itself can't do anything.
+fn is_plain_place(cx: &LateContext, expr: &Expr) -> bool {
+    match expr.node {
+        ExprPath(_) => true,
+        ExprField(ref inner, _) => is_plain_place(cx, inner),
+        ExprUnary(UnDeref, ref inner) => !cx.tables.is_method_call(expr) && is_plain_place(cx, inner),
+        ExprIndex(ref inner, ref index) => is_plain_place(cx, inner) && is_side_effect_free(index),
+        _ => false,
+    }
+}
+
+fn is_side_effect_free(expr: &Expr) -> bool {
+    match expr.node {
+        ExprLit(_) | ExprPath(_) => true,
+        ExprField(ref inner, _) | ExprUnary(_, ref inner) | ExprCast(ref inner, _) => is_side_effect_free(inner),
+        ExprBinary(_, ref left, ref right) => is_side_effect_free(left) && is_side_effect_free(right),
+        _ => false,
+    }
+}
@@ -0,0 +1,80 @@
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::ty;
+use rustc_errors::Applicability;
+use crate::utils::{in_macro, snippet_opt, span_lint_and_then};
+
+/// **What it does:** Looks for blocks of expressions and fires if the last
+/// expression returns `()` but is not followed by a semicolon.
+///
+/// **Why is this bad?** The semicolon might be optional but when extending the
+/// block with new code, it doesn't require a change in both lines. It also
+/// makes it explicit that the block doesn't return anything.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// fn main() {
+///     println!("Hello world")
+/// }
+/// ```
+/// Use instead:
+/// ```rust
+/// fn main() {
+///     println!("Hello world");
+/// }
+/// ```
+declare_clippy_lint! {
+    pub SEMICOLON_IF_NOTHING_RETURNED,
+    pedantic,
+    "add a semicolon if nothing is returned"
+}
+
+#[derive(Copy, Clone)]
+pub struct SemicolonIfNothingReturned;
+
+impl LintPass for SemicolonIfNothingReturned {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(SEMICOLON_IF_NOTHING_RETURNED)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for SemicolonIfNothingReturned {
+    fn check_block(&mut self, cx: &LateContext<'a, 'tcx>, block: &'tcx Block) {
+        if_chain! {
+            if !in_macro(block.span);
+            if let Some(ref expr) = block.expr;
+            // the arms of these expressions are checked as blocks of their own
+            if !is_block_like(expr);
+            if let ty::TyTuple(tys) = cx.tables.expr_ty(expr).sty;
+            if tys.is_empty();
+            // a macro call in tail position is linted as a whole
+            let span = expr.span.source_callsite();
+            if let Some(snippet) = snippet_opt(cx, span);
+            then {
+                span_lint_and_then(
+                    cx,
+                    SEMICOLON_IF_NOTHING_RETURNED,
+                    span,
+                    "consider adding a `;` to the last statement for consistent formatting",
+                    |db| {
+                        db.span_suggestion_with_applicability(
+                            span,
+                            "add a `;` here",
+                            format!("{};", snippet),
+                            Applicability::MachineApplicable,
+                        );
+                    },
+                );
+            }
+        }
+    }
+}
+
+fn is_block_like(expr: &Expr) -> bool {
+    match expr.node {
+        ExprBlock(..) | ExprIf(..) | ExprMatch(..) | ExprLoop(..) | ExprWhile(..) => true,
+        _ => false,
+    }
+}
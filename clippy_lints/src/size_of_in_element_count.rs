@@ -0,0 +1,147 @@
+use rustc::hir::*;
+use rustc::hir::def::Def;
+use rustc::lint::*;
+use rustc::ty::{self, Ty};
+use crate::utils::{match_def_path, paths, span_help_and_lint, walk_ptrs_ty};
+
+/// **What it does:** Detects expressions where
+/// `size_of::<T>` or `size_of_val::<T>` is used as a
+/// count of elements of type `T`
+///
+/// **Why is this bad?** These functions expect a count
+/// of `T` and not a number of bytes
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust,no_run
+/// use std::ptr::copy_nonoverlapping;
+/// use std::mem::size_of;
+/// const SIZE: usize = 128;
+/// let x = [2u8; SIZE];
+/// let mut y = [2u8; SIZE];
+/// unsafe { copy_nonoverlapping(x.as_ptr(), y.as_mut_ptr(), size_of::<u8>() * SIZE) };
+/// ```
+declare_clippy_lint! {
+    pub SIZE_OF_IN_ELEMENT_COUNT,
+    correctness,
+    "using `size_of::<T>` or `size_of_val::<T>` where a count of elements of `T` is expected"
+}
+
+/// Functions taking a pointer to `T` as first argument and a count of `T` as
+/// last argument.
+const FUNCTIONS: [&[&str]; 6] = [
+    &paths::PTR_COPY,
+    &paths::PTR_COPY_NONOVERLAPPING,
+    &paths::PTR_SWAP_NONOVERLAPPING,
+    &paths::PTR_WRITE_BYTES,
+    &paths::SLICE_FROM_RAW_PARTS,
+    &paths::SLICE_FROM_RAW_PARTS_MUT,
+];
+
+/// Methods of raw pointers taking a count of pointees as last argument.
+const METHODS: [&str; 11] = [
+    "copy_to",
+    "copy_to_nonoverlapping",
+    "copy_from",
+    "copy_from_nonoverlapping",
+    "write_bytes",
+    "add",
+    "sub",
+    "offset",
+    "wrapping_add",
+    "wrapping_sub",
+    "wrapping_offset",
+];
+
+#[derive(Copy, Clone)]
+pub struct SizeOfInElementCount;
+
+impl LintPass for SizeOfInElementCount {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(SIZE_OF_IN_ELEMENT_COUNT)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for SizeOfInElementCount {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_chain! {
+            if let Some((pointee_ty, count_expr)) = get_pointee_ty_and_count_expr(cx, expr);
+            if let Some(ty_used_for_size_of) = get_size_of_ty(cx, count_expr, false);
+            if pointee_ty == ty_used_for_size_of;
+            then {
+                span_help_and_lint(
+                    cx,
+                    SIZE_OF_IN_ELEMENT_COUNT,
+                    count_expr.span,
+                    &format!("found a count of bytes instead of a count of elements of `{}`", pointee_ty),
+                    "use a count of elements instead of a count of bytes, it already gets multiplied by the size of \
+                     the type",
+                );
+            }
+        }
+    }
+}
+
+/// Returns the type of `size_of::<T>()` or `size_of_val(&t)` if `expr` is a
+/// byte count made of it, i.e. it is multiplied by something, or divides
+/// something if `inverted`.
+fn get_size_of_ty<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr, inverted: bool) -> Option<Ty<'tcx>> {
+    match expr.node {
+        ExprCall(ref count_func, ref args) if !inverted => {
+            if let ExprPath(ref qpath) = count_func.node {
+                if let Def::Fn(def_id) = cx.tables.qpath_def(qpath, count_func.hir_id) {
+                    if match_def_path(cx.tcx, def_id, &paths::MEM_SIZE_OF) {
+                        return Some(cx.tables.node_substs(count_func.hir_id).type_at(0));
+                    } else if match_def_path(cx.tcx, def_id, &paths::MEM_SIZE_OF_VAL) && args.len() == 1 {
+                        return Some(walk_ptrs_ty(cx.tables.expr_ty(&args[0])));
+                    }
+                }
+            }
+            None
+        },
+        ExprBinary(ref op, ref left, ref right) => match op.node {
+            BiMul => get_size_of_ty(cx, left, inverted).or_else(|| get_size_of_ty(cx, right, inverted)),
+            BiDiv => get_size_of_ty(cx, left, inverted).or_else(|| get_size_of_ty(cx, right, !inverted)),
+            _ => None,
+        },
+        ExprCast(ref inner, _) => get_size_of_ty(cx, inner, inverted),
+        _ => None,
+    }
+}
+
+/// Returns the pointee type and the count argument if `expr` is a call to a
+/// function or raw pointer method expecting a count of elements.
+fn get_pointee_ty_and_count_expr<'a, 'tcx>(
+    cx: &LateContext<'a, 'tcx>,
+    expr: &'tcx Expr,
+) -> Option<(Ty<'tcx>, &'tcx Expr)> {
+    let (ptr_arg, count_arg) = match expr.node {
+        ExprCall(ref func, ref args) => {
+            if_chain! {
+                if args.len() >= 2;
+                if let ExprPath(ref qpath) = func.node;
+                if let Def::Fn(def_id) = cx.tables.qpath_def(qpath, func.hir_id);
+                if FUNCTIONS.iter().any(|path| match_def_path(cx.tcx, def_id, path));
+                then {
+                    (&args[0], &args[args.len() - 1])
+                } else {
+                    return None;
+                }
+            }
+        },
+        ExprMethodCall(ref method, _, ref args) => {
+            if args.len() >= 2 && METHODS.iter().any(|name| method.ident.name == *name) {
+                (&args[0], &args[args.len() - 1])
+            } else {
+                return None;
+            }
+        },
+        _ => return None,
+    };
+    if let ty::TyRawPtr(ty::TypeAndMut { ty: pointee_ty, .. }) = cx.tables.expr_ty(ptr_arg).sty {
+        Some((pointee_ty, count_arg))
+    } else {
+        None
+    }
+}
@@ -0,0 +1,71 @@
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc::ty;
+use crate::utils::{in_macro, span_help_and_lint};
+
+/// **What it does:** Checks for structs whose last field is a zero-sized
+/// array, without a `#[repr(C)]` attribute.
+///
+/// **Why is this bad?** A trailing zero-sized array is usually meant to
+/// emulate a C flexible array member, to access data stored right after the
+/// struct. Without `#[repr(C)]`, the compiler may reorder the fields, so the
+/// array is not guaranteed to be at the end of the struct.
+///
+/// **Known problems:** Zero-sized arrays are sometimes used for other
+/// purposes, like forcing the alignment of a struct.
+///
+/// **Example:**
+/// ```rust
+/// struct RarelyUseful {
+///     some_field: u32,
+///     last: [u32; 0],
+/// }
+/// ```
+/// Use instead:
+/// ```rust
+/// #[repr(C)]
+/// struct MoreOftenUseful {
+///     some_field: u32,
+///     last: [u32; 0],
+/// }
+/// ```
+declare_clippy_lint! {
+    pub TRAILING_EMPTY_ARRAY,
+    nursery,
+    "struct with a trailing zero-sized array but without `#[repr(C)]`"
+}
+
+#[derive(Copy, Clone)]
+pub struct TrailingEmptyArray;
+
+impl LintPass for TrailingEmptyArray {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(TRAILING_EMPTY_ARRAY)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for TrailingEmptyArray {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if in_macro(item.span) {
+            return;
+        }
+        if_chain! {
+            if let ItemStruct(ref data, _) = item.node;
+            if let Some(last_field) = data.fields().last();
+            let def_id = cx.tcx.hir.local_def_id(item.id);
+            if !cx.tcx.adt_def(def_id).repr.c();
+            if let ty::TyArray(_, len) = cx.tcx.type_of(cx.tcx.hir.local_def_id(last_field.id)).sty;
+            // the length is unknown if it depends on a generic parameter
+            if len.assert_usize(cx.tcx) == Some(0);
+            then {
+                span_help_and_lint(
+                    cx,
+                    TRAILING_EMPTY_ARRAY,
+                    cx.tcx.def_span(def_id),
+                    "trailing zero-sized array in a struct which is not marked `#[repr(C)]`",
+                    &format!("consider annotating `{}` with `#[repr(C)]`", item.name),
+                );
+            }
+        }
+    }
+}
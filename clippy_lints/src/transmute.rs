@@ -1,6 +1,7 @@
 use rustc::lint::*;
 use rustc::ty::{self, Ty};
 use rustc::hir::*;
+use rustc_target::abi::LayoutOf;
 use std::borrow::Cow;
 use syntax::ast;
 use crate::utils::{last_path_segment, match_def_path, paths, snippet, span_lint, span_lint_and_then};
@@ -195,6 +196,33 @@ declare_clippy_lint! {
     "transmutes from a pointer to a pointer / a reference to a reference"
 }
 
+/// **What it does:** Checks for transmutes between types which do not have a
+/// representation defined relative to each other.
+///
+/// **Why is this bad?** The results of such a transmute are not defined. The
+/// compiler is free to reorder the fields of a `repr(Rust)` struct, and to do
+/// so differently for each instantiation of a generic type.
+///
+/// **Known problems:** Types are only compared through references, not raw
+/// pointers. Types with a single non-zero-sized field are assumed to have the
+/// layout of that field.
+///
+/// **Example:**
+/// ```rust
+/// let _: Vec<u32> = unsafe { std::mem::transmute(Vec::<i32>::new()) };
+/// ```
+/// Use instead:
+/// ```rust
+/// // the buffer is owned by the new `Vec`, so `v` must not free it
+/// let mut v = std::mem::ManuallyDrop::new(Vec::<i32>::new());
+/// let _ = unsafe { Vec::from_raw_parts(v.as_mut_ptr() as *mut u32, v.len(), v.capacity()) };
+/// ```
+declare_clippy_lint! {
+    pub TRANSMUTE_UNDEFINED_REPR,
+    correctness,
+    "transmute to or from a type with an undefined representation"
+}
+
 pub struct Transmute;
 
 impl LintPass for Transmute {
@@ -209,6 +237,7 @@ impl LintPass for Transmute {
             TRANSMUTE_BYTES_TO_STR,
             TRANSMUTE_INT_TO_BOOL,
             TRANSMUTE_INT_TO_FLOAT,
+            TRANSMUTE_UNDEFINED_REPR,
         )
     }
 }
@@ -222,6 +251,8 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Transmute {
                         let from_ty = cx.tables.expr_ty(&args[0]);
                         let to_ty = cx.tables.expr_ty(e);
 
+                        check_undefined_repr(cx, e, from_ty, to_ty);
+
                         match (&from_ty.sty, &to_ty.sty) {
                             _ if from_ty == to_ty => span_lint(
                                 cx,
@@ -447,6 +478,100 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Transmute {
     }
 }
 
+/// The layout of a type, as far as transmutes are concerned.
+enum ReducedTy<'tcx> {
+    /// A struct or tuple with several non-zero-sized fields, which the
+    /// compiler is free to reorder.
+    UnorderedFields(Ty<'tcx>),
+    /// Any other type.
+    Other,
+}
+
+/// Lints transmutes involving a type whose fields may be reordered, unless
+/// both sides are the same type.
+fn check_undefined_repr<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, e: &Expr, from_ty: Ty<'tcx>, to_ty: Ty<'tcx>) {
+    let mut from_ty = cx.tcx.erase_regions(&from_ty);
+    let mut to_ty = cx.tcx.erase_regions(&to_ty);
+    // transmuting references reinterprets the referenced values
+    while let (&ty::TyRef(_, from_sub_ty, _), &ty::TyRef(_, to_sub_ty, _)) = (&from_ty.sty, &to_ty.sty) {
+        from_ty = from_sub_ty;
+        to_ty = to_sub_ty;
+    }
+    if from_ty == to_ty {
+        return;
+    }
+    match (reduce_ty(cx, from_ty), reduce_ty(cx, to_ty)) {
+        (ReducedTy::UnorderedFields(from_sub_ty), ReducedTy::UnorderedFields(to_sub_ty)) => {
+            if from_sub_ty == to_sub_ty {
+                return;
+            }
+            span_lint_and_then(
+                cx,
+                TRANSMUTE_UNDEFINED_REPR,
+                e.span,
+                &format!("transmute from `{}` to `{}`, both of which have an undefined layout", from_ty, to_ty),
+                |db| {
+                    if let (&ty::TyAdt(from_def, _), &ty::TyAdt(to_def, _)) = (&from_sub_ty.sty, &to_sub_ty.sty) {
+                        if from_def.did == to_def.did {
+                            db.note(&format!(
+                                "two instances of the same generic type (`{}`) may have different layouts",
+                                cx.tcx.item_path_str(from_def.did)
+                            ));
+                        }
+                    }
+                },
+            );
+        },
+        (ReducedTy::UnorderedFields(_), ReducedTy::Other) => {
+            span_lint(
+                cx,
+                TRANSMUTE_UNDEFINED_REPR,
+                e.span,
+                &format!("transmute from `{}` which has an undefined layout", from_ty),
+            );
+        },
+        (ReducedTy::Other, ReducedTy::UnorderedFields(_)) => {
+            span_lint(
+                cx,
+                TRANSMUTE_UNDEFINED_REPR,
+                e.span,
+                &format!("transmute into `{}` which has an undefined layout", to_ty),
+            );
+        },
+        _ => (),
+    }
+}
+
+/// Looks through arrays and through structs and tuples with a single
+/// non-zero-sized field to find the type whose layout matters.
+fn reduce_ty<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, mut ty: Ty<'tcx>) -> ReducedTy<'tcx> {
+    loop {
+        let fields: Vec<Ty<'tcx>> = match ty.sty {
+            ty::TyArray(sub_ty, _) => {
+                ty = sub_ty;
+                continue;
+            },
+            ty::TyTuple(tys) => tys.to_vec(),
+            ty::TyAdt(def, substs) if def.is_struct() => {
+                if def.repr.c() || def.repr.transparent() {
+                    return ReducedTy::Other;
+                }
+                def.non_enum_variant().fields.iter().map(|f| f.ty(cx.tcx, substs)).collect()
+            },
+            _ => return ReducedTy::Other,
+        };
+        // the size of generic fields is unknown, they are assumed not to be zero-sized
+        let mut sized_fields = fields
+            .into_iter()
+            .filter(|&field_ty| cx.layout_of(field_ty).map_or(true, |layout| layout.size.bytes() != 0));
+        match (sized_fields.next(), sized_fields.next()) {
+            (Some(field_ty), None) => ty = field_ty,
+            (Some(_), Some(_)) => return ReducedTy::UnorderedFields(ty),
+            (None, _) => return ReducedTy::Other,
+        }
+    }
+}
+
 /// Get the snippet of `Bar` in `…::transmute<Foo, &Bar>`. If that snippet is
 /// not available , use
 /// the type's `ToString` implementation. In weird cases it could lead to types
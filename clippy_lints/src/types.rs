@@ -1,11 +1,14 @@
 use crate::reexport::*;
 use rustc::hir;
 use rustc::hir::*;
+use rustc::hir::def_id::DefId;
+use rustc::hir::def::Def;
 use rustc::hir::intravisit::{walk_body, walk_expr, walk_ty, FnKind, NestedVisitorMap, Visitor};
 use rustc::lint::*;
 use rustc::ty::{self, Ty, TyCtxt, TypeckTables};
 use rustc::ty::layout::LayoutOf;
 use rustc_typeck::hir_ty_to_ty;
+use semver::Version;
 use std::cmp::Ordering;
 use std::collections::BTreeMap;
 use std::borrow::Cow;
@@ -13,7 +16,7 @@ use syntax::ast::{FloatTy, IntTy, UintTy};
 use syntax::codemap::Span;
 use syntax::errors::DiagnosticBuilder;
 use crate::utils::{comparisons, differing_macro_contexts, higher, in_constant, in_external_macro, in_macro, last_path_segment, match_def_path, match_path,
-            match_type, multispan_sugg, opt_def_id, same_tys, snippet, snippet_opt, span_help_and_lint, span_lint,
+            match_type, meets_msrv, multispan_sugg, opt_def_id, path_to_def, same_tys, snippet, snippet_opt, span_help_and_lint, span_lint,
             span_lint_and_sugg, span_lint_and_then, clip, unsext, sext, int_bits};
 use crate::utils::paths;
 use crate::consts::{constant, Constant};
@@ -22,11 +25,12 @@ use crate::consts::{constant, Constant};
 #[allow(missing_copy_implementations)]
 pub struct TypePass;
 
-/// **What it does:** Checks for use of `Box<Vec<_>>` anywhere in the code.
+/// **What it does:** Checks for use of `Box<T>` where T is a collection such
+/// as `Vec`, `String` or `HashMap` anywhere in the code.
 ///
-/// **Why is this bad?** `Vec` already keeps its contents in a separate area on
-/// the heap. So if you `Box` it, you just add another level of indirection
-/// without any benefit whatsoever.
+/// **Why is this bad?** Collections already keep their contents in a separate
+/// area on the heap. So if you `Box` them, you just add another level of
+/// indirection without any benefit whatsoever.
 ///
 /// **Known problems:** None.
 ///
@@ -45,9 +49,9 @@ pub struct TypePass;
 /// }
 /// ```
 declare_clippy_lint! {
-    pub BOX_VEC,
+    pub BOX_COLLECTION,
     perf,
-    "usage of `Box<Vec<T>>`, vector elements are already on the heap"
+    "usage of `Box<Vec<T>>`, `Box<String>` and other boxed collections, their contents are already on the heap"
 }
 
 /// **What it does:** Checks for use of `Option<Option<_>>` in function signatures and type
@@ -129,9 +133,110 @@ declare_clippy_lint! {
     "a borrow of a boxed type"
 }
 
+/// **What it does:** Checks for `Rc<T>` and `Arc<T>` when `T` is a mutable
+/// buffer type such as `String` or `Vec`.
+///
+/// **Why is this bad?** Expressions such as `Rc<String>` usually have no
+/// advantage over `Rc<str>`, since it is larger and involves an extra level of
+/// indirection, and doesn't implement `Borrow<str>`.
+///
+/// While mutating a buffer type would still be possible with `Rc::get_mut()`,
+/// it only works if there are no additional references yet, which usually
+/// defeats the purpose of enclosing it in a shared ownership type. Instead,
+/// additionally wrapping the inner type with an interior mutable container
+/// (such as `RefCell` or `Mutex`) would normally be used.
+///
+/// **Known problems:** This pattern can be desirable to avoid the overhead of
+/// a `RefCell` or `Mutex` for cases where mutation only happens before there
+/// are any additional references.
+///
+/// **Example:**
+/// ```rust
+/// fn foo(interned_string: Rc<String>) { ... }
+/// ```
+///
+/// Better:
+///
+/// ```rust
+/// fn foo(interned_string: Rc<str>) { ... }
+/// ```
+declare_clippy_lint! {
+    pub RC_BUFFER,
+    restriction,
+    "shared ownership of a buffer type"
+}
+
+/// **What it does:** Checks for `Rc<Mutex<T>>`.
+///
+/// **Why is this bad?** `Rc` is used in single thread and `Mutex` is used in
+/// multi thread. Consider using `Rc<RefCell<T>>` in single thread or
+/// `Arc<Mutex<T>>` in multi thread.
+///
+/// **Known problems:** Sometimes combining generic types can lead to the
+/// requirement that a type use `Rc` in conjunction with `Mutex`. We must
+/// consider those cases false positives, but alas they are quite hard to rule
+/// out. Luckily they are also rare.
+///
+/// **Example:**
+/// ```rust
+/// use std::rc::Rc;
+/// use std::sync::Mutex;
+/// fn foo(interned_string: Rc<Mutex<i32>>) { ... }
+/// ```
+///
+/// Better:
+///
+/// ```rust
+/// use std::rc::Rc;
+/// use std::cell::RefCell;
+/// fn foo(interned_string: Rc<RefCell<i32>>) { ... }
+/// ```
+declare_clippy_lint! {
+    pub RC_MUTEX,
+    restriction,
+    "usage of `Rc<Mutex<T>>`"
+}
+
+/// **What it does:** Checks for use of redundant allocations anywhere in the
+/// code: a `Box`, `Rc` or `Arc` of a reference or of another `Box`, `Rc` or
+/// `Arc`.
+///
+/// **Why is this bad?** Expressions such as `Rc<&T>`, `Rc<Rc<T>>`,
+/// `Rc<Arc<T>>`, `Rc<Box<T>>`, `Arc<&T>`, `Arc<Rc<T>>`, `Arc<Arc<T>>`,
+/// `Arc<Box<T>>`, `Box<&T>`, `Box<Rc<T>>`, `Box<Arc<T>>` and `Box<Box<T>>`
+/// add an unnecessary level of indirection.
+///
+/// **Known problems:** Removing the inner `Rc` or `Arc` changes which values
+/// share a reference count. Pointers to unsized types like `Box<dyn Trait>`
+/// are not linted, since the outer pointer is thin and the inner one is not.
+///
+/// **Example:**
+/// ```rust
+/// fn foo(bar: Rc<&usize>) {}
+/// ```
+///
+/// Better:
+///
+/// ```rust
+/// fn foo(bar: &usize) {}
+/// ```
+declare_clippy_lint! {
+    pub REDUNDANT_ALLOCATION,
+    perf,
+    "redundant allocation"
+}
+
 impl LintPass for TypePass {
     fn get_lints(&self) -> LintArray {
-        lint_array!(BOX_VEC, OPTION_OPTION, LINKEDLIST, BORROWED_BOX)
+        lint_array!(
+            BOX_COLLECTION,
+            OPTION_OPTION,
+            LINKEDLIST,
+            BORROWED_BOX,
+            RC_BUFFER,
+            RC_MUTEX,
+            REDUNDANT_ALLOCATION
+        )
     }
 }
 
@@ -196,6 +301,161 @@ fn match_type_parameter(cx: &LateContext, qpath: &QPath, path: &[&str]) -> bool
     false
 }
 
+/// Collections which keep their contents on the heap, with the name to use in
+/// messages.
+const HEAP_COLLECTIONS: [(&[&str], &str); 9] = [
+    (&paths::VEC, "Vec<..>"),
+    (&paths::STRING, "String"),
+    (&paths::HASHMAP, "HashMap<..>"),
+    (&paths::HASHSET, "HashSet<..>"),
+    (&paths::BTREEMAP, "BTreeMap<..>"),
+    (&paths::BTREESET, "BTreeSet<..>"),
+    (&paths::VEC_DEQUE, "VecDeque<..>"),
+    (&paths::LINKED_LIST, "LinkedList<..>"),
+    (&paths::BINARY_HEAP, "BinaryHeap<..>"),
+];
+
+/// Get the name of the collection in `Box<_>`, if its type parameter is one.
+fn boxed_collection(cx: &LateContext, qpath: &QPath) -> Option<&'static str> {
+    HEAP_COLLECTIONS
+        .iter()
+        .find(|&&(path, _)| match_type_parameter(cx, qpath, path))
+        .map(|&(_, name)| name)
+}
+
+/// Get the first type parameter of the last segment of `qpath`, if any.
+fn qpath_type_param(qpath: &QPath) -> Option<&hir::Ty> {
+    let last = last_path_segment(qpath);
+    if_chain! {
+        if let Some(ref params) = last.args;
+        if !params.parenthesized;
+        then {
+            return params.args.iter().find_map(|arg| match arg {
+                GenericArg::Type(ty) => Some(ty),
+                GenericArg::Lifetime(_) => None,
+            });
+        }
+    }
+    None
+}
+
+/// Check for `Rc<T>` and `Arc<T>` of a buffer type `T`, returns true if
+/// something was linted.
+fn check_rc_buffer(cx: &LateContext, ast_ty: &hir::Ty, qpath: &QPath, def_id: DefId) -> bool {
+    let sugg = if match_type_parameter(cx, qpath, &paths::STRING) {
+        Cow::from("str")
+    } else if match_type_parameter(cx, qpath, &paths::OS_STRING) {
+        Cow::from("std::ffi::OsStr")
+    } else if match_type_parameter(cx, qpath, &paths::PATH_BUF) {
+        Cow::from("std::path::Path")
+    } else if match_type_parameter(cx, qpath, &paths::VEC) {
+        let vec_ty = match qpath_type_param(qpath) {
+            Some(&hir::Ty { node: TyPath(ref vec_qpath), .. }) => vec_qpath,
+            _ => return false,
+        };
+        match qpath_type_param(vec_ty).and_then(|elem_ty| snippet_opt(cx, elem_ty.span)) {
+            Some(elem_ty) => Cow::from(format!("[{}]", elem_ty)),
+            None => return false,
+        }
+    } else {
+        return false;
+    };
+    let name = if match_def_path(cx.tcx, def_id, &paths::RC) { "Rc" } else { "Arc" };
+    span_lint_and_sugg(
+        cx,
+        RC_BUFFER,
+        ast_ty.span,
+        &format!("usage of `{}<T>` when T is a buffer type", name),
+        "try",
+        format!("{}<{}>", name, sugg),
+    );
+    true
+}
+
+/// Get the name of `Box`, `Rc` or `Arc` if `def_id` is one of them.
+fn pointer_name(cx: &LateContext, def_id: DefId) -> Option<&'static str> {
+    if Some(def_id) == cx.tcx.lang_items().owned_box() {
+        Some("Box")
+    } else if match_def_path(cx.tcx, def_id, &paths::RC) {
+        Some("Rc")
+    } else if match_def_path(cx.tcx, def_id, &paths::ARC) {
+        Some("Arc")
+    } else {
+        None
+    }
+}
+
+/// Check for a `Box`, `Rc` or `Arc` of a reference or of another one of them,
+/// returns true if something was linted.
+fn check_redundant_allocation(cx: &LateContext, ast_ty: &hir::Ty, qpath: &QPath, def_id: DefId) -> bool {
+    let outer = match pointer_name(cx, def_id) {
+        Some(outer) => outer,
+        None => return false,
+    };
+    let inner_ty = match qpath_type_param(qpath) {
+        Some(inner_ty) => inner_ty,
+        None => return false,
+    };
+    match inner_ty.node {
+        TyRptr(..) => {
+            let inner_snippet = snippet(cx, inner_ty.span, "..");
+            span_lint_and_then(
+                cx,
+                REDUNDANT_ALLOCATION,
+                ast_ty.span,
+                &format!("usage of `{}<{}>`", outer, inner_snippet),
+                |db| {
+                    db.span_suggestion(ast_ty.span, "try", inner_snippet.to_string());
+                    db.note(&format!(
+                        "`{}` is already a pointer, `{}<{}>` allocates a pointer on the heap",
+                        inner_snippet, outer, inner_snippet
+                    ));
+                },
+            );
+            true
+        },
+        TyPath(ref inner_qpath) => {
+            let inner_def = cx.tables.qpath_def(inner_qpath, cx.tcx.hir.node_to_hir_id(inner_ty.id));
+            let inner = match opt_def_id(inner_def).and_then(|inner_def_id| pointer_name(cx, inner_def_id)) {
+                Some(inner) => inner,
+                None => return false,
+            };
+            let pointee = match qpath_type_param(inner_qpath) {
+                Some(pointee) => pointee,
+                None => return false,
+            };
+            // the inner pointer is fat for unsized types, which the outer one can't hold directly
+            let is_unsized = match pointee.node {
+                TyTraitObject(..) | TySlice(..) => true,
+                TyPath(QPath::Resolved(None, ref path)) => path.def == Def::PrimTy(TyStr),
+                _ => false,
+            };
+            if is_unsized {
+                return false;
+            }
+            span_lint_and_then(
+                cx,
+                REDUNDANT_ALLOCATION,
+                ast_ty.span,
+                &format!("usage of `{}<{}<T>>`", outer, inner),
+                |db| {
+                    db.span_suggestion(
+                        ast_ty.span,
+                        "try",
+                        format!("{}<{}>", outer, snippet(cx, pointee.span, "..")),
+                    );
+                    db.note(&format!(
+                        "`{}<T>` is already on the heap, `{}<{}<T>>` makes an extra allocation",
+                        inner, outer, inner
+                    ));
+                },
+            );
+            true
+        },
+        _ => false,
+    }
+}
+
 /// Recursively check for `TypePass` lints in the given type. Stop at the first
 /// lint found.
 ///
@@ -210,14 +470,23 @@ fn check_ty(cx: &LateContext, ast_ty: &hir::Ty, is_local: bool) {
             let hir_id = cx.tcx.hir.node_to_hir_id(ast_ty.id);
             let def = cx.tables.qpath_def(qpath, hir_id);
             if let Some(def_id) = opt_def_id(def) {
+                if check_redundant_allocation(cx, ast_ty, qpath, def_id) {
+                    return; // don't recurse into the type
+                }
                 if Some(def_id) == cx.tcx.lang_items().owned_box() {
-                    if match_type_parameter(cx, qpath, &paths::VEC) {
+                    if let Some(collection) = boxed_collection(cx, qpath) {
                         span_help_and_lint(
                             cx,
-                            BOX_VEC,
+                            BOX_COLLECTION,
                             ast_ty.span,
-                            "you seem to be trying to use `Box<Vec<T>>`. Consider using just `Vec<T>`",
-                            "`Vec<T>` is already on the heap, `Box<Vec<T>>` makes an extra allocation.",
+                            &format!(
+                                "you seem to be trying to use `Box<{0}>`. Consider using just `{0}`",
+                                collection
+                            ),
+                            &format!(
+                                "`{0}` is already on the heap, `Box<{0}>` makes an extra allocation.",
+                                collection
+                            ),
                         );
                         return; // don't recurse into the type
                     }
@@ -232,6 +501,20 @@ fn check_ty(cx: &LateContext, ast_ty: &hir::Ty, is_local: bool) {
                         );
                         return; // don't recurse into the type
                     }
+                } else if match_def_path(cx.tcx, def_id, &paths::RC) || match_def_path(cx.tcx, def_id, &paths::ARC) {
+                    if check_rc_buffer(cx, ast_ty, qpath, def_id) {
+                        return; // don't recurse into the type
+                    }
+                    if match_def_path(cx.tcx, def_id, &paths::RC) && match_type_parameter(cx, qpath, &paths::MUTEX) {
+                        span_help_and_lint(
+                            cx,
+                            RC_MUTEX,
+                            ast_ty.span,
+                            "usage of `Rc<Mutex<_>>`",
+                            "consider using `Rc<RefCell<_>>` or `Arc<Mutex<_>>` instead",
+                        );
+                        return; // don't recurse into the type
+                    }
                 } else if match_def_path(cx.tcx, def_id, &paths::LINKED_LIST) {
                     span_help_and_lint(
                         cx,
@@ -560,7 +843,15 @@ fn is_unit_literal(expr: &Expr) -> bool {
     }
 }
 
-pub struct CastPass;
+pub struct CastPass {
+    msrv: Option<Version>,
+}
+
+impl CastPass {
+    pub fn new(msrv: Option<Version>) -> Self {
+        Self { msrv }
+    }
+}
 
 /// **What it does:** Checks for casts from any numerical to a float type where
 /// the receiving type cannot store all values from the original type without
@@ -730,6 +1021,34 @@ declare_clippy_lint! {
     "cast function pointer to the numeric type"
 }
 
+/// **What it does:** Checks for casts of a function pointer to any integer type.
+///
+/// **Why is this bad?** Casting a function pointer to an integer can have
+/// surprising results and can occur accidentally if parentheses are omitted
+/// from a function call. If you aren't doing anything low-level with function
+/// pointers then you can opt out of casting functions to integers in order to
+/// avoid mistakes. Alternatively, you can allow this lint on the places where
+/// the address is really needed.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// fn fn1() -> u16 { 1 };
+/// let _ = fn1 as usize;
+/// ```
+/// Use instead:
+/// ```rust
+/// // if you meant to call the function
+/// fn fn1() -> u16 { 1 };
+/// let _ = fn1() as usize;
+/// ```
+declare_clippy_lint! {
+    pub FN_TO_NUMERIC_CAST_ANY,
+    restriction,
+    "casting a function pointer to any integer type"
+}
+
 /// **What it does:** Checks for casts from a less-strictly-aligned pointer to a
 /// more-strictly-aligned pointer
 ///
@@ -749,6 +1068,34 @@ declare_clippy_lint! {
     "cast from a pointer to a more-strictly-aligned pointer"
 }
 
+/// **What it does:** Checks for casts of references to pointers and suggests
+/// `std::ptr::from_ref` and `std::ptr::from_mut` instead.
+///
+/// **Why is this bad?** Using `as` casts may result in silently changing
+/// mutability or type, while the functions only convert a reference into a
+/// pointer of the same type and mutability.
+///
+/// **Known problems:** The functions were added in Rust 1.76, set the `msrv`
+/// configuration if the crate supports older versions. With an older standard
+/// library the lint suggests coercing the reference to the pointer type
+/// instead.
+///
+/// **Example:**
+/// ```rust
+/// let a = 1;
+/// let a_ptr = &a as *const _;
+/// ```
+/// Use instead:
+/// ```rust
+/// let a = 1;
+/// let a_ptr = std::ptr::from_ref(&a);
+/// ```
+declare_clippy_lint! {
+    pub REF_AS_PTR,
+    pedantic,
+    "using `as` to cast a reference to pointer"
+}
+
 /// Returns the size in bits of an integral type.
 /// Will return 0 if the type is not an int or uint variant
 fn int_ty_to_nbits(typ: Ty, tcx: TyCtxt) -> u64 {
@@ -945,13 +1292,15 @@ impl LintPass for CastPass {
             CAST_PTR_ALIGNMENT,
             FN_TO_NUMERIC_CAST,
             FN_TO_NUMERIC_CAST_WITH_TRUNCATION,
+            FN_TO_NUMERIC_CAST_ANY,
+            REF_AS_PTR,
         )
     }
 }
 
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for CastPass {
     fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
-        if let ExprCast(ref ex, _) = expr.node {
+        if let ExprCast(ref ex, ref cast_to_hir) = expr.node {
             let (cast_from, cast_to) = (cx.tables.expr_ty(ex), cx.tables.expr_ty(expr));
             if let ExprLit(ref lit) = ex.node {
                 use syntax::ast::{LitIntType, LitKind};
@@ -1032,6 +1381,16 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for CastPass {
             match &cast_from.sty {
                 ty::TyFnDef(..) |
                 ty::TyFnPtr(..) => {
+                    if cast_to.is_numeric() {
+                        span_lint_and_sugg(
+                            cx,
+                            FN_TO_NUMERIC_CAST_ANY,
+                            expr.span,
+                            &format!("casting function pointer `{}` to `{}`", snippet(cx, ex.span, "x"), cast_to),
+                            "did you mean to invoke the function?",
+                            format!("{}() as {}", snippet(cx, ex.span, "x"), cast_to),
+                        );
+                    }
                     if cast_to.is_numeric() && cast_to.sty != ty::TyUint(UintTy::Usize){
                         let to_nbits = int_ty_to_nbits(cast_to, cx.tcx);
                         let pointer_nbits = cx.tcx.data_layout.pointer_size.bits();
@@ -1080,6 +1439,63 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for CastPass {
                     );
                 }
             }
+
+            if meets_msrv(self.msrv.as_ref(), &Version::new(1, 76, 0)) {
+                check_ref_as_ptr(cx, expr, ex, cast_to_hir, cast_from, cast_to);
+            }
+        }
+    }
+}
+
+fn check_ref_as_ptr<'a, 'tcx>(
+    cx: &LateContext<'a, 'tcx>,
+    expr: &Expr,
+    cast_expr: &Expr,
+    cast_to_hir: &hir::Ty,
+    cast_from: Ty<'tcx>,
+    cast_to: Ty<'tcx>,
+) {
+    if_chain! {
+        if let ty::TyRef(_, from_pointee, from_mutbl) = cast_from.sty;
+        if let ty::TyRawPtr(to_ptr) = cast_to.sty;
+        // `&[T; N] as *const T` also changes the type
+        if from_pointee == to_ptr.ty;
+        if !in_external_macro(cx, expr.span);
+        then {
+            let (fn_name, fn_path) = match (from_mutbl, to_ptr.mutbl) {
+                (_, MutImmutable) => ("from_ref", &paths::PTR_FROM_REF),
+                (MutMutable, MutMutable) => ("from_mut", &paths::PTR_FROM_MUT),
+                (MutImmutable, MutMutable) => return,
+            };
+            if path_to_def(cx, fn_path).is_none() {
+                span_help_and_lint(
+                    cx,
+                    REF_AS_PTR,
+                    expr.span,
+                    "reference as raw pointer",
+                    &format!(
+                        "consider coercing it instead: `let ptr: {} = {};`",
+                        snippet(cx, cast_to_hir.span, ".."),
+                        snippet(cx, cast_expr.span, ".."),
+                    ),
+                );
+                return;
+            }
+            let turbofish = match cast_to_hir.node {
+                TyPtr(MutTy { ty: ref pointee, .. }) => match pointee.node {
+                    TyInfer => String::new(),
+                    _ => format!("::<{}>", snippet(cx, pointee.span, "_")),
+                },
+                _ => String::new(),
+            };
+            span_lint_and_sugg(
+                cx,
+                REF_AS_PTR,
+                expr.span,
+                "reference as raw pointer",
+                "try",
+                format!("std::ptr::{}{}({})", fn_name, turbofish, snippet(cx, cast_expr.span, "..")),
+            );
         }
     }
 }
@@ -1749,44 +2165,62 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ImplicitHasher {
         fn suggestion<'a, 'tcx>(
             cx: &LateContext<'a, 'tcx>,
             db: &mut DiagnosticBuilder,
-            generics_span: Span,
+            generics: &Generics,
             generics_suggestion_span: Span,
             target: &ImplicitHasherType,
             vis: ImplicitHasherConstructorVisitor,
         ) {
-            let generics_snip = snippet(cx, generics_span, "");
+            let generics_snip = snippet(cx, generics.span, "");
             // trim `<` `>`
             let generics_snip = if generics_snip.is_empty() {
                 ""
             } else {
                 &generics_snip[1..generics_snip.len() - 1]
             };
-
-            multispan_sugg(
-                db,
-                "consider adding a type parameter".to_string(),
-                vec![
-                    (
-                        generics_suggestion_span,
-                        format!(
-                            "<{}{}S: ::std::hash::BuildHasher{}>",
-                            generics_snip,
-                            if generics_snip.is_empty() { "" } else { ", " },
-                            if vis.suggestions.is_empty() {
-                                ""
-                            } else {
-                                // request users to add `Default` bound so that generic constructors can be used
-                                " + Default"
-                            },
-                        ),
-                    ),
-                    (
-                        target.span(),
-                        format!("{}<{}, S>", target.type_name(), target.type_arguments(),),
-                    ),
-                ],
+            let separator = if generics_snip.is_empty() { "" } else { ", " };
+
+            // don't reuse the name of an existing type parameter
+            let hasher = (0..)
+                .map(|i| if i == 0 { "S".to_string() } else { format!("S{}", i) })
+                .find(|name| !generics.params.iter().any(|param| param.name.ident().as_str() == **name))
+                .expect("there is always an unused name");
+            let bound = format!(
+                "{}: ::std::hash::BuildHasher{}",
+                hasher,
+                if vis.suggestions.is_empty() {
+                    ""
+                } else {
+                    // request users to add `Default` bound so that generic constructors can be used
+                    " + Default"
+                },
             );
 
+            let mut suggestions = Vec::new();
+            // keep the bounds in the `where` clause if the item has one
+            if let Some(predicate) = generics.where_clause.predicates.last() {
+                let span = match *predicate {
+                    WherePredicate::BoundPredicate(ref p) => p.span,
+                    WherePredicate::RegionPredicate(ref p) => p.span,
+                    WherePredicate::EqPredicate(ref p) => p.span,
+                };
+                suggestions.push((
+                    generics_suggestion_span,
+                    format!("<{}{}{}>", generics_snip, separator, hasher),
+                ));
+                suggestions.push((Span::new(span.hi(), span.hi(), span.ctxt()), format!(", {}", bound)));
+            } else {
+                suggestions.push((
+                    generics_suggestion_span,
+                    format!("<{}{}{}>", generics_snip, separator, bound),
+                ));
+            }
+            suggestions.push((
+                target.span(),
+                format!("{}<{}, {}>", target.type_name(), target.type_arguments(), hasher),
+            ));
+
+            multispan_sugg(db, "consider adding a type parameter".to_string(), suggestions);
+
             if !vis.suggestions.is_empty() {
                 multispan_sugg(db, "...and use generic constructor".into(), vis.suggestions);
             }
@@ -1824,7 +2258,7 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ImplicitHasher {
                         target.span(),
                         &format!("impl for `{}` should be generalized over different hashers", target.type_name()),
                         move |db| {
-                            suggestion(cx, db, generics.span, generics_suggestion_span, target, ctr_vis);
+                            suggestion(cx, db, generics, generics_suggestion_span, target, ctr_vis);
                         },
                     );
                 }
@@ -1859,7 +2293,7 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ImplicitHasher {
                                 target.type_name()
                             ),
                             move |db| {
-                                suggestion(cx, db, generics.span, generics_suggestion_span, target, ctr_vis);
+                                suggestion(cx, db, generics, generics_suggestion_span, target, ctr_vis);
                             },
                         );
                     }
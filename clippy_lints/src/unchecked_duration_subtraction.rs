@@ -0,0 +1,69 @@
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc_errors::Applicability;
+use syntax::codemap::Spanned;
+use crate::utils::{in_macro, match_type, paths, span_lint_and_then, sugg};
+
+/// **What it does:** Checks for subtractions of a `Duration` from an
+/// `Instant` or from another `Duration` with the `-` operator.
+///
+/// **Why is this bad?** These subtractions panic if the result would
+/// underflow, e.g. when the `Duration` is longer than the other one, or
+/// points before the origin of the platform clock for an `Instant`.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// # use std::time::{Duration, Instant};
+/// let time_passed = Instant::now() - Duration::from_secs(5);
+/// ```
+/// Use instead:
+/// ```rust
+/// # use std::time::{Duration, Instant};
+/// let time_passed = Instant::now().checked_sub(Duration::from_secs(5));
+/// ```
+declare_clippy_lint! {
+    pub UNCHECKED_DURATION_SUBTRACTION,
+    correctness,
+    "unchecked subtraction of a `Duration` from an `Instant` or a `Duration`"
+}
+
+#[derive(Copy, Clone)]
+pub struct UncheckedDurationSubtraction;
+
+impl LintPass for UncheckedDurationSubtraction {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(UNCHECKED_DURATION_SUBTRACTION)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UncheckedDurationSubtraction {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_chain! {
+            if !in_macro(expr.span);
+            if let ExprBinary(Spanned { node: BiSub, .. }, ref lhs, ref rhs) = expr.node;
+            if match_type(cx, cx.tables.expr_ty(rhs), &paths::DURATION);
+            let lhs_ty = cx.tables.expr_ty(lhs);
+            then {
+                let msg = if match_type(cx, lhs_ty, &paths::INSTANT) {
+                    "unchecked subtraction of a `Duration` from an `Instant`"
+                } else if match_type(cx, lhs_ty, &paths::DURATION) {
+                    "unchecked subtraction between `Duration` values"
+                } else {
+                    return;
+                };
+                span_lint_and_then(cx, UNCHECKED_DURATION_SUBTRACTION, expr.span, msg, |db| {
+                    let lhs = sugg::Sugg::hir(cx, lhs, "..").maybe_par();
+                    let rhs = sugg::Sugg::hir(cx, rhs, "..");
+                    db.span_suggestion_with_applicability(
+                        expr.span,
+                        "consider handling the underflow with `checked_sub`",
+                        format!("{}.checked_sub({})", lhs, rhs),
+                        Applicability::MaybeIncorrect,
+                    );
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use rustc::hir::*;
+use rustc::hir::intravisit::FnKind;
+use rustc::lint::*;
+use rustc::ty;
+use syntax::ast::NodeId;
+use syntax_pos::Span;
+use crate::utils::{in_macro, span_lint};
+
+/// **What it does:** Checks for functions taking or returning a reference to
+/// an uninhabited type, like `&!` or a reference to an empty enum.
+///
+/// **Why is this bad?** A value of an uninhabited type can never exist, so
+/// dereferencing such a reference is undefined behavior. A function with such
+/// a signature can only be called or return by producing an invalid
+/// reference, which is almost certainly a mistake.
+///
+/// **Known problems:** `&self` arguments are not checked, since methods of an
+/// uninhabited type take them when implementing a trait.
+///
+/// **Example:**
+/// ```rust
+/// enum Void {}
+///
+/// fn foo(x: &Void) -> &Void {
+///     x
+/// }
+/// ```
+declare_clippy_lint! {
+    pub UNINHABITED_REFERENCES,
+    correctness,
+    "reference to uninhabited type in a function signature"
+}
+
+#[derive(Copy, Clone)]
+pub struct UninhabitedReferences;
+
+impl LintPass for UninhabitedReferences {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(UNINHABITED_REFERENCES)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UninhabitedReferences {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'a, 'tcx>,
+        kind: FnKind<'tcx>,
+        decl: &'tcx FnDecl,
+        _: &'tcx Body,
+        span: Span,
+        node_id: NodeId,
+    ) {
+        if in_macro(span) {
+            return;
+        }
+        if let FnKind::Closure(..) = kind {
+            return;
+        }
+
+        let fn_sig = cx.tcx.fn_sig(cx.tcx.hir.local_def_id(node_id));
+        let fn_sig = cx.tcx.erase_late_bound_regions(&fn_sig);
+
+        // methods of uninhabited types need `&self` to implement traits, the body is unreachable anyway
+        let skip = if decl.has_implicit_self { 1 } else { 0 };
+        for (input, &ty) in decl.inputs.iter().zip(fn_sig.inputs()).skip(skip) {
+            check_ty(cx, input.span, ty);
+        }
+        if let FunctionRetTy::Return(ref output) = decl.output {
+            check_ty(cx, output.span, fn_sig.output());
+        }
+    }
+}
+
+fn check_ty<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, span: Span, ty: ty::Ty<'tcx>) {
+    if let ty::TyRef(_, inner, _) = ty.sty {
+        if cx.tcx.is_ty_uninhabited_from_all_modules(inner) {
+            span_lint(
+                cx,
+                UNINHABITED_REFERENCES,
+                span,
+                "dereferencing a reference to an uninhabited type would be undefined behavior",
+            );
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use rustc::hir::*;
+use rustc::lint::*;
+use rustc_target::abi::LayoutOf;
+use syntax::ast::NodeId;
+use syntax_pos::{Span, DUMMY_SP};
+use crate::utils::{in_macro, return_ty, span_lint_and_then};
+
+/// **What it does:** Checks for a return type containing a `Box<T>` where `T`
+/// implements `Sized` and is small enough to be returned by value.
+///
+/// **Why is this bad?** It's better to just return `T` in these cases. The
+/// caller may not need the value to be boxed, and it's expensive to free the
+/// memory once the `Box<T>` is dropped. Callers that do need a box can still
+/// create one themselves.
+///
+/// **Known problems:** Changing the return type is a breaking change for
+/// public functions. Trait implementations are not checked, since they can't
+/// change their signature.
+///
+/// The configuration option `unnecessary_box_size` can be set to override the
+/// default limit of 128 bytes for a project.
+///
+/// **Example:**
+/// ```rust
+/// fn foo() -> Box<String> {
+///     Box::new(String::from("Hello, world!"))
+/// }
+/// ```
+/// Use instead:
+/// ```rust
+/// fn foo() -> String {
+///     String::from("Hello, world!")
+/// }
+/// ```
+declare_clippy_lint! {
+    pub UNNECESSARY_BOX_RETURNS,
+    pedantic,
+    "Needlessly returning a Box"
+}
+
+#[derive(Copy, Clone)]
+pub struct UnnecessaryBoxReturns {
+    maximum_size: u64,
+}
+
+impl UnnecessaryBoxReturns {
+    pub fn new(maximum_size: u64) -> Self {
+        Self { maximum_size }
+    }
+
+    fn check_fn_decl<'a, 'tcx>(self, cx: &LateContext<'a, 'tcx>, decl: &FnDecl, span: Span, node_id: NodeId) {
+        if in_macro(span) {
+            return;
+        }
+        let return_ty_hir = match decl.output {
+            FunctionRetTy::Return(ref ty) => ty,
+            FunctionRetTy::DefaultReturn(_) => return,
+        };
+
+        let return_ty = return_ty(cx, node_id);
+        if !return_ty.is_box() {
+            return;
+        }
+        let boxed_ty = return_ty.boxed_ty();
+
+        // `Box<dyn Trait>`, `Box<str>` and friends can't be returned by value
+        if !boxed_ty.is_sized(cx.tcx.at(DUMMY_SP), cx.param_env) {
+            return;
+        }
+        let size = match cx.layout_of(boxed_ty) {
+            Ok(layout) => layout.size.bytes(),
+            Err(_) => return,
+        };
+        if size > self.maximum_size {
+            return;
+        }
+
+        span_lint_and_then(
+            cx,
+            UNNECESSARY_BOX_RETURNS,
+            return_ty_hir.span,
+            &format!("boxed return of the sized type `{}`", boxed_ty),
+            |db| {
+                db.span_suggestion(return_ty_hir.span, "try", boxed_ty.to_string());
+                db.help("changing this also requires a change to the return expressions in this function");
+            },
+        );
+    }
+}
+
+impl LintPass for UnnecessaryBoxReturns {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(UNNECESSARY_BOX_RETURNS)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UnnecessaryBoxReturns {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if let ItemFn(ref decl, ..) = item.node {
+            self.check_fn_decl(cx, decl, item.span, item.id);
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'a, 'tcx>, impl_item: &'tcx ImplItem) {
+        // trait implementations can't change their signature, only the trait can be blamed
+        let parent = cx.tcx.hir.get_parent(impl_item.id);
+        if let ItemImpl(_, _, _, _, Some(_), _, _) = cx.tcx.hir.expect_item(parent).node {
+            return;
+        }
+        if let ImplItemKind::Method(ref sig, _) = impl_item.node {
+            self.check_fn_decl(cx, &sig.decl, impl_item.span, impl_item.id);
+        }
+    }
+
+    fn check_trait_item(&mut self, cx: &LateContext<'a, 'tcx>, trait_item: &'tcx TraitItem) {
+        if let TraitItemKind::Method(ref sig, _) = trait_item.node {
+            self.check_fn_decl(cx, &sig.decl, trait_item.span, trait_item.id);
+        }
+    }
+}
@@ -0,0 +1,225 @@
+use rustc::hir::*;
+use rustc::hir::def::Def;
+use rustc::hir::intravisit::{walk_expr, FnKind, NestedVisitorMap, Visitor};
+use rustc::lint::*;
+use rustc::ty;
+use syntax::ast::NodeId;
+use syntax_pos::Span;
+use crate::utils::{in_macro, match_def_path, match_type, opt_def_id, paths, resolve_node, return_ty, snippet,
+                   span_help_and_lint, span_lint_and_sugg, walk_ptrs_ty};
+
+/// **What it does:** Checks for `Cow::Owned` constructed from a string slice,
+/// like `Cow::Owned(String::from("foo"))` or `Cow::Owned(s.to_string())`, and
+/// for functions returning a `Cow` which is always `Cow::Owned`.
+///
+/// **Why is this bad?** The point of a `Cow` is to avoid allocating when
+/// borrowed data is enough. `Cow::Borrowed("foo")` doesn't allocate, and a
+/// function which always allocates can just return the owned type.
+///
+/// **Known problems:** Borrowing a string slice which is not `'static` can
+/// fail if its lifetime is too short. Changing the return type of a public
+/// function is a breaking change.
+///
+/// **Example:**
+/// ```rust
+/// fn name(anonymous: bool) -> Cow<'static, str> {
+///     if anonymous {
+///         Cow::Owned(String::from("anonymous"))
+///     } else {
+///         Cow::Owned(load_name())
+///     }
+/// }
+/// ```
+/// Use instead:
+/// ```rust
+/// fn name(anonymous: bool) -> Cow<'static, str> {
+///     if anonymous {
+///         Cow::Borrowed("anonymous")
+///     } else {
+///         Cow::Owned(load_name())
+///     }
+/// }
+/// ```
+declare_clippy_lint! {
+    pub UNNECESSARY_OWNED_COW,
+    pedantic,
+    "constructing `Cow::Owned` where `Cow::Borrowed` or the owned type would do"
+}
+
+#[derive(Copy, Clone)]
+pub struct UnnecessaryOwnedCow;
+
+impl LintPass for UnnecessaryOwnedCow {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(UNNECESSARY_OWNED_COW)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UnnecessaryOwnedCow {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_macro(expr.span) {
+            return;
+        }
+        if_chain! {
+            if let Some((callee, arg)) = cow_owned_arg(cx, expr);
+            if let Some(borrowed) = owned_str(cx, arg);
+            then {
+                span_lint_and_sugg(
+                    cx,
+                    UNNECESSARY_OWNED_COW,
+                    expr.span,
+                    "unnecessary allocation of a `String` in a `Cow`",
+                    "use a borrowed `Cow` instead",
+                    format!(
+                        "{}Borrowed({})",
+                        snippet(cx, callee.span, "Cow::Owned").trim_right_matches("Owned"),
+                        snippet(cx, borrowed.span, "..")
+                    ),
+                );
+            }
+        }
+    }
+
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'a, 'tcx>,
+        kind: FnKind<'tcx>,
+        decl: &'tcx FnDecl,
+        body: &'tcx Body,
+        span: Span,
+        node_id: NodeId,
+    ) {
+        if in_macro(span) {
+            return;
+        }
+        match kind {
+            FnKind::ItemFn(..) => (),
+            FnKind::Method(..) => {
+                // trait implementations can't change their signature
+                let parent = cx.tcx.hir.get_parent(node_id);
+                if let ItemImpl(_, _, _, _, Some(_), _, _) = cx.tcx.hir.expect_item(parent).node {
+                    return;
+                }
+            },
+            FnKind::Closure(..) => return,
+        }
+        let ret_ty_span = match decl.output {
+            FunctionRetTy::Return(ref ty) => ty.span,
+            FunctionRetTy::DefaultReturn(_) => return,
+        };
+        if !match_type(cx, return_ty(cx, node_id), &paths::COW) {
+            return;
+        }
+
+        let mut visitor = ReturnVisitor { returns: Vec::new() };
+        visitor.visit_expr(&body.value);
+        let mut leaves = Vec::new();
+        collect_leaves(&body.value, &mut leaves);
+        for ret in visitor.returns {
+            collect_leaves(ret, &mut leaves);
+        }
+
+        let mut owned_ty = None;
+        for leaf in leaves {
+            match cow_owned_arg(cx, leaf) {
+                Some((_, arg)) => owned_ty = Some(cx.tables.expr_ty(arg)),
+                None => return,
+            }
+        }
+        if let Some(owned_ty) = owned_ty {
+            span_help_and_lint(
+                cx,
+                UNNECESSARY_OWNED_COW,
+                ret_ty_span,
+                "this function only ever returns `Cow::Owned`",
+                &format!("consider returning `{}` instead", owned_ty),
+            );
+        }
+    }
+}
+
+/// Returns the callee and argument of a `Cow::Owned(arg)` expression.
+fn cow_owned_arg<'e>(cx: &LateContext, expr: &'e Expr) -> Option<(&'e Expr, &'e Expr)> {
+    if_chain! {
+        if let ExprCall(ref callee, ref args) = expr.node;
+        if args.len() == 1;
+        if let ExprPath(ref qpath) = callee.node;
+        if let Def::VariantCtor(def_id, ..) = cx.tables.qpath_def(qpath, callee.hir_id);
+        if match_def_path(cx.tcx, def_id, &paths::COW_OWNED);
+        then {
+            return Some((callee, &args[0]));
+        }
+    }
+    None
+}
+
+/// Returns the string slice in `String::from(s)`, `s.to_string()` or
+/// `s.to_owned()`.
+fn owned_str<'e>(cx: &LateContext, expr: &'e Expr) -> Option<&'e Expr> {
+    if !match_type(cx, cx.tables.expr_ty(expr), &paths::STRING) {
+        return None;
+    }
+    let source = match expr.node {
+        ExprCall(ref callee, ref args) if args.len() == 1 => {
+            if_chain! {
+                if let ExprPath(ref qpath) = callee.node;
+                if let Some(def_id) = opt_def_id(resolve_node(cx, qpath, callee.hir_id));
+                if match_def_path(cx.tcx, def_id, &paths::FROM_FROM);
+                then {
+                    &args[0]
+                } else {
+                    return None;
+                }
+            }
+        },
+        ExprMethodCall(ref path, _, ref args) if path.ident.name == "to_string" || path.ident.name == "to_owned" => {
+            &args[0]
+        },
+        _ => return None,
+    };
+    if walk_ptrs_ty(cx.tables.expr_ty(source)).sty == ty::TyStr {
+        Some(source)
+    } else {
+        None
+    }
+}
+
+/// Collects the expressions which may be the value of `expr`, looking through
+/// blocks, `if` and `match`.
+fn collect_leaves<'e>(expr: &'e Expr, leaves: &mut Vec<&'e Expr>) {
+    match expr.node {
+        ExprBlock(ref block, _) => {
+            if let Some(ref tail) = block.expr {
+                collect_leaves(tail, leaves);
+            }
+        },
+        ExprIf(_, ref then, Some(ref els)) => {
+            collect_leaves(then, leaves);
+            collect_leaves(els, leaves);
+        },
+        ExprMatch(_, ref arms, _) => for arm in arms {
+            collect_leaves(&arm.body, leaves);
+        },
+        // diverging expressions don't produce a value
+        ExprRet(_) | ExprBreak(..) | ExprContinue(_) => (),
+        _ => leaves.push(expr),
+    }
+}
+
+/// Collects the values of `return` expressions in a function body.
+struct ReturnVisitor<'tcx> {
+    returns: Vec<&'tcx Expr>,
+}
+
+impl<'tcx> Visitor<'tcx> for ReturnVisitor<'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if let ExprRet(Some(ref value)) = expr.node {
+            self.returns.push(value);
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
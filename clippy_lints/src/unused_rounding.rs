@@ -0,0 +1,71 @@
+use rustc::lint::*;
+use syntax::ast::*;
+use crate::utils::{in_macro, snippet, span_lint_and_sugg};
+
+/// **What it does:** Detects cases where a whole-number literal float is being
+/// rounded, using the `floor`, `ceil`, or `round` methods.
+///
+/// **Why is this bad?** This is unnecessary and confusing to the reader.
+/// Doing this is probably a mistake, like rounding the wrong operand.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// let x = 1f32.ceil();
+/// ```
+/// Use instead:
+/// ```rust
+/// let x = 1f32;
+/// ```
+declare_clippy_lint! {
+    pub UNUSED_ROUNDING,
+    nursery,
+    "uselessly rounding a whole number floating-point literal"
+}
+
+#[derive(Copy, Clone)]
+pub struct UnusedRounding;
+
+impl LintPass for UnusedRounding {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(UNUSED_ROUNDING)
+    }
+}
+
+impl EarlyLintPass for UnusedRounding {
+    fn check_expr(&mut self, cx: &EarlyContext, expr: &Expr) {
+        if_chain! {
+            if !in_macro(expr.span);
+            if let ExprKind::MethodCall(ref method, ref args) = expr.node;
+            if args.len() == 1;
+            let method_name = method.ident.as_str();
+            if method_name == "ceil" || method_name == "floor" || method_name == "round";
+            if let ExprKind::Lit(ref lit) = args[0].node;
+            if let LitKind::Float(sym, _) | LitKind::FloatUnsuffixed(sym) = lit.node;
+            if is_whole_number(&sym.as_str());
+            then {
+                span_lint_and_sugg(
+                    cx,
+                    UNUSED_ROUNDING,
+                    expr.span,
+                    &format!("used the `{}` method with a whole number float", method_name),
+                    &format!("remove the `{}` method call", method_name),
+                    snippet(cx, args[0].span, "..").into_owned(),
+                );
+            }
+        }
+    }
+}
+
+/// Checks if the digits of a float literal (without underscores or suffix)
+/// have no fractional part, like `1.0`, `1.` or `1`.
+fn is_whole_number(digits: &str) -> bool {
+    if digits.contains(|c| c == 'e' || c == 'E') {
+        return false;
+    }
+    match digits.find('.') {
+        Some(dot) => digits[dot + 1..].chars().all(|c| c == '0'),
+        None => true,
+    }
+}
@@ -0,0 +1,119 @@
+use rustc::hir::*;
+use rustc::hir::def::Def;
+use rustc::hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc::lint::*;
+use syntax::ast::NodeId;
+use crate::utils::{in_macro, span_help_and_lint};
+
+/// **What it does:** Checks methods of inherent impls that take `self` (by
+/// value or by reference) but never use it.
+///
+/// **Why is this bad?** The method doesn't need an instance to do its work, so
+/// it should be an associated function instead. Callers then don't have to
+/// construct or borrow a value just to call it.
+///
+/// **Known problems:** Removing `self` is a breaking change for public
+/// methods. Methods that have to keep their signature for API stability can
+/// opt out with `#[allow(unused_self)]`. Only `self`, `&self` and `&mut self`
+/// are checked, explicitly typed receivers like `self: Box<Self>` are not.
+///
+/// **Example:**
+/// ```rust
+/// struct A;
+/// impl A {
+///     fn method(&self) -> u32 {
+///         42
+///     }
+/// }
+/// ```
+///
+/// could be written as
+///
+/// ```rust
+/// struct A;
+/// impl A {
+///     fn method() -> u32 {
+///         42
+///     }
+/// }
+/// ```
+declare_clippy_lint! {
+    pub UNUSED_SELF,
+    pedantic,
+    "methods that contain a `self` argument but don't use it"
+}
+
+#[derive(Copy, Clone)]
+pub struct UnusedSelf;
+
+impl LintPass for UnusedSelf {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(UNUSED_SELF)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UnusedSelf {
+    fn check_impl_item(&mut self, cx: &LateContext<'a, 'tcx>, impl_item: &'tcx ImplItem) {
+        if in_macro(impl_item.span) {
+            return;
+        }
+        let parent = cx.tcx.hir.get_parent(impl_item.id);
+        let item = cx.tcx.hir.expect_item(parent);
+        // trait implementations can't change the signature of their methods
+        if let ItemImpl(_, _, _, _, None, _, _) = item.node {
+            if_chain! {
+                if let ImplItemKind::Method(ref sig, body_id) = impl_item.node;
+                if sig.decl.has_implicit_self;
+                let body = cx.tcx.hir.body(body_id);
+                if let Some(self_arg) = body.arguments.get(0);
+                if let PatKind::Binding(_, self_id, _, _) = self_arg.pat.node;
+                then {
+                    let mut visitor = SelfUsageVisitor {
+                        cx,
+                        self_id,
+                        used: false,
+                    };
+                    visitor.visit_expr(&body.value);
+                    if !visitor.used {
+                        span_help_and_lint(
+                            cx,
+                            UNUSED_SELF,
+                            impl_item.span,
+                            "unused `self` argument",
+                            "consider refactoring to an associated function",
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+struct SelfUsageVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    self_id: NodeId,
+    used: bool,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for SelfUsageVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if self.used {
+            return;
+        }
+        if let ExprPath(QPath::Resolved(None, ref path)) = expr.node {
+            match path.def {
+                Def::Local(id) | Def::Upvar(id, ..) if id == self.self_id => {
+                    self.used = true;
+                    return;
+                },
+                _ => (),
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    // closures capturing `self` count as a use
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::OnlyBodies(&self.cx.tcx.hir)
+    }
+}
@@ -132,6 +132,8 @@ define_Conf! {
     (blacklisted_names, "blacklisted_names", ["foo", "bar", "baz", "quux"] => Vec<String>),
     /// Lint: CYCLOMATIC_COMPLEXITY. The maximum cyclomatic complexity a function can have
     (cyclomatic_complexity_threshold, "cyclomatic_complexity_threshold", 25 => u64),
+    /// Lint: COGNITIVE_COMPLEXITY. The maximum cognitive complexity a function can have
+    (cognitive_complexity_threshold, "cognitive_complexity_threshold", 25 => u64),
     /// Lint: DOC_MARKDOWN. The list of words this lint should not consider as identifiers needing ticks
     (doc_valid_idents, "doc_valid_idents", [
         "KiB", "MiB", "GiB", "TiB", "PiB", "EiB",
@@ -151,6 +153,8 @@ define_Conf! {
     ] => Vec<String>),
     /// Lint: TOO_MANY_ARGUMENTS. The maximum number of argument a function or method can have
     (too_many_arguments_threshold, "too_many_arguments_threshold", 7 => u64),
+    /// Lint: TOO_MANY_LINES. The maximum number of lines a function or method can have
+    (too_many_lines_threshold, "too_many_lines_threshold", 100 => u64),
     /// Lint: TYPE_COMPLEXITY. The maximum complexity a type can have
     (type_complexity_threshold, "type_complexity_threshold", 250 => u64),
     /// Lint: MANY_SINGLE_CHAR_NAMES. The maximum number of single char bindings a scope may have
@@ -167,15 +171,51 @@ define_Conf! {
     (literal_representation_threshold, "literal_representation_threshold", 16384 => u64),
     /// Lint: TRIVIALLY_COPY_PASS_BY_REF. The maximum size (in bytes) to consider a `Copy` type for passing by value instead of by reference.
     (trivial_copy_size_limit, "trivial_copy_size_limit", None => Option<u64>),
+    /// Lint: LARGE_TYPES_PASSED_BY_VALUE. The minimum size (in bytes) to consider a type for passing by reference instead of by value.
+    (pass_by_value_size_limit, "pass_by_value_size_limit", 256 => u64),
+    /// Lint: STRUCT_EXCESSIVE_BOOLS. The maximum number of bools a struct can have
+    (max_struct_bools, "max_struct_bools", 3 => u64),
+    /// Lint: FN_PARAMS_EXCESSIVE_BOOLS. The maximum number of bools function parameters can have
+    (max_fn_params_bools, "max_fn_params_bools", 3 => u64),
+    /// Lint: UNNECESSARY_BOX_RETURNS. The byte size a `T` in `Box<T>` can have, below which it triggers the `unnecessary_box_returns` lint
+    (unnecessary_box_size, "unnecessary_box_size", 128 => u64),
+    /// Lint: LARGE_STACK_ARRAYS, LARGE_CONST_ARRAYS. The maximum allowed size for arrays on the stack
+    (array_size_threshold, "array_size_threshold", 512_000 => u64),
+    /// Lint: MUTABLE_KEY_TYPE. The paths of types which use interior mutability without affecting their hash or order
+    (ignore_interior_mutability, "ignore_interior_mutability", ["bytes::Bytes"] => Vec<String>),
+    /// Lint: LARGE_INCLUDE_FILE. The maximum size of a file included via `include_bytes!()` or `include_str!()`, in bytes
+    (max_include_file_size, "max_include_file_size", 1_000_000 => u64),
+    /// Lint: PUB_USE. The modules, by name or by path relative to the crate root, which may contain `pub use`
+    (allowed_pub_use_modules, "allowed_pub_use_modules", ["prelude"] => Vec<String>),
+    /// Lint: SEEK_FROM_CURRENT, SEEK_TO_START_INSTEAD_OF_REWIND, DURATION_SUBSEC, MANUAL_NON_EXHAUSTIVE, REF_AS_PTR, MISSING_CONST_FOR_FN. The minimum rust version that the project supports
+    (msrv, "msrv", None => Option<String>),
+    /// Lint: EXPENSIVE_CONSTRUCTION_IN_LOOPS. The paths of functions, in addition to the `regex` constructors, which are too expensive to be called in a loop
+    (expensive_constructors, "expensive_constructors", Vec::<&str>::new() => Vec<String>),
+    /// Lint: NEEDLESS_LIFETIMES, EXTRA_UNUSED_LIFETIMES, TRIVIALLY_COPY_PASS_BY_REF, LARGE_TYPES_PASSED_BY_VALUE. Whether to skip the lifetimes of exported items, which are part of the public API
+    (avoid_breaking_exported_api, "avoid_breaking_exported_api", false => bool),
+    /// Lint: ALLOW_ATTRIBUTES_WITHOUT_REASON. Whether the reason for an `allow` attribute has to be given in a `reason = ".."` field, instead of also accepting a comment
+    (require_allow_reason_field, "require_allow_reason_field", false => bool),
+    /// Lint: UNSEPARATED_LITERAL_SUFFIX, SEPARATED_LITERAL_SUFFIX. The style of literal suffixes to enforce, either `separated` (`123_i32`) or `unseparated` (`123i32`)
+    (literal_suffix_style, "literal_suffix_style", "separated".to_string() => String),
+    /// Whether to take the values which are not set in this file from the configuration file of an enclosing directory, like the one of the workspace
+    (inherit_parent_config, "inherit_parent_config", false => bool),
 }
 
+/// The key of `inherit_parent_config` in the configuration file.
+const INHERIT_KEY: &str = "inherit-parent-config";
+
 /// Search for the configuration file.
 pub fn lookup_conf_file() -> io::Result<Option<path::PathBuf>> {
+    lookup_conf_file_from(path::PathBuf::from(
+        env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR not set"),
+    ))
+}
+
+/// Search for the configuration file in `current` and its ancestors.
+fn lookup_conf_file_from(mut current: path::PathBuf) -> io::Result<Option<path::PathBuf>> {
     /// Possible filename to search for.
     const CONFIG_FILE_NAMES: [&str; 2] = [".clippy.toml", "clippy.toml"];
 
-    let mut current = path::PathBuf::from(env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR not set"));
-
     loop {
         for config_file_name in &CONFIG_FILE_NAMES {
             let config_file = current.join(config_file_name);
@@ -206,7 +246,7 @@ fn default(errors: Vec<Error>) -> (Conf, Vec<Error>) {
     (toml::from_str("").expect("we never error on empty config files"), errors)
 }
 
-/// Read the `toml` configuration file.
+/// Read the `toml` configuration file, and the ones it inherits from.
 ///
 /// In case of error, the function tries to continue as much as possible.
 pub fn read(path: Option<&path::Path>) -> (Conf, Vec<Error>) {
@@ -216,17 +256,10 @@ pub fn read(path: Option<&path::Path>) -> (Conf, Vec<Error>) {
         return default(Vec::new());
     };
 
-    let file = match fs::File::open(path) {
-        Ok(mut file) => {
-            let mut buf = String::new();
-
-            if let Err(err) = file.read_to_string(&mut buf) {
-                return default(vec![err.into()]);
-            }
-
-            buf
-        },
-        Err(err) => return default(vec![err.into()]),
+    let mut errors = Vec::new();
+    let table = match read_table(path, &mut errors) {
+        Some(table) => table,
+        None => return default(errors),
     };
 
     assert!(
@@ -235,21 +268,63 @@ pub fn read(path: Option<&path::Path>) -> (Conf, Vec<Error>) {
             .expect("no threading -> mutex always safe")
             .is_empty()
     );
-    match toml::from_str(&file) {
-        Ok(toml) => (
-            toml,
-            ERRORS
-                .lock()
-                .expect("no threading -> mutex always safe")
-                .split_off(0),
-        ),
+    match toml::Value::Table(table).try_into() {
+        Ok(toml) => {
+            errors.extend(
+                ERRORS
+                    .lock()
+                    .expect("no threading -> mutex always safe")
+                    .split_off(0),
+            );
+            (toml, errors)
+        },
         Err(e) => {
-            let mut errors = ERRORS
-                .lock()
-                .expect("no threading -> mutex always safe")
-                .split_off(0);
+            errors.extend(
+                ERRORS
+                    .lock()
+                    .expect("no threading -> mutex always safe")
+                    .split_off(0),
+            );
             errors.push(Error::Toml(e.to_string()));
             default(errors)
         },
     }
 }
+
+/// Read the `toml` configuration file as a table. If it sets
+/// `inherit-parent-config`, the keys it doesn't set are taken from the
+/// configuration file of the enclosing directories, recursively. Lists are
+/// not merged, they are overridden as a whole.
+fn read_table(path: &path::Path, errors: &mut Vec<Error>) -> Option<toml::value::Table> {
+    let mut file = String::new();
+    if let Err(err) = fs::File::open(path).and_then(|mut f| f.read_to_string(&mut file)) {
+        errors.push(err.into());
+        return None;
+    }
+
+    let mut table = match toml::from_str::<toml::value::Table>(&file) {
+        Ok(table) => table,
+        Err(e) => {
+            errors.push(Error::Toml(e.to_string()));
+            return None;
+        },
+    };
+
+    if table.get(INHERIT_KEY).and_then(toml::Value::as_bool) == Some(true) {
+        // the search starts in the directory which contains the directory of this file
+        let parent_dir = path.parent().and_then(path::Path::parent).map(path::Path::to_path_buf);
+        match parent_dir.map_or(Ok(None), lookup_conf_file_from) {
+            Ok(Some(parent)) => {
+                if let Some(parent) = read_table(&parent, errors) {
+                    for (key, value) in parent {
+                        table.entry(key).or_insert(value);
+                    }
+                }
+            },
+            Ok(None) => (),
+            Err(err) => errors.push(err.into()),
+        }
+    }
+
+    Some(table)
+}
@@ -10,6 +10,7 @@ use rustc::session::Session;
 use rustc::traits;
 use rustc::ty::{self, Binder, Ty, TyCtxt, layout::{self, IntegerExt}, subst::Kind};
 use rustc_errors::{Applicability, CodeSuggestion, Substitution, SubstitutionPart};
+use semver::Version;
 use std::borrow::Cow;
 use std::env;
 use std::mem;
@@ -21,6 +22,7 @@ use syntax::codemap::{CompilerDesugaringKind, ExpnFormat, ExpnInfo, Span, DUMMY_
 use syntax::errors::DiagnosticBuilder;
 use syntax::ptr::P;
 use syntax::symbol::keywords;
+use syntax::util::small_vector::SmallVector;
 
 pub mod comparisons;
 pub mod conf;
@@ -516,6 +518,39 @@ pub fn get_parent_expr<'c>(cx: &'c LateContext, e: &Expr) -> Option<&'c Expr> {
     })
 }
 
+/// Return the list of condition expressions and the list of blocks in a
+/// sequence of `if/else`.
+/// Eg. would return `([a, b], [c, d, e])` for the expression
+/// `if a { c } else if b { d } else { e }`.
+pub fn if_sequence(mut expr: &Expr) -> (SmallVector<&Expr>, SmallVector<&Block>) {
+    let mut conds = SmallVector::new();
+    let mut blocks: SmallVector<&Block> = SmallVector::new();
+
+    while let ExprIf(ref cond, ref then_expr, ref else_expr) = expr.node {
+        conds.push(&**cond);
+        if let ExprBlock(ref block, _) = then_expr.node {
+            blocks.push(block);
+        } else {
+            panic!("ExprIf node is not an ExprBlock");
+        }
+
+        if let Some(ref else_expr) = *else_expr {
+            expr = else_expr;
+        } else {
+            break;
+        }
+    }
+
+    // final `else {..}`
+    if !blocks.is_empty() {
+        if let ExprBlock(ref block, _) = expr.node {
+            blocks.push(&**block);
+        }
+    }
+
+    (conds, blocks)
+}
+
 pub fn get_enclosing_block<'a, 'tcx: 'a>(cx: &LateContext<'a, 'tcx>, node: NodeId) -> Option<&'tcx Block> {
     let map = &cx.tcx.hir;
     let enclosing_node = map.get_enclosing_scope(node)
@@ -607,6 +642,24 @@ pub fn span_lint_and_then<'a, 'tcx: 'a, T: LintContext<'tcx>, F>(
     db.docs_link(lint);
 }
 
+/// Like `span_lint_and_then`, but the lint level is looked up at `node`
+/// instead of the node being visited. Use this for lints emitted after the
+/// item has been visited, e.g. in `check_crate_post`.
+pub fn span_lint_node_and_then<'a, 'tcx: 'a, F>(
+    cx: &'a LateContext<'a, 'tcx>,
+    lint: &'static Lint,
+    node: NodeId,
+    sp: Span,
+    msg: &str,
+    f: F,
+) where
+    F: for<'b> FnOnce(&mut DiagnosticBuilder<'b>),
+{
+    let mut db = DiagnosticWrapper(cx.tcx.struct_span_lint_node(lint, node, sp, msg));
+    f(&mut db.0);
+    db.docs_link(lint);
+}
+
 /// Add a span lint with a suggestion on how to fix it.
 ///
 /// These suggestions can be parsed by rustfix to allow it to automatically fix your code.
@@ -895,6 +948,31 @@ pub fn is_copy<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, ty: Ty<'tcx>) -> bool {
     !ty.moves_by_default(cx.tcx.global_tcx(), cx.param_env, DUMMY_SP)
 }
 
+/// Return whether the given type is an ADT marked `#[must_use]`.
+pub fn is_must_use_ty<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, ty: Ty<'tcx>) -> bool {
+    match ty.sty {
+        ty::TyAdt(adt, _) => attr::contains_name(&cx.tcx.get_attrs(adt.did), "must_use"),
+        _ => false,
+    }
+}
+
+/// Return whether the given expression calls a function or method marked
+/// `#[must_use]`.
+pub fn is_must_use_func_call(cx: &LateContext, expr: &Expr) -> bool {
+    let did = match expr.node {
+        ExprCall(ref path, _) => {
+            if let ExprPath(ref qpath) = path.node {
+                opt_def_id(cx.tables.qpath_def(qpath, path.hir_id))
+            } else {
+                None
+            }
+        },
+        ExprMethodCall(..) => cx.tables.type_dependent_defs().get(expr.hir_id).map(|def| def.def_id()),
+        _ => None,
+    };
+    did.map_or(false, |did| attr::contains_name(&cx.tcx.get_attrs(did), "must_use"))
+}
+
 /// Return whether a pattern is refutable.
 pub fn is_refutable(cx: &LateContext, pat: &Pat) -> bool {
     fn is_enum_variant(cx: &LateContext, qpath: &QPath, id: HirId) -> bool {
@@ -1151,3 +1229,27 @@ pub fn any_parent_is_automatically_derived(tcx: TyCtxt, node: NodeId) -> bool {
     }
     false
 }
+
+/// Returns the types saved across suspension points by a generator, if `ty` is
+/// a generator type.
+pub fn generator_interior_types<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, ty: Ty<'tcx>) -> Option<Vec<Ty<'tcx>>> {
+    if let ty::TyGenerator(def_id, substs, _) = ty.sty {
+        if let ty::TyGeneratorWitness(interior) = substs.witness(def_id, cx.tcx).sty {
+            return Some(interior.skip_binder().to_vec());
+        }
+    }
+    None
+}
+
+/// Parses the `msrv` configuration, a Rust version like `1.51` or `1.51.0`.
+pub fn parse_msrv(msrv: &str) -> Option<Version> {
+    Version::parse(msrv)
+        .or_else(|_| Version::parse(&format!("{}.0", msrv)))
+        .ok()
+}
+
+/// Checks if the minimum supported Rust version of the crate, if configured,
+/// is at least `version`.
+pub fn meets_msrv(msrv: Option<&Version>, version: &Version) -> bool {
+    msrv.map_or(true, |msrv| msrv >= version)
+}
@@ -422,7 +422,7 @@ fn astbinop2assignop(op: ast::BinOp) -> AssocOp {
 
 /// Return the indentation before `span` if there are nothing but `[ \t]`
 /// before it on its line.
-fn indentation<'a, T: LintContext<'a>>(cx: &T, span: Span) -> Option<String> {
+pub fn indentation<'a, T: LintContext<'a>>(cx: &T, span: Span) -> Option<String> {
     let lo = cx.sess().codemap().lookup_char_pos(span.lo());
     if let Some(line) = lo.file
         .get_line(lo.line - 1 /* line numbers in `Loc` are 1-based */)
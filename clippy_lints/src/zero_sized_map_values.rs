@@ -0,0 +1,114 @@
+use rustc::hir::*;
+use rustc::hir::intravisit::FnKind;
+use rustc::lint::*;
+use rustc::ty::{self, Ty};
+use rustc_target::abi::LayoutOf;
+use syntax::ast::NodeId;
+use syntax_pos::Span;
+use crate::utils::{in_macro, match_type, paths, span_help_and_lint, walk_ptrs_ty};
+
+/// **What it does:** Checks for maps with zero-sized value types anywhere in
+/// the code.
+///
+/// **Why is this bad?** Since there is only a single value for a zero-sized
+/// type, a map containing zero sized values is effectively a set. Using a set
+/// in that case improves readability and communicates intent more clearly.
+///
+/// **Known problems:** A zero-sized type cannot be recovered later if it
+/// contains private fields. Maps of generic value types are not checked.
+///
+/// **Example:**
+/// ```rust
+/// fn unique_words(text: &str) -> HashMap<&str, ()> {
+///     ..
+/// }
+/// ```
+/// Use instead:
+/// ```rust
+/// fn unique_words(text: &str) -> HashSet<&str> {
+///     ..
+/// }
+/// ```
+declare_clippy_lint! {
+    pub ZERO_SIZED_MAP_VALUES,
+    pedantic,
+    "usage of map with zero-sized value type"
+}
+
+#[derive(Copy, Clone)]
+pub struct ZeroSizedMapValues;
+
+impl LintPass for ZeroSizedMapValues {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(ZERO_SIZED_MAP_VALUES)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ZeroSizedMapValues {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'a, 'tcx>,
+        kind: FnKind<'tcx>,
+        decl: &'tcx FnDecl,
+        _: &'tcx Body,
+        span: Span,
+        node_id: NodeId,
+    ) {
+        if in_macro(span) {
+            return;
+        }
+        match kind {
+            FnKind::ItemFn(..) => (),
+            FnKind::Method(..) => {
+                // trait implementations can't change their signature
+                let parent = cx.tcx.hir.get_parent(node_id);
+                if let ItemImpl(_, _, _, _, Some(_), _, _) = cx.tcx.hir.expect_item(parent).node {
+                    return;
+                }
+            },
+            FnKind::Closure(..) => return,
+        }
+        let fn_sig = cx.tcx.fn_sig(cx.tcx.hir.local_def_id(node_id));
+        let fn_sig = cx.tcx.erase_late_bound_regions(&fn_sig);
+        for (hir_ty, &ty) in decl.inputs.iter().zip(fn_sig.inputs()) {
+            check_ty(cx, hir_ty.span, ty);
+        }
+        if let FunctionRetTy::Return(ref hir_ty) = decl.output {
+            check_ty(cx, hir_ty.span, fn_sig.output());
+        }
+    }
+
+    fn check_struct_field(&mut self, cx: &LateContext<'a, 'tcx>, field: &'tcx StructField) {
+        if in_macro(field.span) {
+            return;
+        }
+        check_ty(cx, field.ty.span, cx.tcx.type_of(cx.tcx.hir.local_def_id(field.id)));
+    }
+
+    fn check_local(&mut self, cx: &LateContext<'a, 'tcx>, local: &'tcx Local) {
+        if in_macro(local.span) {
+            return;
+        }
+        check_ty(cx, local.span, cx.tables.pat_ty(&local.pat));
+    }
+}
+
+fn check_ty<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, span: Span, ty: Ty<'tcx>) {
+    let ty = walk_ptrs_ty(ty);
+    if_chain! {
+        if let ty::TyAdt(_, substs) = ty.sty;
+        if match_type(cx, ty, &paths::HASHMAP) || match_type(cx, ty, &paths::BTREEMAP);
+        // the layout is only known once the type doesn't depend on generic parameters
+        if let Ok(layout) = cx.layout_of(substs.type_at(1));
+        if layout.size.bytes() == 0;
+        then {
+            span_help_and_lint(
+                cx,
+                ZERO_SIZED_MAP_VALUES,
+                span,
+                "map with zero-sized value type",
+                "consider using a set instead",
+            );
+        }
+    }
+}
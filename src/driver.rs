@@ -5,7 +5,13 @@
 
 use rustc_driver::{self, driver::CompileController, Compilation};
 use rustc_plugin;
+use rustfix::{self, Filter, Suggestion};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
 use std::process::{exit, Command};
+use std::sync::{Arc, Mutex};
 
 #[allow(print_stdout)]
 fn show_version() {
@@ -81,6 +87,19 @@ pub fn main() {
         }
     }
 
+    if clippy_enabled && env::var("CLIPPY_FIX").ok().map_or(false, |val| val == "true") {
+        fix(&args);
+    }
+
+    if rustc_driver::run_compiler(&args, Box::new(clippy_controller(clippy_enabled)), None, None)
+        .0
+        .is_err()
+    {
+        exit(101);
+    }
+}
+
+fn clippy_controller(clippy_enabled: bool) -> CompileController<'static> {
     let mut controller = CompileController::basic();
     if clippy_enabled {
         controller.after_parse.callback = Box::new(move |state| {
@@ -124,11 +143,113 @@ pub fn main() {
         });
     }
     controller.compilation_done.stop = Compilation::Stop;
+    controller
+}
 
-    if rustc_driver::run_compiler(&args, Box::new(controller), None, None)
-        .0
-        .is_err()
-    {
-        exit(101);
+/// A destination for the diagnostics which can be read after the compiler is
+/// done.
+struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Runs the compiler with JSON diagnostics and applies the machine applicable
+/// suggestions to the source files, for `cargo clippy --fix`. The remaining
+/// diagnostics are reported by the normal run afterwards.
+fn fix(args: &[String]) {
+    let mut json_args = Vec::with_capacity(args.len() + 1);
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if arg == "--error-format" {
+            iter.next();
+        } else if !arg.starts_with("--error-format=") {
+            json_args.push(arg.clone());
+        }
+    }
+    json_args.push("--error-format=json".to_owned());
+
+    let output = Arc::new(Mutex::new(Vec::new()));
+    let dest = Box::new(SharedBuffer(Arc::clone(&output)));
+    let _ = rustc_driver::run_compiler(&json_args, Box::new(clippy_controller(true)), None, Some(dest));
+
+    let output = output.lock().unwrap();
+    let output = String::from_utf8_lossy(&output);
+    let suggestions =
+        match rustfix::get_suggestions_from_json(&output, &HashSet::<String>::new(), Filter::MachineApplicableOnly) {
+            Ok(suggestions) => suggestions,
+            Err(error) => {
+                eprintln!("error parsing the diagnostics for `--fix`: {}", error);
+                return;
+            },
+        };
+
+    let mut files = HashMap::new();
+    for suggestion in suggestions {
+        let file_name = {
+            let mut file_names = suggestion
+                .solutions
+                .iter()
+                .flat_map(|solution| &solution.replacements)
+                .map(|replacement| &replacement.snippet.file_name);
+            match file_names.next() {
+                Some(file_name) if file_names.all(|name| name == file_name) => file_name.clone(),
+                _ => continue,
+            }
+        };
+        // cargo passes absolute paths for the crates outside of the workspace
+        if Path::new(&file_name).is_relative() {
+            files.entry(file_name).or_insert_with(Vec::new).push(suggestion);
+        }
     }
+
+    for (file_name, suggestions) in files {
+        if let Err(error) = fix_file(&file_name, suggestions) {
+            eprintln!("error applying the suggestions to `{}`: {}", file_name, error);
+        }
+    }
+}
+
+fn fix_file(file_name: &str, mut suggestions: Vec<Suggestion>) -> io::Result<()> {
+    // overlapping suggestions can't be applied together, the next run will
+    // suggest the remaining ones again
+    suggestions.sort_by_key(suggestion_range);
+    let mut last = None;
+    suggestions.retain(|suggestion| {
+        let (lo, hi) = suggestion_range(suggestion);
+        let keep = last.map_or(true, |(last_lo, last_hi)| lo >= last_hi && (lo, hi) != (last_lo, last_hi));
+        if keep {
+            last = Some((lo, hi));
+        }
+        keep
+    });
+
+    let code = fs::read_to_string(file_name)?;
+    let fixed = rustfix::apply_suggestions(&code, &suggestions)
+        .map_err(|error| io::Error::new(io::ErrorKind::Other, error.to_string()))?;
+    if fixed != code {
+        fs::write(file_name, fixed)?;
+    }
+    Ok(())
+}
+
+/// The byte range of the source which is replaced by the suggestion.
+fn suggestion_range(suggestion: &Suggestion) -> (usize, usize) {
+    let ranges = || {
+        suggestion
+            .solutions
+            .iter()
+            .flat_map(|solution| &solution.replacements)
+            .map(|replacement| &replacement.snippet.range)
+    };
+    (
+        ranges().map(|range| range.start).min().unwrap_or(0),
+        ranges().map(|range| range.end).max().unwrap_or(0),
+    )
 }
@@ -11,9 +11,15 @@ Usage:
 Common options:
     -h, --help               Print this message
     -V, --version            Print version info and exit
+    --fix                    Automatically apply the lint suggestions
+    --message-format=sarif   Print the lints as a SARIF log, for CI integration
 
 Other options are the same as `cargo check`.
 
+With `--fix`, the suggestions which are known to be correct are applied to the
+source files of the checked crates. Crates which are up to date are not checked
+again, so you may need to touch them first.
+
 To allow or deny a lint from the command line you can use `cargo clippy --`
 with:
 
@@ -28,6 +34,8 @@ it to allow or deny lints from the code, eg.:
     #[cfg_attr(feature = "cargo-clippy", allow(needless_lifetimes))]
 "#;
 
+mod sarif;
+
 #[allow(print_stdout)]
 fn show_help() {
     println!("{}", CARGO_CLIPPY_HELP);
@@ -61,14 +69,21 @@ where
     let mut args = vec!["check".to_owned()];
 
     let mut found_dashes = false;
+    let mut fix = false;
     for arg in old_args.by_ref() {
         found_dashes |= arg == "--";
         if found_dashes {
             break;
         }
+        if arg == "--fix" {
+            fix = true;
+            continue;
+        }
         args.push(arg);
     }
 
+    let sarif = sarif::replace_message_format(&mut args);
+
     let clippy_args: String = old_args.map(|arg| format!("{}__CLIPPY_HACKERY__", arg)).collect();
 
     let mut path = std::env::current_exe()
@@ -96,15 +111,24 @@ where
         })
         .map(|p| ("CARGO_TARGET_DIR", p));
 
-    let exit_status = std::process::Command::new("cargo")
+    let mut child = std::process::Command::new("cargo")
         .args(&args)
         .env("RUSTC_WRAPPER", path)
         .env("CLIPPY_ARGS", clippy_args)
         .envs(target_dir)
+        .envs(if fix { Some(("CLIPPY_FIX", "true")) } else { None })
+        .stdout(if sarif {
+            std::process::Stdio::piped()
+        } else {
+            std::process::Stdio::inherit()
+        })
         .spawn()
-        .expect("could not run cargo")
-        .wait()
-        .expect("failed to wait for cargo?");
+        .expect("could not run cargo");
+
+    if let Some(stdout) = child.stdout.take() {
+        sarif::print_report(std::io::BufReader::new(stdout));
+    }
+    let exit_status = child.wait().expect("failed to wait for cargo?");
 
     if exit_status.success() {
         Ok(())
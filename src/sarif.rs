@@ -0,0 +1,539 @@
+//! Conversion of the JSON messages of cargo into a SARIF log, for
+//! `cargo clippy --message-format=sarif`.
+
+use serde_json::{json, Value};
+use std::io::BufRead;
+
+const DOCS_LINK: &str = "https://rust-lang-nursery.github.io/rust-clippy/master/index.html";
+
+/// The category of every lint, sorted by name.
+const LINT_CATEGORIES: &[(&str, &str)] = &[
+    // begin lint categories, see util/update_lints.py
+    ("absurd_extreme_comparisons", "correctness"),
+    ("allow_attributes_without_reason", "restriction"),
+    ("almost_swapped", "correctness"),
+    ("approx_constant", "correctness"),
+    ("assign_op_pattern", "style"),
+    ("assign_ops", "restriction"),
+    ("await_holding_lock", "correctness"),
+    ("bad_bit_mask", "correctness"),
+    ("blacklisted_name", "style"),
+    ("block_in_if_condition_expr", "style"),
+    ("block_in_if_condition_stmt", "style"),
+    ("bool_comparison", "complexity"),
+    ("borrow_interior_mutable_const", "correctness"),
+    ("borrowed_box", "complexity"),
+    ("box_collection", "perf"),
+    ("boxed_local", "perf"),
+    ("builtin_type_shadow", "style"),
+    ("cast_lossless", "complexity"),
+    ("cast_possible_truncation", "pedantic"),
+    ("cast_possible_wrap", "pedantic"),
+    ("cast_precision_loss", "pedantic"),
+    ("cast_ptr_alignment", "correctness"),
+    ("cast_sign_loss", "pedantic"),
+    ("char_lit_as_u8", "complexity"),
+    ("chars_last_cmp", "style"),
+    ("chars_next_cmp", "complexity"),
+    ("clone_double_ref", "correctness"),
+    ("clone_on_copy", "complexity"),
+    ("clone_on_ref_ptr", "restriction"),
+    ("cmp_nan", "correctness"),
+    ("cmp_null", "style"),
+    ("cmp_owned", "perf"),
+    ("cognitive_complexity", "nursery"),
+    ("collapsible_if", "style"),
+    ("comparison_chain", "style"),
+    ("const_static_lifetime", "style"),
+    ("crate_in_macro_def", "style"),
+    ("crosspointer_transmute", "complexity"),
+    ("cyclomatic_complexity", "complexity"),
+    ("decimal_literal_representation", "restriction"),
+    ("declare_interior_mutable_const", "correctness"),
+    ("default_trait_access", "pedantic"),
+    ("deprecated_semver", "correctness"),
+    ("deref_addrof", "complexity"),
+    ("derivable_impls", "complexity"),
+    ("derive_hash_xor_eq", "correctness"),
+    ("derive_partial_eq_without_eq", "pedantic"),
+    ("diverging_sub_expression", "complexity"),
+    ("doc_markdown", "pedantic"),
+    ("double_comparisons", "complexity"),
+    ("double_neg", "style"),
+    ("double_parens", "complexity"),
+    ("drop_copy", "correctness"),
+    ("drop_ref", "correctness"),
+    ("duplicate_underscore_argument", "style"),
+    ("duplicated_attributes", "style"),
+    ("duration_subsec", "complexity"),
+    ("else_if_without_else", "restriction"),
+    ("empty_enum", "pedantic"),
+    ("empty_line_after_doc_comments", "nursery"),
+    ("empty_line_after_outer_attr", "nursery"),
+    ("empty_loop", "style"),
+    ("enum_clike_unportable_variant", "correctness"),
+    ("enum_glob_use", "pedantic"),
+    ("enum_variant_names", "style"),
+    ("eq_op", "correctness"),
+    ("erasing_op", "correctness"),
+    ("eval_order_dependence", "complexity"),
+    ("excessive_precision", "style"),
+    ("exhaustive_enums", "restriction"),
+    ("exhaustive_structs", "restriction"),
+    ("expect_fun_call", "perf"),
+    ("expensive_construction_in_loops", "perf"),
+    ("expl_impl_clone_on_copy", "pedantic"),
+    ("explicit_counter_loop", "complexity"),
+    ("explicit_into_iter_loop", "style"),
+    ("explicit_iter_loop", "style"),
+    ("explicit_write", "complexity"),
+    ("extend_with_drain", "perf"),
+    ("extra_unused_lifetimes", "complexity"),
+    ("fallible_impl_from", "nursery"),
+    ("filter_map", "pedantic"),
+    ("filter_next", "complexity"),
+    ("float_arithmetic", "restriction"),
+    ("float_cmp", "correctness"),
+    ("float_cmp_const", "restriction"),
+    ("fn_params_excessive_bools", "pedantic"),
+    ("fn_to_numeric_cast", "style"),
+    ("fn_to_numeric_cast_any", "restriction"),
+    ("fn_to_numeric_cast_with_truncation", "correctness"),
+    ("for_kv_map", "style"),
+    ("for_loop_over_option", "correctness"),
+    ("for_loop_over_result", "correctness"),
+    ("forget_copy", "correctness"),
+    ("forget_ref", "correctness"),
+    ("from_over_into", "style"),
+    ("get_unwrap", "style"),
+    ("identity_conversion", "complexity"),
+    ("identity_op", "complexity"),
+    ("if_let_redundant_pattern_matching", "style"),
+    ("if_let_some_result", "style"),
+    ("if_not_else", "pedantic"),
+    ("if_same_then_else", "correctness"),
+    ("ifs_same_cond", "correctness"),
+    ("impl_trait_in_params", "restriction"),
+    ("implicit_hasher", "style"),
+    ("implicit_return", "restriction"),
+    ("inconsistent_digit_grouping", "style"),
+    ("indexing_slicing", "pedantic"),
+    ("ineffective_bit_mask", "correctness"),
+    ("infallible_destructuring_match", "style"),
+    ("infinite_iter", "correctness"),
+    ("init_numbered_fields", "style"),
+    ("inline_always", "pedantic"),
+    ("inline_fn_without_body", "correctness"),
+    ("int_plus_one", "complexity"),
+    ("integer_arithmetic", "restriction"),
+    ("into_iter_without_iter", "pedantic"),
+    ("invalid_ref", "correctness"),
+    ("invalid_regex", "correctness"),
+    ("invalid_upcast_comparisons", "pedantic"),
+    ("items_after_statements", "pedantic"),
+    ("iter_cloned_collect", "style"),
+    ("iter_next_loop", "correctness"),
+    ("iter_nth", "perf"),
+    ("iter_skip_next", "style"),
+    ("iter_without_into_iter", "pedantic"),
+    ("iterator_step_by_zero", "correctness"),
+    ("join_absolute_paths", "nursery"),
+    ("just_underscores_and_digits", "style"),
+    ("large_const_arrays", "perf"),
+    ("large_digit_groups", "style"),
+    ("large_enum_variant", "perf"),
+    ("large_include_file", "restriction"),
+    ("large_stack_arrays", "pedantic"),
+    ("large_types_passed_by_value", "pedantic"),
+    ("len_without_is_empty", "style"),
+    ("len_zero", "style"),
+    ("let_and_return", "style"),
+    ("let_underscore_must_use", "restriction"),
+    ("let_underscore_untyped", "restriction"),
+    ("let_unit_value", "style"),
+    ("linkedlist", "pedantic"),
+    ("logic_bug", "correctness"),
+    ("manual_assert", "pedantic"),
+    ("manual_memcpy", "perf"),
+    ("manual_non_exhaustive", "style"),
+    ("manual_retain", "perf"),
+    ("manual_swap", "complexity"),
+    ("many_single_char_names", "style"),
+    ("map_clone", "style"),
+    ("map_entry", "perf"),
+    ("match_as_ref", "complexity"),
+    ("match_bool", "style"),
+    ("match_overlapping_arm", "style"),
+    ("match_ref_pats", "style"),
+    ("match_same_arms", "pedantic"),
+    ("match_wild_err_arm", "style"),
+    ("maybe_infinite_iter", "pedantic"),
+    ("maybe_misused_cfg", "correctness"),
+    ("mem_forget", "restriction"),
+    ("min_max", "correctness"),
+    ("mismatching_type_param_order", "pedantic"),
+    ("misrefactored_assign_op", "complexity"),
+    ("missing_assert_message", "restriction"),
+    ("missing_const_for_fn", "nursery"),
+    ("missing_docs_in_private_items", "restriction"),
+    ("missing_errors_doc", "pedantic"),
+    ("missing_inline_in_public_items", "restriction"),
+    ("missing_panics_doc", "pedantic"),
+    ("missing_trait_methods", "restriction"),
+    ("mixed_case_hex_literals", "style"),
+    ("module_inception", "style"),
+    ("modulo_one", "correctness"),
+    ("multiple_crate_versions", "cargo"),
+    ("multiple_inherent_impl", "restriction"),
+    ("mut_from_ref", "correctness"),
+    ("mut_mut", "pedantic"),
+    ("mut_range_bound", "complexity"),
+    ("mutable_key_type", "correctness"),
+    ("mutex_atomic", "perf"),
+    ("mutex_integer", "nursery"),
+    ("naive_bytecount", "perf"),
+    ("needless_bool", "complexity"),
+    ("needless_borrow", "nursery"),
+    ("needless_borrowed_reference", "complexity"),
+    ("needless_collect", "perf"),
+    ("needless_continue", "pedantic"),
+    ("needless_for_each", "pedantic"),
+    ("needless_late_init", "style"),
+    ("needless_lifetimes", "complexity"),
+    ("needless_pass_by_value", "style"),
+    ("needless_range_loop", "style"),
+    ("needless_return", "style"),
+    ("needless_update", "complexity"),
+    ("neg_cmp_op_on_partial_ord", "complexity"),
+    ("neg_multiply", "style"),
+    ("never_loop", "correctness"),
+    ("new_ret_no_self", "style"),
+    ("new_without_default", "style"),
+    ("new_without_default_derive", "style"),
+    ("no_effect", "complexity"),
+    ("non_ascii_literal", "pedantic"),
+    ("non_canonical_clone_impl", "style"),
+    ("non_canonical_partial_ord_impl", "correctness"),
+    ("non_minimal_cfg", "style"),
+    ("non_octal_unix_permissions", "correctness"),
+    ("non_send_fields_in_send_ty", "correctness"),
+    ("nonminimal_bool", "complexity"),
+    ("nonsensical_open_options", "correctness"),
+    ("not_unsafe_ptr_arg_deref", "correctness"),
+    ("ok_expect", "style"),
+    ("op_ref", "style"),
+    ("option_map_or_none", "style"),
+    ("option_map_unit_fn", "complexity"),
+    ("option_map_unwrap_or", "pedantic"),
+    ("option_map_unwrap_or_else", "pedantic"),
+    ("option_option", "complexity"),
+    ("option_unwrap_used", "restriction"),
+    ("or_fun_call", "perf"),
+    ("out_of_bounds_indexing", "correctness"),
+    ("overflow_check_conditional", "complexity"),
+    ("panic", "restriction"),
+    ("panic_params", "style"),
+    ("panicking_unwrap", "nursery"),
+    ("partialeq_ne_impl", "complexity"),
+    ("path_buf_push_overwrite", "nursery"),
+    ("possible_missing_comma", "correctness"),
+    ("precedence", "complexity"),
+    ("print_literal", "style"),
+    ("print_stdout", "restriction"),
+    ("print_with_newline", "style"),
+    ("println_empty_string", "style"),
+    ("ptr_arg", "style"),
+    ("pub_enum_variant_names", "pedantic"),
+    ("pub_underscore_fields", "pedantic"),
+    ("pub_use", "restriction"),
+    ("question_mark", "style"),
+    ("range_minus_one", "style"),
+    ("range_plus_one", "nursery"),
+    ("range_zip_with_len", "complexity"),
+    ("rc_buffer", "restriction"),
+    ("rc_mutex", "restriction"),
+    ("read_zero_byte_vec", "correctness"),
+    ("recursive_format_impl", "correctness"),
+    ("redundant_allocation", "perf"),
+    ("redundant_clone", "nursery"),
+    ("redundant_closure", "style"),
+    ("redundant_closure_call", "complexity"),
+    ("redundant_else", "pedantic"),
+    ("redundant_field_names", "style"),
+    ("redundant_pattern", "style"),
+    ("redundant_pub_crate", "nursery"),
+    ("redundant_static_bounds", "complexity"),
+    ("redundant_type_annotations", "restriction"),
+    ("ref_as_ptr", "pedantic"),
+    ("ref_in_deref", "complexity"),
+    ("ref_option", "pedantic"),
+    ("regex_macro", "style"),
+    ("replace_consts", "pedantic"),
+    ("result_map_unit_fn", "complexity"),
+    ("result_map_unwrap_or_else", "pedantic"),
+    ("result_unwrap_used", "restriction"),
+    ("return_self_not_must_use", "pedantic"),
+    ("reverse_range_loop", "correctness"),
+    ("same_name_method", "restriction"),
+    ("search_is_some", "complexity"),
+    ("seek_from_current", "complexity"),
+    ("seek_to_start_instead_of_rewind", "complexity"),
+    ("self_assignment", "correctness"),
+    ("semicolon_if_nothing_returned", "pedantic"),
+    ("separated_literal_suffix", "restriction"),
+    ("serde_api_misuse", "correctness"),
+    ("shadow_reuse", "restriction"),
+    ("shadow_same", "restriction"),
+    ("shadow_unrelated", "restriction"),
+    ("short_circuit_statement", "complexity"),
+    ("should_implement_trait", "style"),
+    ("similar_names", "pedantic"),
+    ("single_char_pattern", "perf"),
+    ("single_match", "style"),
+    ("single_match_else", "pedantic"),
+    ("size_of_in_element_count", "correctness"),
+    ("string_add", "restriction"),
+    ("string_add_assign", "pedantic"),
+    ("string_extend_chars", "style"),
+    ("string_lit_as_bytes", "style"),
+    ("struct_excessive_bools", "pedantic"),
+    ("stutter", "pedantic"),
+    ("suspicious_arithmetic_impl", "correctness"),
+    ("suspicious_assignment_formatting", "style"),
+    ("suspicious_command_arg_space", "correctness"),
+    ("suspicious_else_formatting", "style"),
+    ("suspicious_op_assign_impl", "correctness"),
+    ("temporary_assignment", "complexity"),
+    ("temporary_cstring_as_ptr", "correctness"),
+    ("todo", "restriction"),
+    ("too_many_arguments", "complexity"),
+    ("too_many_lines", "pedantic"),
+    ("toplevel_ref_arg", "style"),
+    ("trailing_empty_array", "nursery"),
+    ("transmute_bytes_to_str", "complexity"),
+    ("transmute_int_to_bool", "complexity"),
+    ("transmute_int_to_char", "complexity"),
+    ("transmute_int_to_float", "complexity"),
+    ("transmute_ptr_to_ptr", "complexity"),
+    ("transmute_ptr_to_ref", "complexity"),
+    ("transmute_undefined_repr", "correctness"),
+    ("trivial_lifetime_bounds", "complexity"),
+    ("trivial_regex", "style"),
+    ("trivially_copy_pass_by_ref", "perf"),
+    ("type_complexity", "complexity"),
+    ("unchecked_duration_subtraction", "correctness"),
+    ("unicode_not_nfc", "pedantic"),
+    ("unifiable_lifetimes", "complexity"),
+    ("unimplemented", "restriction"),
+    ("uninhabited_references", "correctness"),
+    ("unit_arg", "complexity"),
+    ("unit_cmp", "correctness"),
+    ("unit_hash", "correctness"),
+    ("unnecessary_box_returns", "pedantic"),
+    ("unnecessary_cast", "complexity"),
+    ("unnecessary_fold", "style"),
+    ("unnecessary_mut_passed", "style"),
+    ("unnecessary_operation", "complexity"),
+    ("unnecessary_owned_cow", "pedantic"),
+    ("unnecessary_unwrap", "nursery"),
+    ("unneeded_field_pattern", "style"),
+    ("unreachable", "restriction"),
+    ("unreadable_literal", "style"),
+    ("unsafe_removed_from_name", "style"),
+    ("unseparated_literal_suffix", "restriction"),
+    ("unused_collect", "perf"),
+    ("unused_io_amount", "correctness"),
+    ("unused_label", "complexity"),
+    ("unused_rounding", "nursery"),
+    ("unused_self", "pedantic"),
+    ("use_debug", "restriction"),
+    ("use_self", "pedantic"),
+    ("used_underscore_binding", "pedantic"),
+    ("useless_asref", "complexity"),
+    ("useless_attribute", "correctness"),
+    ("useless_format", "complexity"),
+    ("useless_let_if_seq", "style"),
+    ("useless_transmute", "complexity"),
+    ("useless_vec", "perf"),
+    ("verbose_bit_mask", "style"),
+    ("while_immutable_condition", "correctness"),
+    ("while_let_loop", "complexity"),
+    ("while_let_on_iterator", "style"),
+    ("write_literal", "style"),
+    ("write_with_newline", "style"),
+    ("writeln_empty_string", "style"),
+    ("wrong_pub_self_convention", "restriction"),
+    ("wrong_self_convention", "style"),
+    ("wrong_transmute", "correctness"),
+    ("zero_divided_by_zero", "complexity"),
+    ("zero_prefixed_literal", "complexity"),
+    ("zero_ptr", "style"),
+    ("zero_sized_map_values", "pedantic"),
+    ("zero_width_space", "correctness"),
+    // end lint categories
+];
+
+/// Replaces the SARIF message format in the arguments for cargo by the JSON
+/// format, which we convert. Returns whether it was found.
+pub fn replace_message_format(args: &mut [String]) -> bool {
+    let mut found = false;
+    for i in 0..args.len() {
+        if args[i] == "--message-format=sarif" {
+            args[i] = "--message-format=json".to_owned();
+            found = true;
+        } else if args[i] == "sarif" && i > 0 && args[i - 1] == "--message-format" {
+            args[i] = "json".to_owned();
+            found = true;
+        }
+    }
+    found
+}
+
+/// Reads the JSON messages of cargo and prints the lints among them as a
+/// SARIF log.
+#[allow(print_stdout)]
+pub fn print_report<R: BufRead>(messages: R) {
+    let mut rules = Vec::new();
+    let mut results = Vec::new();
+    for line in messages.lines() {
+        let line = match line {
+            Ok(line) => line,
+            Err(_) => break,
+        };
+        let message = match serde_json::from_str::<Value>(&line) {
+            Ok(message) => message,
+            Err(_) => continue,
+        };
+        if message["reason"] != "compiler-message" {
+            continue;
+        }
+        let diagnostic = &message["message"];
+        let (name, category) = match diagnostic["code"]["code"].as_str().and_then(lint_category) {
+            Some(lint) => lint,
+            None => continue,
+        };
+        if !rules.contains(&name) {
+            rules.push(name);
+        }
+        results.push(result(name, diagnostic));
+    }
+
+    rules.sort();
+    let rules = rules
+        .into_iter()
+        .map(|name| {
+            json!({
+                "id": name,
+                "helpUri": format!("{}#{}", DOCS_LINK, name),
+                "properties": {
+                    "category": lint_category(name).map(|(_, category)| category),
+                },
+            })
+        })
+        .collect::<Vec<_>>();
+    let log = json!({
+        "$schema": "https://raw.githubusercontent.com/oasis-tcs/sarif-spec/master/Schemata/sarif-schema-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "clippy",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": "https://github.com/rust-lang-nursery/rust-clippy",
+                    "rules": rules,
+                },
+            },
+            "results": results,
+        }],
+    });
+    println!("{}", serde_json::to_string_pretty(&log).expect("a JSON value can always be serialized"));
+}
+
+/// Returns the name and the category of the lint with the given code.
+fn lint_category(code: &str) -> Option<(&'static str, &'static str)> {
+    let name = code.trim_left_matches("clippy::");
+    LINT_CATEGORIES
+        .binary_search_by_key(&name, |&(lint, _)| lint)
+        .ok()
+        .map(|i| LINT_CATEGORIES[i])
+}
+
+/// Converts a diagnostic of rustc into a SARIF result, with the suggestions of
+/// its children as fixes.
+fn result(name: &str, diagnostic: &Value) -> Value {
+    let level = match diagnostic["level"].as_str() {
+        Some("error") => "error",
+        Some("warning") => "warning",
+        _ => "note",
+    };
+    let locations = spans(diagnostic)
+        .filter(|span| span["is_primary"] == true)
+        .map(|span| {
+            json!({
+                "physicalLocation": {
+                    "artifactLocation": { "uri": uri(span) },
+                    "region": region(span),
+                },
+            })
+        })
+        .collect::<Vec<_>>();
+    let fixes = diagnostic["children"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|child| {
+            let replacements = spans(child)
+                .filter_map(|span| {
+                    let text = span["suggested_replacement"].as_str()?;
+                    Some((uri(span), region(span), text))
+                })
+                .collect::<Vec<_>>();
+            if replacements.is_empty() {
+                return None;
+            }
+            let changes = replacements
+                .into_iter()
+                .map(|(uri, region, text)| {
+                    json!({
+                        "artifactLocation": { "uri": uri },
+                        "replacements": [{
+                            "deletedRegion": region,
+                            "insertedContent": { "text": text },
+                        }],
+                    })
+                })
+                .collect::<Vec<_>>();
+            Some(json!({
+                "description": { "text": child["message"] },
+                "artifactChanges": changes,
+            }))
+        })
+        .collect::<Vec<_>>();
+    json!({
+        "ruleId": name,
+        "level": level,
+        "message": { "text": diagnostic["message"] },
+        "locations": locations,
+        "fixes": fixes,
+    })
+}
+
+fn spans<'a>(diagnostic: &'a Value) -> impl Iterator<Item = &'a Value> {
+    diagnostic["spans"].as_array().into_iter().flatten()
+}
+
+/// The file of a span as a relative URI.
+fn uri(span: &Value) -> String {
+    span["file_name"].as_str().unwrap_or_default().replace('\\', "/")
+}
+
+/// The region of a span. Both rustc and SARIF use 1-based lines and columns,
+/// with the end column being exclusive.
+fn region(span: &Value) -> Value {
+    json!({
+        "startLine": span["line_start"],
+        "startColumn": span["column_start"],
+        "endLine": span["line_end"],
+        "endColumn": span["column_end"],
+    })
+}
@@ -69,9 +69,21 @@ fn run_mode(mode: &str, dir: PathBuf) {
 }
 
 fn run_ui_toml_tests(config: &compiletest::Config, mut tests: Vec<test::TestDescAndFn>) -> Result<bool, io::Error> {
-    let mut result = true;
     let opts = compiletest::test_opts(config);
-    for dir in fs::read_dir(&config.src_base)? {
+    run_ui_toml_dir(config, &opts, &config.src_base, &mut tests)
+}
+
+/// Runs the tests of each subdirectory of `dir` with the configuration file of
+/// that subdirectory. Nested directories test configuration files inheriting
+/// from the one of an enclosing directory.
+fn run_ui_toml_dir(
+    config: &compiletest::Config,
+    opts: &test::TestOpts,
+    dir: &Path,
+    tests: &mut Vec<test::TestDescAndFn>,
+) -> Result<bool, io::Error> {
+    let mut result = true;
+    for dir in fs::read_dir(dir)? {
         let dir = dir?;
         if !dir.file_type()?.is_dir() {
             continue;
@@ -90,15 +102,16 @@ fn run_ui_toml_tests(config: &compiletest::Config, mut tests: Vec<test::TestDesc
             let paths = compiletest::common::TestPaths {
                 file: file_path,
                 base: config.src_base.clone(),
-                relative_dir: dir_path.file_name().unwrap().into(),
+                relative_dir: dir_path.strip_prefix(&config.src_base).unwrap().into(),
             };
             let test_name = compiletest::make_test_name(&config, &paths);
             let index = tests
                 .iter()
                 .position(|test| test.desc.name == test_name)
                 .expect("The test should be in there");
-            result &= test::run_tests_console(&opts, vec![tests.swap_remove(index)])?;
+            result &= test::run_tests_console(opts, vec![tests.swap_remove(index)])?;
         }
+        result &= run_ui_toml_dir(config, opts, &dir_path, tests)?;
     }
     Ok(result)
 }
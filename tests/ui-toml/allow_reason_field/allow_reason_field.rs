@@ -0,0 +1,15 @@
+#![feature(lint_reasons)]
+#![warn(allow_attributes_without_reason)]
+#![allow(dead_code, reason = "the test functions are never called")]
+
+// a comment is not enough
+#[allow(unused_mut)]
+fn comment_above() {}
+
+#[allow(unused_mut)] // neither is a comment at the end of the line
+fn comment_after() {}
+
+#[allow(unused_mut, reason = "Ok, the reason is given in the attribute")]
+fn reason_field() {}
+
+fn main() {}
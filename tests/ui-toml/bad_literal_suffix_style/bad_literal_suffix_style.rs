@@ -0,0 +1,3 @@
+// error-pattern: error reading Clippy's configuration file: `both` is not a valid literal suffix style
+
+fn main() {}
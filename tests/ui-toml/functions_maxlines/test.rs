@@ -0,0 +1,44 @@
+#![warn(too_many_lines)]
+
+// This function should be considered one line.
+fn many_comments_but_one_line_of_code() {
+    /* println!("This is good."); */
+    // println!("This is good.");
+    /* */ // println!("This is good.");
+    /* */ // println!("This is good.");
+    /* */ // println!("This is good.");
+    /* */ // println!("This is good.");
+    /* println!("This is good.");
+    println!("This is good.");
+    println!("This is good."); */
+    println!("This is good.");
+}
+
+// This should be considered two and a half lines, rounded up to three.
+fn too_many_lines() {
+    println!("This is bad.");
+    println!("This is bad.");
+    /* println!("This is bad."); */ println!("This is bad."); /* println!("This is bad."); */
+}
+
+// This should be considered one line.
+fn comment_starts_after_code() {
+    let _ = 5; /* closing comment. */ /*
+    this line shouldn't be counted theoretically.
+    */
+}
+
+// This should be considered two lines.
+fn comment_before_code() {
+    let _ = "test";
+    /* This comment extends to the front of
+    the code but this line should still count. */ let _ = 5;
+}
+
+// This should be considered one line.
+fn comment_after_code() {
+    let _ = "test"; /* This comment extends
+    to the next line */
+}
+
+fn main() {}
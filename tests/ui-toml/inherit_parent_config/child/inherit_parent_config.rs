@@ -0,0 +1,11 @@
+#![warn(blacklisted_name, too_many_arguments)]
+#![allow(unused_variables)]
+
+// the threshold is inherited
+fn three(_one: u8, _two: u8, _three: u8) {}
+
+fn main() {
+    // the list is overridden
+    let toto = 42;
+    let titi = 42;
+}
@@ -0,0 +1,11 @@
+#![warn(large_include_file)]
+
+// good
+const GOOD_INCLUDE_BYTES: &[u8; 42] = include_bytes!("small.txt");
+const GOOD_INCLUDE_STR: &str = include_str!("small.txt");
+
+// bad
+const TOO_BIG_INCLUDE_BYTES: &[u8; 700] = include_bytes!("too_big.txt");
+const TOO_BIG_INCLUDE_STR: &str = include_str!("too_big.txt");
+
+fn main() {}
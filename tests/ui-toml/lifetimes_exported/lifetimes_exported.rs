@@ -0,0 +1,30 @@
+#![warn(needless_lifetimes, extra_unused_lifetimes)]
+#![allow(dead_code)]
+
+// kept by the configuration
+pub fn exported<'a>(x: &'a u8) -> &'a u8 {
+    x
+}
+
+fn private<'a>(x: &'a u8) -> &'a u8 {
+    x
+}
+
+pub struct Foo;
+
+impl Foo {
+    pub fn exported_method<'a>(x: &'a u8) -> &'a u8 {
+        x
+    }
+
+    fn private_method<'a>(x: &'a u8) -> &'a u8 {
+        x
+    }
+}
+
+// kept by the configuration
+pub trait Exported<'a> {}
+
+trait Private<'a> {}
+
+fn main() {}
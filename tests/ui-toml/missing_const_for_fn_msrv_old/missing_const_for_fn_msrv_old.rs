@@ -0,0 +1,11 @@
+#![feature(const_fn)]
+#![warn(missing_const_for_fn)]
+#![allow(dead_code)]
+
+// no error, `const fn` isn't stable in Rust 1.30
+
+fn one() -> i32 { 1 }
+
+fn string() -> &'static str { "Hello" }
+
+fn main() {}
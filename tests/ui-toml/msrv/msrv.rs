@@ -0,0 +1,16 @@
+#![warn(seek_from_current, seek_to_start_instead_of_rewind)]
+#![allow(dead_code, unused_must_use)]
+
+use std::io::{Seek, SeekFrom};
+
+// `stream_position` is available since 1.51
+fn current_position<T: Seek>(t: &mut T) {
+    t.seek(SeekFrom::Current(0));
+}
+
+// `rewind` is only available since 1.55
+fn seek_to_start<T: Seek>(t: &mut T) {
+    t.seek(SeekFrom::Start(0));
+}
+
+fn main() {}
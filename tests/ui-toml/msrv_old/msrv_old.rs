@@ -0,0 +1,25 @@
+#![feature(non_exhaustive)]
+#![warn(seek_from_current, duration_subsec, manual_non_exhaustive)]
+#![allow(dead_code, unused_must_use)]
+
+// no error, none of the suggested APIs is available in Rust 1.26
+
+use std::io::{Seek, SeekFrom};
+use std::time::Duration;
+
+fn current_position<T: Seek>(t: &mut T) {
+    t.seek(SeekFrom::Current(0));
+}
+
+fn millis(dur: Duration) -> u32 {
+    dur.subsec_nanos() / 1_000_000
+}
+
+pub enum E {
+    A,
+    B,
+    #[doc(hidden)]
+    _C,
+}
+
+fn main() {}
@@ -0,0 +1,26 @@
+#![warn(trivially_copy_pass_by_ref)]
+#![allow(dead_code)]
+
+// kept by the configuration
+pub fn exported(x: &u32) -> u32 {
+    *x
+}
+
+fn private(x: &u32) -> u32 {
+    *x
+}
+
+pub struct Foo;
+
+impl Foo {
+    // kept by the configuration
+    pub fn exported_method(&self, x: &u32) -> u32 {
+        *x
+    }
+
+    fn private_method(&self, x: &u32) -> u32 {
+        *x
+    }
+}
+
+fn main() {}
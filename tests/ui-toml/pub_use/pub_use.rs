@@ -0,0 +1,19 @@
+#![warn(pub_use)]
+
+pub mod api {
+    mod types {
+        pub struct Config {}
+    }
+
+    pub mod exports {
+        // allowed by the configuration
+        pub use api::types::Config;
+    }
+
+    pub mod prelude {
+        // not allowed, the configuration replaces the default
+        pub use api::types::Config;
+    }
+}
+
+fn main() {}
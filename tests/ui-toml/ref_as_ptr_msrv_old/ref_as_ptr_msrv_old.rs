@@ -0,0 +1,12 @@
+#![warn(ref_as_ptr)]
+#![allow(unused_variables)]
+
+// no error, `std::ptr::from_ref` and `std::ptr::from_mut` aren't available in Rust 1.75
+
+fn main() {
+    let a = 0u32;
+    let _ = &a as *const u32;
+
+    let mut b = 0u32;
+    let _ = &mut b as *mut u32;
+}
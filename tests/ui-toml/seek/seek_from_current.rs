@@ -0,0 +1,24 @@
+#![warn(seek_from_current)]
+#![allow(dead_code)]
+
+use std::io::{self, Seek, SeekFrom};
+
+fn current_position<T: Seek>(t: &mut T) -> io::Result<u64> {
+    t.seek(SeekFrom::Current(0))
+}
+
+fn current_position_try<T: Seek>(mut t: T) -> io::Result<u64> {
+    let pos = t.seek(SeekFrom::Current(0))?;
+    Ok(pos)
+}
+
+// Do not lint
+fn seek_forward<T: Seek>(t: &mut T) -> io::Result<u64> {
+    t.seek(SeekFrom::Current(1))
+}
+
+fn seek_to_start<T: Seek>(t: &mut T) -> io::Result<u64> {
+    t.seek(SeekFrom::Start(0))
+}
+
+fn main() {}
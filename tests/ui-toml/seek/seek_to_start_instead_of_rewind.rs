@@ -0,0 +1,27 @@
+#![warn(seek_to_start_instead_of_rewind)]
+#![allow(dead_code, unused_must_use)]
+
+use std::io::{Seek, SeekFrom};
+
+fn seek_to_start<T: Seek>(t: &mut T) {
+    t.seek(SeekFrom::Start(0));
+}
+
+fn owned_seek_to_start<T: Seek>(mut t: T) {
+    t.seek(SeekFrom::Start(0));
+}
+
+// Do not lint
+fn seek_to_end<T: Seek>(t: &mut T) {
+    t.seek(SeekFrom::End(0));
+}
+
+fn seek_to_five<T: Seek>(t: &mut T) {
+    t.seek(SeekFrom::Start(5));
+}
+
+fn seek_to_start_used<T: Seek>(t: &mut T) -> u64 {
+    t.seek(SeekFrom::Start(0)).unwrap()
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+#![warn(seek_from_current, seek_to_start_instead_of_rewind)]
+#![allow(dead_code, unused_must_use)]
+
+// no error, neither `stream_position` nor `rewind` is available in Rust 1.50
+
+use std::io::{Seek, SeekFrom};
+
+fn current_position<T: Seek>(t: &mut T) {
+    t.seek(SeekFrom::Current(0));
+}
+
+fn seek_to_start<T: Seek>(t: &mut T) {
+    t.seek(SeekFrom::Start(0));
+}
+
+fn main() {}
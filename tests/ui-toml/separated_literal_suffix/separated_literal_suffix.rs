@@ -0,0 +1,16 @@
+#![warn(separated_literal_suffix)]
+#![allow(dead_code, unused_variables)]
+
+fn main() {
+    let _ok1 = 1234i32;
+    let _ok2 = 1.5f32;
+    let _ok3 = 0xab_cdu32;
+    let _ok4 = 1234;
+
+    let _fail1 = 1234_i32;
+    let _fail2 = 1234_u32;
+    let _fail3 = 1234_isize;
+    let _fail4 = 0xab_cd_u32;
+    let _fail5 = 1.5_f32;
+    let _fail6 = 100__u8;
+}
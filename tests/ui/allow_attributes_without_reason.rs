@@ -0,0 +1,33 @@
+#![warn(allow_attributes_without_reason)]
+// the test functions are never called
+#![allow(dead_code)]
+
+#[allow(unused_mut)]
+fn no_reason() {}
+
+/// Documentation is not a reason
+#[inline]
+#[allow(unused_mut)]
+fn doc_is_not_a_reason() {}
+
+fn inner() {
+    #![allow(unused_mut)]
+}
+
+// Ok, the reason is given above
+#[allow(unused_mut)]
+fn reason_above() {}
+
+// Ok, the reason is given above the stacked attributes
+#[inline]
+#[allow(unused_mut)]
+fn reason_above_stacked() {}
+
+#[allow(unused_mut)] // Ok, the reason is given at the end of the line
+fn reason_after() {}
+
+// Ok, not an `allow` attribute
+#[warn(unused_mut)]
+fn warn() {}
+
+fn main() {}
@@ -0,0 +1,62 @@
+// compile-flags: --edition 2018
+
+#![feature(async_await, await_macro, futures_api)]
+#![warn(await_holding_lock)]
+
+use std::cell::RefCell;
+use std::sync::{Mutex, RwLock};
+
+async fn baz() -> u32 {
+    42
+}
+
+async fn bad_mutex(x: &Mutex<u32>) -> u32 {
+    let guard = x.lock().unwrap();
+    await!(baz()) + *guard
+}
+
+async fn bad_rwlock(x: &RwLock<u32>) -> u32 {
+    let read = x.read().unwrap();
+    let n = *read;
+    let mut write = x.write().unwrap();
+    *write += n;
+    await!(baz())
+}
+
+async fn bad_refcell(x: &RefCell<u32>) -> u32 {
+    let mut b = x.borrow_mut();
+    *b += 1;
+    await!(baz())
+}
+
+async fn good_mutex(x: &Mutex<u32>) -> u32 {
+    {
+        let mut guard = x.lock().unwrap();
+        *guard += 1;
+    }
+    await!(baz())
+}
+
+async fn mixed(x: &Mutex<u32>, y: &Mutex<u32>) -> u32 {
+    {
+        let mut first = x.lock().unwrap();
+        *first += 1;
+    }
+    let second = y.lock().unwrap();
+    await!(baz()) + *second
+}
+
+fn not_async(x: &Mutex<u32>) -> u32 {
+    let guard = x.lock().unwrap();
+    *guard
+}
+
+fn main() {
+    let m = Mutex::new(100);
+    let _ = bad_mutex(&m);
+    let _ = good_mutex(&m);
+    let _ = mixed(&m, &m);
+    let _ = not_async(&m);
+    let _ = bad_rwlock(&RwLock::new(0));
+    let _ = bad_refcell(&RefCell::new(0));
+}
@@ -4,7 +4,7 @@
 #![deny(borrowed_box)]
 #![allow(blacklisted_name)]
 #![allow(unused_variables)]
-#![allow(dead_code)]
+#![allow(dead_code, redundant_static_bounds)]
 
 pub fn test1(foo: &mut Box<bool>) {
     println!("{:?}", foo)
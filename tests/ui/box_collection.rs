@@ -2,9 +2,11 @@
 
 
 #![warn(clippy)]
-#![allow(boxed_local, needless_pass_by_value)]
+#![allow(boxed_local, needless_pass_by_value, unused_variables)]
 #![allow(blacklisted_name)]
 
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+
 macro_rules! boxit {
     ($init:expr, $x:ty) => {
         let _: Box<$x> = Box::new($init);
@@ -22,10 +24,23 @@ pub fn test2(foo: Box<Fn(Vec<u32>)>) { // pass if #31 is fixed
     foo(vec![1, 2, 3])
 }
 
+pub fn test3(foo: Box<String>) {}
+
+pub fn test4(foo: Box<HashMap<String, String>>) {}
+
+pub fn test5(foo: Box<HashSet<i64>>) {}
+
+pub fn test6(foo: Box<VecDeque<i32>>) {}
+
+pub fn test7(foo: Box<BinaryHeap<u32>>) {}
+
 pub fn test_local_not_linted() {
     let _: Box<Vec<bool>>;
 }
 
+// Ok, slices and `str` are not collections
+pub fn test_boxed_slice(foo: Box<[u8]>, bar: Box<str>) {}
+
 fn main(){
     test(Box::new(Vec::new()));
     test2(Box::new(|v| println!("{:?}", v)));
@@ -1,3 +1,5 @@
+#![allow(non_canonical_clone_impl)]
+
 use std::marker::PhantomData;
 use std::fmt;
 
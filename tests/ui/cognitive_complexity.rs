@@ -0,0 +1,64 @@
+#![warn(cognitive_complexity)]
+#![allow(unused)]
+
+#[clippy::cognitive_complexity = "0"]
+fn nested_loops(v: &[Vec<u32>]) -> u32 {
+    let mut sum = 0;
+    for row in v {
+        for x in row {
+            if *x > 10 {
+                sum += x;
+            } else if *x > 5 {
+                sum += 1;
+            } else {
+                continue;
+            }
+        }
+    }
+    sum
+}
+
+#[clippy::cognitive_complexity = "0"]
+fn logical(a: bool, b: bool, c: bool, d: bool) -> bool {
+    a && b || c && d
+}
+
+#[clippy::cognitive_complexity = "0"]
+fn early_exits(s: &str) -> Result<u32, std::num::ParseIntError> {
+    let n: u32 = s.parse()?;
+    if n == 0 {
+        return Ok(1);
+    }
+    let mut sum = 0;
+    'outer: for i in 0..n {
+        for j in 0..i {
+            if j == 3 {
+                continue 'outer;
+            }
+            sum += j;
+        }
+    }
+    Ok(sum)
+}
+
+#[clippy::cognitive_complexity = "0"]
+fn closures_and_matches(v: Vec<Option<u32>>) -> Vec<u32> {
+    v.into_iter()
+        .map(|x| match x {
+            Some(x) if x > 1 => x,
+            _ => 0,
+        })
+        .collect()
+}
+
+#[clippy::cognitive_complexity = "1"]
+fn macros_are_not_scored(x: u32) {
+    assert!(x > 0);
+    assert_eq!(x, 1);
+    println!("{}", x);
+    if x == 1 {
+        println!();
+    }
+}
+
+fn main() {}
@@ -11,4 +11,6 @@
 
 #[warn(misaligned_transmute)]
 
+#[warn(box_vec)]
+
 fn main() {}
@@ -0,0 +1,97 @@
+#![allow(dead_code)]
+
+struct FooDefault {
+    a: bool,
+    b: i32,
+    c: u64,
+    d: Vec<i32>,
+    e: String,
+    f: (i32, bool),
+    g: Option<i32>,
+    h: &'static str,
+}
+
+impl std::default::Default for FooDefault {
+    fn default() -> Self {
+        Self {
+            a: false,
+            b: 0,
+            c: 0u64,
+            d: Default::default(),
+            e: String::default(),
+            f: (0, false),
+            g: None,
+            h: "",
+        }
+    }
+}
+
+struct TupleDefault(bool, i32, u64);
+
+impl std::default::Default for TupleDefault {
+    fn default() -> Self {
+        TupleDefault(false, 0, 0u64)
+    }
+}
+
+// not derivable: non-default value
+struct FooND1 {
+    a: bool,
+}
+
+impl std::default::Default for FooND1 {
+    fn default() -> Self {
+        Self { a: true }
+    }
+}
+
+// not derivable: calls another constructor
+struct FooND2 {
+    a: i32,
+}
+
+impl FooND2 {
+    fn new() -> Self {
+        Self { a: 5 }
+    }
+}
+
+impl std::default::Default for FooND2 {
+    fn default() -> Self {
+        FooND2::new()
+    }
+}
+
+// not derivable: documented impl
+struct Documented {
+    a: i32,
+}
+
+/// The documentation explains why this is written by hand
+impl Default for Documented {
+    fn default() -> Self {
+        Self { a: 0 }
+    }
+}
+
+// not derivable: the derive would add a `T: Default` bound
+struct Generic<T> {
+    a: Option<T>,
+}
+
+impl<T> Default for Generic<T> {
+    fn default() -> Self {
+        Self { a: None }
+    }
+}
+
+// derivable: the struct is named instead of using `Self`
+struct Named {
+    guess: i32,
+}
+
+impl Default for Named {
+    fn default() -> Self { Named { guess: 0 } }
+}
+
+fn main() {}
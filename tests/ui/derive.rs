@@ -2,7 +2,7 @@
 
 #![feature(untagged_unions)]
 
-#![allow(dead_code)]
+#![allow(dead_code, non_canonical_clone_impl)]
 #![warn(expl_impl_clone_on_copy)]
 
 use std::hash::{Hash, Hasher};
@@ -0,0 +1,65 @@
+#![warn(duplicated_attributes)]
+#![allow(dead_code, unused_variables)]
+
+#[inline]
+#[inline]
+fn duplicated() {}
+
+#[allow(unused_mut, unused_mut)]
+#[allow(unused_mut)]
+fn duplicated_lint() {}
+
+#[allow(unused_mut)]
+mod parent {
+    #[allow(unused_mut)]
+    fn child() {}
+
+    // Ok, the level is changed in between
+    #[warn(unused_mut)]
+    mod nested {
+        #[allow(unused_mut)]
+        fn grandchild() {}
+    }
+}
+
+// Ok, different attributes
+#[allow(unused_mut)]
+#[allow(unused_assignments)]
+#[inline(always)]
+fn different() {}
+
+/// Ok, doc comments
+/// can span several lines
+fn documented() {}
+
+struct Fields {
+    #[allow(unused_mut)]
+    #[allow(unused_mut)]
+    a: u8,
+}
+
+enum Variants {
+    #[allow(unused_mut)]
+    #[allow(unused_mut)]
+    A,
+}
+
+#[allow(unused_mut)]
+trait Trait {
+    #[allow(unused_mut)]
+    fn trait_item();
+}
+
+impl Fields {
+    #[inline]
+    #[inline]
+    fn impl_item() {}
+}
+
+fn locals() {
+    #[allow(unused_mut)]
+    #[allow(unused_mut)]
+    let x = 1;
+}
+
+fn main() {}
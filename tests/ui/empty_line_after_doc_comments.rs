@@ -0,0 +1,43 @@
+#![warn(empty_line_after_doc_comments)]
+#![allow(dead_code)]
+
+// This should produce a warning
+/// some doc comment
+
+fn with_one_newline() {}
+
+// This should produce a warning, too
+/// some doc comment
+/// with multiple lines
+
+
+fn with_two_newlines() {}
+
+// This should produce a warning
+/** This is a block doc comment */
+
+struct Foo;
+
+// This should produce a warning
+/// some doc comment
+
+#[inline]
+fn with_attribute() {}
+
+// This should not produce a warning
+/// some doc comment
+fn with_no_newline() {}
+
+// This should not produce a warning
+/// some doc comment
+#[inline]
+fn with_attribute_and_no_newline() {}
+
+// This should not produce a warning because the empty line is inside a block comment
+/// some doc comment
+/*
+
+*/
+fn with_block_comment() {}
+
+fn main() {}
@@ -1,4 +1,4 @@
-
+#![allow(duplicated_attributes)]
 #![warn(empty_line_after_outer_attr)]
 
 // This should produce a warning
@@ -1,7 +1,7 @@
 
 
 #![warn(clippy, clippy_pedantic)]
-#![allow(unused_imports, dead_code, missing_docs_in_private_items)]
+#![allow(unused_imports, dead_code, missing_docs_in_private_items, semicolon_if_nothing_returned)]
 
 use std::cmp::Ordering::*;
 
@@ -0,0 +1,50 @@
+#![feature(non_exhaustive)]
+#![warn(exhaustive_enums, exhaustive_structs)]
+#![allow(dead_code)]
+
+pub mod enums {
+    pub enum Exhaustive {
+        Foo,
+        Bar,
+        Baz,
+        Quux(String),
+    }
+
+    #[non_exhaustive]
+    pub enum NonExhaustive {
+        Foo,
+        Bar,
+        Baz,
+        Quux(String),
+    }
+
+    enum NotExported {
+        Foo,
+        Bar,
+    }
+}
+
+pub mod structs {
+    pub struct Exhaustive {
+        pub foo: u8,
+        pub bar: String,
+    }
+
+    // private fields already prevent exhaustive construction
+    pub struct ExhaustivePrivateField {
+        pub foo: u8,
+        bar: String,
+    }
+
+    #[non_exhaustive]
+    pub struct NonExhaustive {
+        pub foo: u8,
+        pub bar: String,
+    }
+
+    struct NotExported {
+        pub foo: u8,
+    }
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+#![warn(exhaustive_enums, exhaustive_structs)]
+#![allow(dead_code)]
+
+// no error, `#[non_exhaustive]` can't be used without the feature
+
+pub enum Exhaustive {
+    Foo,
+    Bar,
+}
+
+pub struct ExhaustiveStruct {
+    pub foo: u8,
+    pub bar: String,
+}
+
+fn main() {}
@@ -0,0 +1,33 @@
+#![allow(unused)]
+#![warn(expensive_construction_in_loops)]
+
+extern crate regex;
+
+use regex::Regex;
+
+fn main() {
+    for _ in 0..10 {
+        let _ = Regex::new("a.b");
+    }
+
+    let mut n = 0;
+    while n < 3 {
+        let _ = Regex::new("a.b");
+        n += 1;
+    }
+
+    (0..10).for_each(|_| {
+        let _ = Regex::new("a.b");
+    });
+
+    // not in a loop
+    let _ = Regex::new("a.b");
+
+    // the pattern depends on the iteration
+    for s in &["a", "b"] {
+        let _ = Regex::new(s);
+    }
+
+    // the closure is not called once per item
+    let f = || Regex::new("a.b");
+}
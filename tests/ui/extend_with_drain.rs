@@ -0,0 +1,25 @@
+#![warn(extend_with_drain)]
+#![allow(dead_code)]
+
+fn take_mut(a: &mut Vec<u8>, b: &mut Vec<u8>) {
+    a.extend(b.drain(..));
+}
+
+fn main() {
+    let mut vec1 = vec![0u8; 1024];
+    let mut vec2 = vec![0u8; 1024];
+    vec1.extend(vec2.drain(..));
+
+    // Ok, partial range
+    vec1.extend(vec2.drain(1..));
+
+    // Ok, not a `Vec`
+    let mut deque = std::collections::VecDeque::new();
+    deque.push_back(0u8);
+    vec1.extend(deque.drain(..));
+
+    // Ok, the drained elements are mapped
+    let mut vec3 = vec![0u16; 1024];
+    let mut vec4 = Vec::<u16>::new();
+    vec4.extend(vec3.drain(..).map(|x| x + 1));
+}
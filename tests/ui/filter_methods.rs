@@ -2,7 +2,7 @@
 
 
 #![warn(clippy, clippy_pedantic)]
-#![allow(missing_docs_in_private_items)]
+#![allow(missing_docs_in_private_items, semicolon_if_nothing_returned)]
 
 fn main() {
     let _: Vec<_> = vec![5; 6].into_iter()
@@ -0,0 +1,37 @@
+#![warn(fn_params_excessive_bools)]
+#![allow(dead_code)]
+
+extern "C" {
+    fn f(_: bool, _: bool, _: bool, _: bool);
+}
+
+extern "C" fn k(_: bool, _: bool, _: bool, _: bool) {}
+
+fn g(_: bool, _: bool, _: bool, _: bool) {}
+
+fn h(_: bool, _: bool, _: bool) {}
+
+#[allow(fn_params_excessive_bools)]
+fn i(_: bool, _: bool, _: bool, _: bool) {}
+
+struct S;
+
+trait Trait {
+    fn f(_: bool, _: bool, _: bool, _: bool);
+    fn g(_: bool, _: bool, _: bool);
+}
+
+impl S {
+    fn f(&self, _: bool, _: bool, _: bool, _: bool) {}
+    fn g(&self, _: bool, _: bool, _: bool) {}
+}
+
+impl Trait for S {
+    // should not lint, the trait is to blame
+    fn f(_: bool, _: bool, _: bool, _: bool) {}
+    fn g(_: bool, _: bool, _: bool) {}
+}
+
+fn main() {
+    fn n(_: bool, _: u32, _: bool, _: Box<u32>, _: bool, _: bool) {}
+}
@@ -0,0 +1,25 @@
+#![warn(fn_to_numeric_cast_any)]
+#![allow(fn_to_numeric_cast, fn_to_numeric_cast_with_truncation)]
+
+fn foo() -> u8 {
+    0
+}
+
+fn generic_foo<T>(x: T) -> T {
+    x
+}
+
+fn main() {
+    let _ = foo as i8;
+    let _ = foo as i64;
+    let _ = foo as usize;
+    let _ = foo as u64;
+
+    let _ = generic_foo::<u8> as usize;
+
+    let fn_ptr: fn() -> u8 = foo;
+    let _ = fn_ptr as usize;
+
+    // ok, the function is called
+    let _ = foo() as usize;
+}
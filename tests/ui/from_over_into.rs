@@ -0,0 +1,29 @@
+#![warn(from_over_into)]
+#![allow(dead_code)]
+
+struct StringWrapper(String);
+
+impl Into<StringWrapper> for String {
+    fn into(self) -> StringWrapper {
+        StringWrapper(self)
+    }
+}
+
+struct SelfType(String);
+
+impl Into<SelfType> for String {
+    fn into(self) -> SelfType {
+        SelfType(Self::new() + &self)
+    }
+}
+
+// Ok, `From` is already preferred
+struct Wrapper(u32);
+
+impl From<u32> for Wrapper {
+    fn from(val: u32) -> Self {
+        Wrapper(val)
+    }
+}
+
+fn main() {}
@@ -1,4 +1,4 @@
-#![allow(dead_code)]
+#![allow(dead_code, extra_unused_lifetimes)]
 #![warn(multiple_inherent_impl)]
 
 struct MyStruct;
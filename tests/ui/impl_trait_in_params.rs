@@ -0,0 +1,30 @@
+#![warn(impl_trait_in_params)]
+#![allow(dead_code, unused_variables)]
+
+use std::fmt::Debug;
+
+pub fn debug(x: impl Debug) {}
+
+pub fn nested(x: Vec<impl Debug>) {}
+
+pub fn generic<T: Debug>(x: T) {}
+
+fn private(x: impl Debug) {}
+
+pub struct S;
+
+impl S {
+    pub fn method(&self, x: impl Debug) {}
+
+    fn private_method(&self, x: impl Debug) {}
+}
+
+pub trait Taking {
+    fn take(x: impl Debug);
+}
+
+impl Taking for S {
+    fn take(x: impl Debug) {}
+}
+
+fn main() {}
@@ -90,4 +90,10 @@ gen!(fn bar);
 #[path = "../auxiliary/test_macro.rs"] pub mod test_macro;
 __implicit_hasher_test_macro!(impl<K, V> for HashMap<K, V> where V: test_macro::A);
 
+pub fn with_where_clause<K>(_map: &HashMap<K, u8>) where K: Hash + Eq {
+}
+
+pub fn with_hasher_name<S>(_map: &HashMap<i32, S>) {
+}
+
 fn main() {}
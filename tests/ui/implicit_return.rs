@@ -0,0 +1,68 @@
+#![warn(implicit_return)]
+#![allow(dead_code, unused_variables)]
+
+fn test_end_of_fn() -> bool {
+    if true {
+        // no error!
+        return true;
+    }
+    true
+}
+
+fn test_if_block() -> bool {
+    if true {
+        true
+    } else {
+        false
+    }
+}
+
+fn test_match(x: bool) -> bool {
+    match x {
+        true => false,
+        false => {
+            true
+        },
+    }
+}
+
+fn test_loop() -> bool {
+    loop {
+        break true;
+    }
+}
+
+fn test_loop_with_nested_loop() -> bool {
+    loop {
+        let x = loop {
+            break 10;
+        };
+        if x > 5 {
+            break true;
+        }
+    }
+}
+
+fn test_closure() {
+    let _ = || {
+        true
+    };
+    let _ = || true;
+}
+
+fn test_macro() -> String {
+    format!("test {}", "test")
+}
+
+fn test_unit() {
+    test_closure()
+}
+
+fn main() {
+    let _ = test_end_of_fn();
+    let _ = test_if_block();
+    let _ = test_match(true);
+    let _ = test_loop();
+    let _ = test_loop_with_nested_loop();
+    test_closure();
+}
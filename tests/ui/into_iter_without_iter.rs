@@ -0,0 +1,64 @@
+#![warn(into_iter_without_iter)]
+#![allow(dead_code)]
+
+pub struct S1;
+impl<'a> IntoIterator for &'a S1 {
+    type Item = &'a u8;
+    type IntoIter = std::slice::Iter<'a, u8>;
+    fn into_iter(self) -> Self::IntoIter {
+        [].iter()
+    }
+}
+impl<'a> IntoIterator for &'a mut S1 {
+    type Item = &'a mut u8;
+    type IntoIter = std::slice::IterMut<'a, u8>;
+    fn into_iter(self) -> Self::IntoIter {
+        [].iter_mut()
+    }
+}
+
+pub struct S2<T>(Vec<T>);
+impl<'a, T> IntoIterator for &'a S2<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+// Ok, has an `iter` method
+pub struct S3(Vec<u8>);
+impl S3 {
+    pub fn iter(&self) -> std::slice::Iter<u8> {
+        self.0.iter()
+    }
+}
+impl<'a> IntoIterator for &'a S3 {
+    type Item = &'a u8;
+    type IntoIter = std::slice::Iter<'a, u8>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+// Ok, not public
+struct S4;
+impl<'a> IntoIterator for &'a S4 {
+    type Item = &'a u8;
+    type IntoIter = std::slice::Iter<'a, u8>;
+    fn into_iter(self) -> Self::IntoIter {
+        [].iter()
+    }
+}
+
+// Ok, not implemented for a reference
+pub struct S5;
+impl IntoIterator for S5 {
+    type Item = u8;
+    type IntoIter = std::vec::IntoIter<u8>;
+    fn into_iter(self) -> Self::IntoIter {
+        Vec::new().into_iter()
+    }
+}
+
+fn main() {}
@@ -0,0 +1,68 @@
+#![warn(iter_without_into_iter)]
+#![allow(dead_code)]
+
+pub struct S1;
+impl S1 {
+    pub fn iter(&self) -> std::slice::Iter<'static, u8> {
+        [].iter()
+    }
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'static, u8> {
+        [].iter_mut()
+    }
+}
+
+pub struct S2<'a>(&'a [u8]);
+impl<'a> S2<'a> {
+    pub fn iter(&self) -> std::slice::Iter<'a, u8> {
+        self.0.iter()
+    }
+}
+
+pub struct S3<T>(Vec<T>);
+impl<T> S3<T> {
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<T> {
+        self.0.iter_mut()
+    }
+}
+
+// Ok, has `IntoIterator` impls
+pub struct S4(Vec<u8>);
+impl S4 {
+    pub fn iter(&self) -> std::slice::Iter<u8> {
+        self.0.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a S4 {
+    type Item = &'a u8;
+    type IntoIter = std::slice::Iter<'a, u8>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+// Ok, doesn't return an iterator
+pub struct S5;
+impl S5 {
+    pub fn iter(&self) -> u8 {
+        0
+    }
+}
+
+// Ok, not public
+pub struct S6;
+impl S6 {
+    fn iter(&self) -> std::slice::Iter<'static, u8> {
+        [].iter()
+    }
+}
+
+// Ok, takes self by value
+pub struct S7;
+impl S7 {
+    pub fn iter(self) -> std::slice::Iter<'static, u8> {
+        [].iter()
+    }
+}
+
+fn main() {}
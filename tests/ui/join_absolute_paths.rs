@@ -0,0 +1,18 @@
+#![warn(join_absolute_paths)]
+
+use std::path::{Path, PathBuf};
+
+fn main() {
+    let path = Path::new("/bin");
+    path.join("/sh");
+
+    let path = Path::new("C:\\Users");
+    path.join("\\user");
+
+    let path = PathBuf::from("/bin");
+    path.join("/sh");
+
+    // relative paths are fine
+    path.join("sh");
+    Path::new("/bin").join("sh/");
+}
@@ -0,0 +1,16 @@
+#![warn(large_const_arrays)]
+#![allow(dead_code)]
+
+pub const FOO: [u32; 1_000_000] = [0u32; 1_000_000];
+const BAR: [[u8; 1000]; 1000] = [[0u8; 1000]; 1000];
+pub(crate) const PTRS: [*const u8; 100_000] = [0 as *const u8; 100_000];
+const LOOKUP: [u8; 1_000_000] = [0u8; 1_000_000];
+const FIRST: u8 = LOOKUP[0];
+
+// Ok, small enough
+const SMALL: [u8; 1000] = [0u8; 1000];
+
+// Ok, already static
+static STATIC: [u32; 1_000_000] = [0u32; 1_000_000];
+
+fn main() {}
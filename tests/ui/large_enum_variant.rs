@@ -15,6 +15,7 @@ enum GenericEnumOk<T> {
     B([T; 8000]),
 }
 
+// no error, boxing one of the large variants doesn't make the enum smaller
 enum GenericEnum2<T> {
     A(i32),
     B([i32; 8000]),
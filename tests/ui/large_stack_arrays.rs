@@ -0,0 +1,24 @@
+#![warn(large_stack_arrays)]
+#![allow(dead_code, unused_variables)]
+
+static BIG: [u8; 1_000_000] = [0; 1_000_000];
+
+fn make() -> [u16; 300_000] {
+    unimplemented!()
+}
+
+fn generic<T: Default + Copy>() {
+    // Ok, the size is unknown
+    let x = [T::default(); 1_000_000];
+}
+
+fn main() {
+    let repeat = [0u32; 1_000_000];
+    let nested = [[0u8; 1000]; 1000];
+    let literal = [[0u64; 40_000], [1u64; 40_000]];
+    let call = make();
+
+    // Ok, small enough
+    let small = [0u8; 1000];
+    let also_small = [[0u8; 100]; 100];
+}
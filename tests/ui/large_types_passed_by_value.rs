@@ -0,0 +1,45 @@
+#![warn(large_types_passed_by_value)]
+#![allow(dead_code, unused_variables, needless_pass_by_value)]
+
+#[derive(Clone, Copy)]
+pub struct Large([u8; 2048]);
+
+#[derive(Clone, Copy)]
+pub struct Small([u8; 4]);
+
+#[derive(Clone)]
+pub struct LargeNotCopy([u8; 2048]);
+
+fn large_by_value(a: Large) {}
+
+fn large_array(a: [u8; 1024]) {}
+
+fn large_tuple(a: (Large, u8)) {}
+
+fn large_by_ref(a: &Large) {}
+
+fn small_by_value(a: Small) {}
+
+fn large_not_copy(a: LargeNotCopy) {}
+
+pub struct S;
+
+impl S {
+    fn method(&self, a: Large) {}
+}
+
+pub trait LargeTypeParam {
+    fn other(a: Large) {}
+}
+
+// trait implementations can't change the signature
+impl LargeTypeParam for S {
+    fn other(a: Large) {}
+}
+
+impl Large {
+    // `self` is not linted
+    fn by_self(self) {}
+}
+
+fn main() {}
@@ -1,7 +1,7 @@
 
 
 
-#![allow(unused_variables, unused_assignments, similar_names, blacklisted_name)]
+#![allow(unused_variables, unused_assignments, similar_names, blacklisted_name, needless_late_init)]
 #![warn(useless_let_if_seq)]
 
 fn f() -> bool { true }
@@ -2,7 +2,7 @@
 
 
 #![warn(needless_lifetimes, extra_unused_lifetimes)]
-#![allow(dead_code, needless_pass_by_value, trivially_copy_pass_by_ref)]
+#![allow(dead_code, needless_pass_by_value, trivially_copy_pass_by_ref, unifiable_lifetimes)]
 
 fn distinct_lifetimes<'a, 'b>(_x: &'a u8, _y: &'b u8, _z: u8) { }
 
@@ -160,5 +160,17 @@ fn test<'a>(x: &'a [u8]) -> u8 {
     *y
 }
 
+// where clauses which don't mention the elided lifetimes don't matter
+fn where_clause_static<'a, T>(x: &'a T) -> &'a T where T: 'static { x }
+
+fn where_clause_hrtb<'a, F>(x: &'a u8, _f: F) -> &'a u8 where for<'x> F: Fn(&'x u8) -> &'x u8 { x }
+
+impl<'a> Foo<'a> {
+    fn where_clause_outer_lt<'b, T>(&self, _x: &'b T) where T: 'a { }
+}
+
+// no error, the where clause bounds the lifetime
+fn where_clause_bound<'a, T>(x: &'a T) -> &'a T where T: 'a { x }
+
 fn main() {
 }
@@ -0,0 +1,39 @@
+// compile-flags: --edition 2018
+
+#![warn(needless_lifetimes)]
+#![allow(dead_code, needless_pass_by_value, trivially_copy_pass_by_ref)]
+
+struct Foo<'a>(&'a u8);
+
+fn one_used_once<'a, 'b>(x: &'a u8, _y: &'b u8) -> &'a u8 {
+    x
+}
+
+fn struct_used_once<'a, 'b>(x: &'a u8, _foo: Foo<'b>) -> &'a u8 {
+    x
+}
+
+struct X {
+    x: u8,
+}
+
+impl X {
+    fn self_and_used_once<'a>(&self, _foo: Foo<'a>) -> &u8 {
+        &self.x
+    }
+}
+
+// no error, both lifetimes are used twice
+fn used_twice<'a, 'b>(x: &'a u8, _y: &'b u8, _z: &'b u8) -> &'a u8 {
+    x
+}
+
+// no error, the where clause needs the name
+fn used_in_where<'a, 'b, T>(x: &'a u8, _y: &'b T) -> &'a u8
+where
+    T: 'b,
+{
+    x
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+// compile-flags: --edition 2018
+
+#![feature(async_await, futures_api)]
+#![warn(needless_lifetimes, extra_unused_lifetimes)]
+#![allow(dead_code, trivially_copy_pass_by_ref)]
+
+async fn in_and_out<'a>(x: &'a u8) -> &'a u8 {
+    x
+}
+
+async fn no_output<'a>(_x: &'a u8) {}
+
+async fn unused_lt<'a>(_x: u8) {}
+
+// no error, `async fn` doesn't support multiple elided lifetimes
+async fn distinct<'a, 'b>(_x: &'a u8, _y: &'b u8) {}
+
+// no error, multiple input refs
+async fn multiple_in_and_out<'a>(x: &'a u8, _y: &'a u8) -> &'a u8 {
+    x
+}
+
+fn main() {}
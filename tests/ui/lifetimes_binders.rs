@@ -0,0 +1,20 @@
+#![warn(needless_lifetimes)]
+#![allow(dead_code, needless_pass_by_value)]
+
+type Pointer = for<'a> fn(&'a u8) -> &'a u8;
+
+type Distinct = for<'a, 'b> fn(&'a u8, &'b u8);
+
+fn bound<F: for<'a> Fn(&'a u8) -> &'a u8>(_f: F) {}
+
+fn boxed(_f: Box<dyn for<'a> Fn(&'a u8)>) {}
+
+// no error, multiple input refs
+type Multiple = for<'a> fn(&'a u8, &'a u8) -> &'a u8;
+
+// no error, two input lifetimes
+type Elided = for<'a> fn(&'a u8, &u8) -> &'a u8;
+
+fn main() {
+    let _f: for<'a> fn(&'a u8) = |_| ();
+}
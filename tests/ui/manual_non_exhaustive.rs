@@ -0,0 +1,104 @@
+#![feature(non_exhaustive)]
+#![warn(manual_non_exhaustive)]
+#![allow(dead_code, unused)]
+
+pub mod enums {
+    // Not exported, so `#[non_exhaustive]` would change nothing.
+    enum NotExported {
+        A,
+        #[doc(hidden)]
+        _B,
+    }
+
+    pub enum E {
+        A,
+        B,
+        #[doc(hidden)]
+        _C,
+    }
+
+    // user forgot to remove the marker
+    #[non_exhaustive]
+    pub enum Ep {
+        A,
+        B,
+        #[doc(hidden)]
+        _C,
+    }
+
+    // marker variant does not have doc hidden attribute, should be ignored
+    pub enum NoDocHidden {
+        A,
+        B,
+        _C,
+    }
+
+    // marker variant is not unit, should be ignored
+    pub enum NotUnit {
+        A,
+        B,
+        #[doc(hidden)]
+        _C(bool),
+    }
+
+    // multiple marker variants, should be ignored
+    pub enum MultipleMarkers {
+        A,
+        #[doc(hidden)]
+        _B,
+        #[doc(hidden)]
+        _C,
+    }
+
+    // only one variant, should be ignored
+    pub enum OnlyMarker {
+        #[doc(hidden)]
+        _A,
+    }
+}
+
+pub mod structs {
+    pub struct S {
+        pub a: i32,
+        pub b: i32,
+        _c: (),
+    }
+
+    pub struct T(pub i32, pub i32, ());
+
+    // user forgot to remove the private field
+    #[non_exhaustive]
+    pub struct Sp {
+        pub a: i32,
+        pub b: i32,
+        _c: (),
+    }
+
+    // some other fields are private, should be ignored
+    pub struct PrivateFields {
+        a: i32,
+        pub b: i32,
+        _c: (),
+    }
+
+    // private field name does not start with underscore, should be ignored
+    pub struct NoUnderscore {
+        pub a: i32,
+        pub b: i32,
+        c: (),
+    }
+
+    // private field is not unit type, should be ignored
+    pub struct NotUnit {
+        pub a: i32,
+        pub b: i32,
+        _c: i32,
+    }
+
+    // private field is the only field, should be ignored
+    pub struct OnlyMarker {
+        _a: (),
+    }
+}
+
+fn main() {}
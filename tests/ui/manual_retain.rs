@@ -0,0 +1,37 @@
+#![warn(manual_retain)]
+#![allow(unused)]
+
+use std::collections::{HashSet, VecDeque};
+
+fn vec() {
+    let mut vec = vec![0, 1, 2];
+    vec = vec.into_iter().filter(|x| x % 2 == 0).collect();
+    vec = vec.iter().cloned().filter(|x| x % 2 == 0).collect();
+
+    // Do not lint: different binding
+    let other = vec![0, 1, 2];
+    vec = other.into_iter().filter(|x| x % 2 == 0).collect();
+
+    // Do not lint: collected into another collection
+    let mut set: HashSet<i32> = HashSet::new();
+    set = vec.into_iter().filter(|x| x % 2 == 0).collect();
+
+    // Do not lint: not a plain filter
+    let mut vec = vec![0, 1, 2];
+    vec = vec.into_iter().map(|x| x + 1).filter(|x| x % 2 == 0).collect();
+    vec = vec.iter().filter(|&x| x % 2 == 0).cloned().collect();
+}
+
+fn vec_deque() {
+    let mut deque: VecDeque<i32> = VecDeque::new();
+    deque = deque.into_iter().filter(|&x| x > 1).collect();
+    deque = deque.iter().cloned().filter(|&x| x > 1).collect();
+}
+
+fn hash_set() {
+    let mut set: HashSet<i32> = HashSet::new();
+    set = set.into_iter().filter(|x| x % 2 == 0).collect();
+    set = set.iter().cloned().filter(|x| x % 2 == 0).collect();
+}
+
+fn main() {}
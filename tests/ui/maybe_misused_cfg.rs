@@ -0,0 +1,19 @@
+#![warn(maybe_misused_cfg)]
+#![allow(dead_code)]
+
+#[cfg(not(features = "not-really-a-feature"))]
+fn features() {}
+
+#[cfg(any(feature = "right", not(features = "wrong")))]
+fn nested_features() {}
+
+#[cfg(not(test = "true"))]
+fn test_value() {}
+
+#[cfg(not(feature = "right"))]
+fn ok_feature() {}
+
+#[cfg(not(test))]
+fn ok_test() {}
+
+fn main() {}
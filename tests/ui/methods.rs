@@ -4,7 +4,7 @@
 #![warn(clippy, clippy_pedantic, option_unwrap_used)]
 #![allow(blacklisted_name, unused, print_stdout, non_ascii_literal, new_without_default,
     new_without_default_derive, missing_docs_in_private_items, needless_pass_by_value,
-    default_trait_access)]
+    default_trait_access, unused_self, return_self_not_must_use, semicolon_if_nothing_returned)]
 
 use std::collections::BTreeMap;
 use std::collections::HashMap;
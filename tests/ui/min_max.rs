@@ -2,7 +2,7 @@
 
 
 #![warn(clippy)]
-
+#![allow(needless_late_init)]
 use std::cmp::{min, max};
 use std::cmp::min as my_min;
 use std::cmp::max as my_max;
@@ -0,0 +1,24 @@
+#![warn(mismatching_type_param_order)]
+#![allow(dead_code)]
+
+struct Foo<A, B> {
+    x: A,
+    y: B,
+}
+
+// lint on both params
+impl<B, A> Foo<B, A> {}
+
+// lint on the `A` only
+impl<C, A> Foo<C, A> {}
+
+// Ok, names match
+impl<A, B> Foo<A, B> {}
+
+// Ok, names don't conflict
+impl<C, D> Foo<C, D> {}
+
+// Ok, concrete types
+impl Foo<u32, u8> {}
+
+fn main() {}
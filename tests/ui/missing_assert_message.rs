@@ -0,0 +1,40 @@
+#![warn(missing_assert_message)]
+#![allow(dead_code)]
+
+// Should trigger warning
+fn asserts_without_message(a: i32, b: i32) {
+    assert!(a > b);
+    assert_eq!(a, b);
+    assert_ne!(a, b);
+    debug_assert!(a > b);
+    debug_assert_eq!(a, b);
+    assert!(
+        (a, b) == (1, 2)
+    );
+}
+
+// Should not trigger warning
+fn asserts_with_message(a: i32, b: i32, c: char) {
+    assert!(a > b, "a should be greater than b");
+    assert!(a > b, "{} should be greater than {}", a, b);
+    assert_eq!(a, b, "a should be equal to b");
+    assert_ne!(a, b, "a should not be equal to b");
+    debug_assert_ne!(c, ',', "commas are not allowed");
+}
+
+// Should not trigger warning
+#[test]
+fn test_fn() {
+    assert!(true);
+}
+
+// Should not trigger warning
+#[cfg(test)]
+mod tests {
+    fn asserts_without_message() {
+        assert!(true);
+        assert_eq!(1, 1);
+    }
+}
+
+fn main() {}
@@ -0,0 +1,62 @@
+#![feature(const_fn)]
+#![warn(missing_const_for_fn)]
+#![allow(dead_code, derivable_impls)]
+
+struct Game {
+    guess: i32,
+}
+
+impl Game {
+    // Could be const
+    pub fn new() -> Self { Game { guess: 42 } }
+
+    // Could be const
+    fn guess(&self) -> i32 { self.guess }
+
+    // Already const
+    const fn answer() -> i32 { 42 }
+
+    // Mutable references are not allowed in const fn
+    fn set_guess(&mut self, guess: i32) { self.guess = guess; }
+}
+
+// Could be const
+fn one() -> i32 { 1 }
+
+// Could not be const until `one` is made const
+fn two() -> i32 { one() + one() }
+
+// Could be const
+fn string() -> &'static str { "Hello" }
+
+// Calls a const fn, could be const too
+fn forty_two() -> i32 { Game::answer() }
+
+// Could not be const, `String::new` is not a `const fn` yet
+fn owned_string() -> String { String::new() }
+
+// Could not be const, branching is not allowed in const fn
+fn max(a: u32, b: u32) -> u32 { if a > b { a } else { b } }
+
+// Could not be const, floating point arithmetic is not allowed in const fn
+fn half(x: f32) -> f32 { x / 2.0 }
+
+// Could not be const, local bindings are not allowed in const fn
+fn sum(a: u8, b: u8) -> u8 { let s = a + b; s }
+
+// Could not be const, trait bounds are not allowed in const fn
+fn generic<T: Clone>(t: &T) -> &T { t }
+
+// Could not be const, `Vec` has a destructor
+fn get_len(v: Vec<u8>) -> usize { v.len() }
+
+// Could not be const, unsafe functions are out of scope
+unsafe fn unchecked() -> i32 { 1 }
+
+// Could not be const, trait methods can't be const
+impl Default for Game {
+    fn default() -> Self { Game { guess: 0 } }
+}
+
+// Could not be const, `main` is special
+fn main() {}
@@ -0,0 +1,71 @@
+#![warn(missing_panics_doc)]
+#![allow(dead_code, missing_errors_doc)]
+
+/// This needs to be documented
+pub fn unwrap() {
+    let result = Err("Hi");
+    result.unwrap()
+}
+
+/// This needs to be documented
+pub fn panics() {
+    match Some(42) {
+        Some(x) => println!("{}", x),
+        None => panic!("No value"),
+    }
+}
+
+/// This needs to be documented
+pub fn asserts(x: u32) {
+    assert!(x > 0);
+}
+
+/// This needs to be documented
+pub fn index(v: &[u32]) -> u32 {
+    v[0]
+}
+
+/// This is documented
+///
+/// # Panics
+///
+/// Panics if `result` is an error
+pub fn unwrap_documented() {
+    let result = Err("Hi");
+    result.unwrap()
+}
+
+/// This is okay because it is private
+fn unwrap_private() {
+    let result = Err("Hi");
+    result.unwrap()
+}
+
+/// This is okay because unreachable
+pub fn unreachable() {
+    unreachable!("This function panics")
+}
+
+/// This is okay because it only asserts in debug builds
+pub fn debug_asserts(x: u32) {
+    debug_assert!(x > 0);
+    debug_assert_eq!(x, 1);
+}
+
+pub struct Struct;
+
+impl Struct {
+    /// This needs to be documented
+    pub fn expect(&self) -> u32 {
+        Some(42).expect("always some")
+    }
+
+    /// This is okay because nested items are checked on their own
+    pub fn nested(&self) {
+        fn inner() {
+            panic!("not our problem")
+        }
+    }
+}
+
+fn main() {}
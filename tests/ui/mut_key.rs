@@ -0,0 +1,33 @@
+#![warn(mutable_key_type)]
+#![allow(dead_code, unused_variables)]
+
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::rc::Rc;
+use std::sync::atomic::AtomicUsize;
+
+struct Key(AtomicUsize);
+
+fn should_not_take_this_arg(m: &mut HashMap<Key, usize>, _n: usize) -> HashSet<Key> {
+    unimplemented!()
+}
+
+fn this_is_ok(m: &mut HashMap<usize, Key>) {}
+
+fn nested(m: BTreeMap<(u8, Rc<Cell<u8>>), ()>, s: HashSet<&[Cell<u8>]>) {}
+
+fn generic<T>(m: HashMap<(T, Cell<u8>), ()>, ok: HashMap<Vec<T>, ()>) {}
+
+fn main() {
+    let _: HashSet<Key>;
+    let _: HashMap<Box<Key>, usize>;
+    let _: HashMap<String, Cell<u8>>;
+    let _: HashMap<Vec<Cell<u8>>, ()>;
+    let _: HashSet<Box<RefCell<u8>>>;
+
+    // Ok, pointers don't make the hash or order of the key change
+    let _: HashSet<Rc<usize>>;
+    let _: HashSet<WithPointers>;
+}
+
+struct WithPointers(*const u8, Rc<u8>, std::sync::Arc<u8>);
@@ -0,0 +1,37 @@
+#![warn(needless_collect)]
+
+use std::collections::{HashSet, VecDeque};
+
+fn main() {
+    let sample = [1; 5];
+    let len = sample.iter().collect::<Vec<_>>().len();
+    if sample.iter().collect::<Vec<_>>().is_empty() {
+        // Empty
+    }
+    sample.iter().cloned().collect::<Vec<_>>().contains(&1);
+    sample.iter().map(|x| x + 1).collect::<VecDeque<_>>().into_iter().for_each(drop);
+
+    let v: Vec<_> = sample.iter().collect();
+    println!("{}", v.len());
+
+    let v: Vec<i32> = sample.iter().cloned().collect();
+    for x in v {
+        println!("{} {}", x, len);
+    }
+
+    // Sets remove duplicates, `len()` is not `count()`
+    let set: HashSet<_> = sample.iter().collect();
+    println!("{}", set.len());
+
+    // Used more than once
+    let v: Vec<_> = sample.iter().collect();
+    println!("{} {}", v.len(), v.is_empty());
+
+    // Used in a way the iterator can't replace
+    let v: Vec<_> = sample.iter().collect();
+    println!("{:?}", v);
+
+    // The closure argument must not shadow the needle
+    let x = 1;
+    sample.iter().cloned().collect::<Vec<_>>().contains(&x);
+}
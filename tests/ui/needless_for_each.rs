@@ -0,0 +1,50 @@
+#![warn(needless_for_each)]
+#![allow(unused)]
+
+fn make_vec() -> Vec<i32> {
+    vec![1, 2, 3]
+}
+
+fn should_lint() {
+    let v = vec![1, 2, 3];
+    let mut acc = 0;
+    v.iter().for_each(|elem| { acc += elem; });
+    v.into_iter().for_each(|elem| { acc += elem; });
+
+    [1, 2, 3].iter().for_each(|elem| { acc += elem; });
+    make_vec().into_iter().for_each(|elem| { acc += elem; });
+
+    let v = vec![1, 2, 3];
+    v.iter().for_each(|elem| {
+        acc += elem;
+    });
+
+    // `return` is replaced with `continue`
+    v.iter().for_each(|elem| { if *elem == 2 { return; } acc += elem; });
+}
+
+fn should_not_lint() {
+    let v = vec![1, 2, 3];
+    let mut acc = 0;
+
+    // `for_each` after an adapter.
+    v.iter().map(|elem| elem * 2).for_each(|elem| { acc += elem; });
+
+    // The closure body is not a block.
+    v.iter().for_each(|elem| acc += elem);
+
+    // `return` inside a loop can't be replaced with `continue`.
+    v.iter().for_each(|elem| {
+        for i in 0..*elem {
+            if i == 2 {
+                return;
+            }
+        }
+        acc += elem;
+    });
+
+    // `for_each` is the tail expression of the block.
+    v.iter().for_each(|elem| { acc += elem; })
+}
+
+fn main() {}
@@ -0,0 +1,68 @@
+#![warn(needless_late_init)]
+#![allow(unused_assignments, unused_variables, useless_let_if_seq)]
+
+fn cond() -> bool {
+    true
+}
+
+fn main() {
+    let a;
+    a = "zero";
+
+    let b;
+    let c;
+    b = 1;
+    c = 2;
+
+    let d: usize;
+    d = 1;
+
+    let mut e;
+    e = 1;
+    e += 1;
+
+    let f;
+    match 1 {
+        1 => f = "one",
+        _ => f = "two",
+    }
+
+    let g: usize;
+    if cond() {
+        g = 5;
+    } else {
+        g = 6;
+    }
+
+    let h;
+    if let Some(x) = Some(1) {
+        h = x;
+    } else {
+        h = 0;
+    };
+
+    // no lint: not every branch assigns
+    let k;
+    if cond() {
+        k = 1;
+    } else {
+        return;
+    }
+
+    // no lint: the assignment is not the last statement
+    let l: usize;
+    let m = &mut 0;
+    if cond() {
+        l = 1;
+        *m = l;
+    } else {
+        l = 2;
+    }
+
+    // no lint: assigned in a loop
+    let n;
+    loop {
+        n = 1;
+        break;
+    }
+}
@@ -1,7 +1,7 @@
 #![feature(const_fn)]
 
 
-#![allow(dead_code)]
+#![allow(dead_code, extra_unused_lifetimes)]
 #![warn(new_without_default, new_without_default_derive)]
 
 pub struct Foo;
@@ -0,0 +1,65 @@
+#![warn(non_canonical_clone_impl)]
+#![allow(dead_code)]
+
+#[derive(Copy)]
+struct A(u32);
+
+impl Clone for A {
+    fn clone(&self) -> Self {
+        A(self.0)
+    }
+}
+
+struct B(u32);
+
+impl Clone for B {
+    fn clone(&self) -> Self {
+        B(self.0)
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        *self = source.clone();
+    }
+}
+
+// Ok, canonical
+#[derive(Copy)]
+struct C(u32);
+
+impl Clone for C {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+// Ok, not `Copy`
+struct D(Vec<u32>);
+
+impl Clone for D {
+    fn clone(&self) -> Self {
+        D(self.0.clone())
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.0.clone_from(&source.0);
+    }
+}
+
+// Ok, derived
+#[derive(Clone, Copy)]
+struct E(u32);
+
+// Ok, a user-defined `Clone` trait
+mod user_defined {
+    pub trait Clone {
+        fn clone(&self) -> Self;
+    }
+
+    impl Clone for super::E {
+        fn clone(&self) -> Self {
+            super::E(self.0)
+        }
+    }
+}
+
+fn main() {}
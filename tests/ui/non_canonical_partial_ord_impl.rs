@@ -0,0 +1,110 @@
+#![warn(non_canonical_partial_ord_impl)]
+#![allow(dead_code)]
+
+use std::cmp::Ordering;
+
+#[derive(Eq, PartialEq)]
+struct A(u32);
+
+impl Ord for A {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl PartialOrd for A {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+#[derive(Eq, PartialEq)]
+struct B(u32);
+
+impl Ord for B {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl PartialOrd for B {
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        Some(self.0.cmp(&rhs.0))
+    }
+}
+
+// Ok, canonical
+#[derive(Eq, PartialEq)]
+struct C(u32);
+
+impl Ord for C {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl PartialOrd for C {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Ok, canonical with a fully qualified call
+#[derive(Eq, PartialEq)]
+struct D(u32);
+
+impl Ord for D {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl PartialOrd for D {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(Ord::cmp(self, other))
+    }
+}
+
+// Ok, not `Ord`
+#[derive(PartialEq)]
+struct E(f32);
+
+impl PartialOrd for E {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+// Ok, comparison with another type
+impl PartialOrd<u32> for A {
+    fn partial_cmp(&self, other: &u32) -> Option<Ordering> {
+        self.0.partial_cmp(other)
+    }
+}
+
+impl PartialEq<u32> for A {
+    fn eq(&self, other: &u32) -> bool {
+        self.0 == *other
+    }
+}
+
+// Ok, derived
+#[derive(Eq, PartialEq, Ord, PartialOrd)]
+struct F(u32);
+
+// Ok, a user-defined `PartialOrd` trait
+mod user_defined {
+    use std::cmp::Ordering;
+
+    pub trait PartialOrd {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering>;
+    }
+
+    impl PartialOrd for super::A {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            self.0.partial_cmp(&other.0)
+        }
+    }
+}
+
+fn main() {}
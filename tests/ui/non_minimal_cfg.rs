@@ -0,0 +1,19 @@
+#![warn(non_minimal_cfg)]
+#![allow(dead_code)]
+
+#[cfg(all(not(foo)))]
+fn all() {}
+
+#[cfg(any(not(foo)))]
+fn any() {}
+
+#[cfg(all(not(foo), any(not(bar))))]
+fn nested() {}
+
+#[cfg(all(not(foo), not(bar)))]
+fn several() {}
+
+#[cfg(any(foo, not(bar)))]
+fn alternatives() {}
+
+fn main() {}
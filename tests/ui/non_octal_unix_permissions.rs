@@ -0,0 +1,34 @@
+// ignore-windows
+
+#![warn(non_octal_unix_permissions)]
+
+use std::fs::{DirBuilder, File, OpenOptions, Permissions};
+use std::os::unix::fs::{DirBuilderExt, OpenOptionsExt, PermissionsExt};
+
+fn main() {
+    let permissions = 0o760;
+
+    // OpenOptionsExt::mode
+    let _file = OpenOptions::new().mode(440).open("foo");
+    let _file = OpenOptions::new().mode(0o440).open("foo");
+    let _file = OpenOptions::new().mode(permissions).open("foo");
+
+    // PermissionsExt::from_mode
+    let _permissions = Permissions::from_mode(647);
+    let _permissions = Permissions::from_mode(0o647);
+    let _permissions = Permissions::from_mode(permissions);
+
+    // PermissionsExt::set_mode
+    let f = File::create("foo").unwrap();
+    let mut permissions = f.metadata().unwrap().permissions();
+    permissions.set_mode(644);
+    permissions.set_mode(0o704);
+
+    // DirBuilderExt::mode
+    let mut builder = DirBuilder::new();
+    builder.mode(755);
+    builder.mode(0o406);
+
+    // not an octal value anyway
+    builder.mode(493);
+}
@@ -0,0 +1,42 @@
+#![warn(non_send_fields_in_send_ty)]
+#![allow(dead_code)]
+
+use std::cell::UnsafeCell;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+pub struct RingBuffer<T> {
+    data: Vec<UnsafeCell<T>>,
+    capacity: usize,
+}
+
+unsafe impl<T> Send for RingBuffer<T> {}
+
+pub struct ContainsRc {
+    rc: Rc<String>,
+    ptr: *mut u8,
+}
+
+unsafe impl Send for ContainsRc {}
+
+pub struct Unbounded<T> {
+    inner: T,
+}
+
+unsafe impl<T> Send for Unbounded<T> {}
+
+// all fields are `Send`
+pub struct AllSend<T> {
+    shared: Arc<Mutex<T>>,
+    count: usize,
+}
+
+unsafe impl<T: Send> Send for AllSend<T> {}
+
+pub struct Bounded<T> {
+    inner: T,
+}
+
+unsafe impl<T: Send> Send for Bounded<T> {}
+
+fn main() {}
@@ -0,0 +1,21 @@
+#![warn(panic)]
+#![allow(panic_params)]
+
+fn panic() {
+    let a = 2;
+    panic!();
+    panic!("message");
+    panic!("{} {}", "panic with", "multiple arguments");
+    let b = a + 2;
+}
+
+// no error, the `panic!` is called by other macros
+fn other_macros() {
+    assert!(true);
+    unimplemented!();
+}
+
+fn main() {
+    panic();
+    other_macros();
+}
@@ -1,7 +1,7 @@
 
 
 
-#![warn(panic_params, unimplemented)]
+#![warn(panic_params, unimplemented, todo, unreachable)]
 
 fn missing() {
     if true {
@@ -59,6 +59,26 @@ fn unimplemented() {
     let b = a + 2;
 }
 
+macro_rules! todo {
+    () => {
+        panic!("not yet implemented")
+    };
+}
+
+fn todo() {
+    let a = 2;
+    todo!();
+    let b = a + 2;
+}
+
+fn unreachable() {
+    let a = 2;
+    unreachable!();
+    unreachable!("message");
+    unreachable!("{} {}", "panic with", "multiple arguments");
+    let b = a + 2;
+}
+
 fn main() {
     missing();
     ok_single();
@@ -68,4 +88,6 @@ fn main() {
     ok_nomsg();
     ok_escaped();
     unimplemented();
+    todo();
+    unreachable();
 }
@@ -0,0 +1,11 @@
+#![warn(path_buf_push_overwrite)]
+
+use std::path::PathBuf;
+
+fn main() {
+    let mut x = PathBuf::from("/foo");
+    x.push("/bar");
+
+    let mut y = PathBuf::from("/foo");
+    y.push("bar");
+}
@@ -0,0 +1,18 @@
+#![warn(pub_underscore_fields)]
+#![allow(dead_code)]
+
+pub struct FieldNamedStruct {
+    _a: u8,
+    pub b: u8,
+    pub _c: i64,
+    _e: bool,
+    pub(crate) _f: Option<u8>,
+}
+
+pub struct TupleStruct(pub u8, u16);
+
+struct PrivateStruct {
+    pub _a: u8,
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+#![warn(pub_use)]
+#![allow(unused_imports)]
+
+pub mod outer {
+    mod inner {
+        pub struct Test {}
+    }
+    // should be linted
+    pub use self::inner::Test;
+}
+
+// should not be linted
+use std::fmt;
+
+pub mod prelude {
+    // should not be linted, `prelude` is allowed by default
+    pub use outer::Test;
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+#![warn(rc_buffer)]
+#![allow(dead_code)]
+
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::sync::Arc;
+
+struct S {
+    a: Rc<String>,
+    b: Rc<PathBuf>,
+    c: Rc<Vec<u8>>,
+    d: Rc<OsString>,
+    // Ok, not a buffer
+    e: Rc<u8>,
+}
+
+fn func_rc(_: Rc<String>) -> Arc<Vec<u8>> {
+    unimplemented!()
+}
+
+// Ok, already unsized
+fn func_str(_: Rc<str>, _: Arc<[u8]>) {}
+
+fn main() {}
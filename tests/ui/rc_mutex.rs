@@ -0,0 +1,22 @@
+#![warn(rc_mutex)]
+#![allow(dead_code, unused_variables)]
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+struct Foo {
+    foo: Rc<Mutex<i32>>,
+}
+
+fn test1(foo: Rc<Mutex<u8>>) {}
+
+fn test2(foo: Vec<Rc<Mutex<u8>>>) {}
+
+// Ok, single threaded
+fn test3(foo: Rc<RefCell<u8>>) {}
+
+// Ok, multi threaded
+fn test4(foo: Arc<Mutex<u8>>) {}
+
+fn main() {}
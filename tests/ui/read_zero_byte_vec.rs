@@ -0,0 +1,51 @@
+#![warn(read_zero_byte_vec)]
+#![allow(dead_code, unused_io_amount)]
+
+use std::fs::File;
+use std::io;
+use std::io::prelude::*;
+
+fn test() -> io::Result<()> {
+    let cap = 1000;
+    let mut f = File::open("foo.txt").unwrap();
+
+    // should lint
+    let mut data = Vec::with_capacity(20);
+    f.read_exact(&mut data).unwrap();
+
+    // should lint
+    let mut data2 = Vec::with_capacity(cap);
+    f.read_exact(&mut data2)?;
+
+    // should lint
+    let mut data3 = Vec::new();
+    f.read_exact(&mut data3)?;
+
+    // should lint
+    let mut data4 = vec![];
+    let _ = f.read(&mut data4)?;
+
+    // should lint
+    let mut data5 = Vec::new();
+    let _ = {
+        f.read(&mut data5).unwrap()
+    };
+
+    // should not lint
+    let mut buf = [0u8; 100];
+    f.read(&mut buf)?;
+
+    // should not lint
+    let mut data6 = Vec::with_capacity(100);
+    data6.resize(100, 0);
+    f.read(&mut data6)?;
+
+    // should not lint
+    let mut data7: Vec<u8> = Vec::new();
+    data7.extend_from_slice(&[1, 2, 3]);
+    f.read_exact(&mut data7)?;
+
+    Ok(())
+}
+
+fn main() {}
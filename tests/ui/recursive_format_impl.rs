@@ -0,0 +1,45 @@
+#![warn(recursive_format_impl)]
+#![allow(dead_code)]
+
+use std::fmt;
+
+struct A;
+
+impl fmt::Display for A {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+struct B;
+
+impl fmt::Debug for B {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "B({:?})", &self)
+    }
+}
+
+struct C;
+
+impl fmt::Display for C {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", *self)
+    }
+}
+
+// Debug formatting in a `Display` impl is fine
+struct D(i32);
+
+impl fmt::Debug for D {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "D({})", self.0)
+    }
+}
+
+impl fmt::Display for D {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+fn main() {}
@@ -0,0 +1,45 @@
+#![warn(redundant_allocation)]
+#![allow(dead_code, unused_variables)]
+
+use std::rc::Rc;
+use std::sync::Arc;
+
+pub struct MyStruct;
+
+pub struct SubT<T> {
+    foo: T,
+}
+
+pub enum MyEnum {
+    One,
+    Two,
+}
+
+struct Foo {
+    foo: Box<Box<MyStruct>>,
+    bar: Rc<&'static MyStruct>,
+}
+
+fn box_box(foo: Box<Box<MyStruct>>) {}
+
+fn box_ref(foo: Box<&MyStruct>) {}
+
+fn rc_box(foo: Rc<Box<MyEnum>>) {}
+
+fn arc_rc(foo: Arc<Rc<SubT<usize>>>) {}
+
+fn nested(foo: Vec<Box<Rc<u8>>>) {}
+
+// Ok, the inner pointer is fat
+fn box_box_trait(foo: Box<Box<dyn Fn()>>) {}
+
+fn rc_box_slice(foo: Rc<Box<[u8]>>) {}
+
+fn arc_box_str(foo: Arc<Box<str>>) {}
+
+// Ok, no redundant allocation
+fn rc_vec(foo: Rc<Vec<u8>>) {}
+
+fn rc_struct(foo: Rc<MyStruct>) {}
+
+fn main() {}
@@ -0,0 +1,32 @@
+#![warn(redundant_clone)]
+
+use std::path::Path;
+
+fn main() {
+    let _ = ["lorem", "ipsum"].join(" ").to_string();
+
+    let s = String::from("foo");
+    let _ = s.clone();
+
+    let s = String::from("foo");
+    let _ = s.to_string();
+
+    let s = String::from("foo");
+    let _ = s.to_owned();
+
+    let _ = Path::new("/a/b/").join("c").to_owned();
+
+    let s = String::from("foo");
+    let t = s.clone();
+    println!("{} {}", s, t);
+
+    let s = String::from("foo");
+    let r = &s;
+    let _ = s.clone();
+    println!("{}", r);
+
+    let v = vec![1, 2, 3];
+    for _ in 0..2 {
+        let _ = v.clone();
+    }
+}
@@ -0,0 +1,77 @@
+#![warn(redundant_else)]
+#![allow(dead_code, unused_variables)]
+
+fn main() {
+    loop {
+        // break
+        if foo() {
+            println!("Love your neighbor;");
+            break;
+        } else {
+            println!("yet don't pull down your hedge.");
+        }
+        // continue
+        if foo() {
+            println!("He that lies down with Dogs,");
+            continue;
+        } else {
+            println!("shall rise up with fleas.");
+        }
+        // match block
+        if foo() {
+            match 1u8 {
+                1 => break,
+                _ => return,
+            }
+        } else {
+            println!("You may delay, but time will not.");
+        }
+    }
+    // else if
+    if foo() {
+        return;
+    } else if foo() {
+        return;
+    } else {
+        println!("A fat kitchen makes a lean will.");
+    }
+    // let binding outside of block
+    let _ = {
+        if foo() {
+            return;
+        } else {
+            1
+        }
+    };
+    // panic in the then branch
+    if foo() {
+        panic!();
+    } else {
+        println!("Little strokes fell great oaks.");
+    }
+
+    // ok, no divergence in the then branch
+    if foo() {
+        println!("Haste makes waste.");
+    } else {
+        println!("Well done is better than well said.");
+    }
+    // ok, the else if branch doesn't diverge
+    if foo() {
+        return;
+    } else if foo() {
+        println!("Fish and visitors stink after three days.");
+    } else {
+        return;
+    }
+    // ok, the `if` is used as a value
+    let _ = if foo() {
+        return;
+    } else {
+        1
+    };
+}
+
+fn foo() -> bool {
+    unimplemented!()
+}
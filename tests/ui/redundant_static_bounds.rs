@@ -0,0 +1,32 @@
+#![warn(redundant_static_bounds)]
+#![allow(dead_code, needless_pass_by_value)]
+
+use std::any::Any;
+use std::fmt::Debug;
+
+fn boxed(_f: Box<dyn Fn() + 'static>) {}
+
+fn boxed_bare(_f: Box<Debug + Send + 'static>) {}
+
+struct Callback {
+    f: Box<dyn Fn(u8) -> u8 + 'static>,
+}
+
+fn generic<T: Any + 'static>(_x: T) {}
+
+fn where_clause<T>(_x: T)
+where
+    T: 'static + Any + Debug,
+{
+}
+
+// no error, the default lifetime would be the one of the reference
+fn borrowed(_f: &(dyn Fn() + 'static)) {}
+
+// no error, `Debug` doesn't imply `'static`
+fn not_implied<T: Debug + 'static>(_x: T) {}
+
+fn main() {
+    // no error, the lifetime would be inferred here
+    let _f: Box<dyn Fn() + 'static> = Box::new(|| ());
+}
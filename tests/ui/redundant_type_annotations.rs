@@ -0,0 +1,52 @@
+#![warn(redundant_type_annotations)]
+#![allow(dead_code, unused_variables)]
+
+struct Cake;
+
+impl Cake {
+    fn new() -> Self {
+        Cake
+    }
+
+    fn slices(&self) -> u8 {
+        8
+    }
+}
+
+struct Pie<T> {
+    filling: T,
+}
+
+impl<T: Default> Pie<T> {
+    fn new() -> Self {
+        Pie { filling: T::default() }
+    }
+}
+
+fn count() -> usize {
+    1
+}
+
+fn main() {
+    let cake = Cake::new();
+
+    // Should lint
+    let s: String = String::new();
+    let c: Cake = Cake::new();
+    let n: usize = count();
+    let slices: u8 = cake.slices();
+    let a: u32 = 5u32;
+    let f: f64 = 1.5f64;
+    let b: bool = true;
+    let ch: char = 'x';
+    let st: &str = "hello";
+
+    // Shouldn't lint
+    let v: Vec<u8> = Vec::new();
+    let p: Pie<u8> = Pie::new();
+    let x: u64 = 5;
+    let y: f32 = 1.5;
+    let slice: &[u8] = &[1, 2, 3];
+    let d: String = Default::default();
+    let inferred = String::new();
+}
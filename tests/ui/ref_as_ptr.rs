@@ -0,0 +1,16 @@
+#![warn(ref_as_ptr)]
+#![allow(unused_variables)]
+
+fn main() {
+    let a = 0u32;
+    let _ = &a as *const u32;
+    let _ = &a as *const _;
+
+    let mut b = 0u32;
+    let _ = &mut b as *mut u32;
+    let _ = &mut b as *const u32;
+
+    // changes the type, not only the kind of pointer
+    let array = [1u8, 2, 3];
+    let _ = &array as *const u8;
+}
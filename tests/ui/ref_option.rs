@@ -0,0 +1,38 @@
+#![warn(ref_option)]
+#![allow(dead_code, unused_variables)]
+
+fn opt_u8(a: &Option<u8>) {}
+
+fn opt_gen<T>(a: &Option<T>) {}
+
+fn opt_string(a: &std::option::Option<String>) {}
+
+fn mut_u8(a: &mut Option<u8>) {}
+
+fn ref_u8(a: Option<&u8>) {}
+
+pub struct S;
+
+impl S {
+    fn method(&self, a: &Option<Vec<u8>>) {}
+}
+
+pub trait HasOpt {
+    fn trait_opt(&self, a: &Option<u8>);
+}
+
+impl HasOpt for S {
+    fn trait_opt(&self, a: &Option<u8>) {}
+}
+
+#[allow(ref_option)]
+fn allowed(a: &Option<u8>) {}
+
+fn main() {
+    let x = Some(1u8);
+    opt_u8(&x);
+    opt_u8(&Some(2));
+    let r = &x;
+    opt_u8(r);
+    S.method(&None);
+}
@@ -0,0 +1,58 @@
+#![warn(return_self_not_must_use)]
+#![allow(dead_code)]
+
+pub struct Builder {
+    size: usize,
+}
+
+impl Builder {
+    pub fn new() -> Self {
+        Builder { size: 0 }
+    }
+
+    pub fn size(mut self, size: usize) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn double(self) -> Builder {
+        Builder { size: self.size * 2 }
+    }
+
+    #[must_use]
+    pub fn annotated(self) -> Self {
+        self
+    }
+
+    // not builder-style, doesn't take `self`
+    pub fn with_size(size: usize) -> Self {
+        Builder { size }
+    }
+
+    // doesn't return `Self`
+    pub fn build(self) -> usize {
+        self.size
+    }
+
+    // not part of the public API
+    fn private(self) -> Self {
+        self
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Builder::new()
+    }
+}
+
+#[must_use]
+pub struct MustUseBuilder;
+
+impl MustUseBuilder {
+    pub fn step(self) -> Self {
+        self
+    }
+}
+
+fn main() {}
@@ -0,0 +1,37 @@
+#![warn(same_name_method)]
+#![allow(dead_code)]
+
+trait T1 {
+    fn foo() {}
+}
+
+trait T2 {
+    fn bar(&self);
+}
+
+trait T3 {
+    fn baz(&self);
+}
+
+struct S;
+
+impl S {
+    fn foo() {}
+
+    fn bar(&self) {}
+
+    fn qux(&self) {}
+}
+
+impl T1 for S {}
+
+impl T2 for S {
+    fn bar(&self) {}
+}
+
+// Ok, no name in common
+impl T3 for S {
+    fn baz(&self) {}
+}
+
+fn main() {}
@@ -2,7 +2,7 @@
 
 
 #![warn(clippy, clippy_pedantic, shadow_same, shadow_reuse, shadow_unrelated)]
-#![allow(unused_parens, unused_variables, missing_docs_in_private_items)]
+#![allow(unused_parens, unused_variables, missing_docs_in_private_items, semicolon_if_nothing_returned, needless_late_init)]
 
 fn id<T>(x: T) -> T { x }
 
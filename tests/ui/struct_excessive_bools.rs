@@ -0,0 +1,42 @@
+#![warn(struct_excessive_bools)]
+#![allow(dead_code)]
+
+struct Foo {
+    a: bool,
+    b: bool,
+    c: bool,
+}
+
+struct BadFoo {
+    a: bool,
+    b: bool,
+    c: bool,
+    d: bool,
+}
+
+struct BadTuple(bool, bool, bool, bool);
+
+#[repr(C)]
+struct Ffi {
+    a: bool,
+    b: bool,
+    c: bool,
+    d: bool,
+}
+
+#[allow(struct_excessive_bools)]
+struct Allowed {
+    a: bool,
+    b: bool,
+    c: bool,
+    d: bool,
+}
+
+fn main() {
+    struct FooFoo {
+        a: bool,
+        b: bool,
+        c: bool,
+        d: bool,
+    }
+}
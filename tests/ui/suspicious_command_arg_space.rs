@@ -0,0 +1,16 @@
+#![warn(suspicious_command_arg_space)]
+
+use std::process::Command;
+
+fn main() {
+    // Things it should warn about:
+    Command::new("echo").arg("-n hello").spawn().unwrap();
+    Command::new("cat").arg("--number file").spawn().unwrap();
+    Command::new("mkfs").arg("-t").arg("-t ext2").spawn().unwrap();
+
+    // Things it should not warn about:
+    Command::new("echo").arg("hello world").spawn().unwrap();
+    Command::new("a").arg("--fmt=%a %b %c").spawn().unwrap();
+    Command::new("b").arg("-ldflags=-s -w").spawn().unwrap();
+    Command::new("echo").args(&["-n", "hello"]).spawn().unwrap();
+}
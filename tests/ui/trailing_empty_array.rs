@@ -0,0 +1,40 @@
+#![warn(trailing_empty_array)]
+#![allow(dead_code)]
+
+struct RarelyUseful {
+    field: i32,
+    last: [usize; 0],
+}
+
+struct OnlyField {
+    first_and_last: [usize; 0],
+}
+
+const ZERO: usize = 0;
+struct ConstLength {
+    field: i32,
+    last: [usize; ZERO],
+}
+
+struct Tuple(i32, [u8; 0]);
+
+// Ok, has `#[repr(C)]`
+#[repr(C)]
+struct GoodReason {
+    field: i32,
+    last: [usize; 0],
+}
+
+// Ok, not the last field
+struct NotLast {
+    first: [usize; 0],
+    field: i32,
+}
+
+// Ok, not zero-sized
+struct NonZero {
+    field: i32,
+    last: [usize; 1],
+}
+
+fn main() {}
@@ -1,7 +1,7 @@
 
 
 
-#![allow(dead_code)]
+#![allow(dead_code, transmute_undefined_repr)]
 
 extern crate core;
 
@@ -0,0 +1,34 @@
+#![warn(transmute_undefined_repr)]
+#![allow(unused_variables, useless_transmute, transmute_ptr_to_ptr)]
+
+use std::mem::transmute;
+
+struct Empty;
+struct Ty<T>(T);
+struct Ty2<T, U>(T, U);
+
+#[repr(C)]
+struct Ty2C<T, U>(T, U);
+
+fn main() {
+    unsafe {
+        let _: () = transmute(Empty); // Ok, zero-sized
+        let _: Ty<u32> = transmute(0u32); // Ok, single field
+        let _: u32 = transmute(Ty(0u32)); // Ok, single field
+        let _: Ty<(u32, Empty)> = transmute(0u32); // Ok, single non-zero-sized field
+
+        let _: Ty2C<u32, i32> = transmute(Ty2C(0u32, 0i32)); // Ok, `repr(C)`
+        let _: [u32; 2] = transmute(Ty2C(0u32, 0u32)); // Ok, `repr(C)`
+
+        let _: Ty2<u32, i32> = transmute(Ty2::<u32, f32>(0, 0.0)); // Lint, different generic arguments
+        let _: &Ty2<u32, i32> = transmute(&Ty2::<u32, f32>(0, 0.0)); // Lint, through references
+        let _: Ty<Ty2<u32, i32>> = transmute(Ty2::<u32, f32>(0, 0.0)); // Lint, different generic arguments
+        let _: Ty2<u32, i32> = transmute(Ty2::<u32, i32>(0, 0)); // Ok, same type
+
+        let _: [u32; 2] = transmute(Ty2::<u32, u32>(0, 0)); // Lint, from undefined layout
+        let _: (u32, u32) = transmute([0u32; 2]); // Lint, into undefined layout
+        let _: (u32, u32) = transmute(Ty2::<u32, u32>(0, 0)); // Lint, both undefined
+
+        let _: *const Ty2<u32, f32> = transmute(&Ty2::<u32, i32>(0, 0)); // Ok, pointers are not looked through
+    }
+}
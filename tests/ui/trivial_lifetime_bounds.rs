@@ -0,0 +1,31 @@
+#![warn(trivial_lifetime_bounds)]
+#![allow(dead_code, needless_lifetimes)]
+
+fn reflexive<'a: 'a>(_x: &'a u8) {}
+
+fn reflexive_and_other<'a, 'b: 'a + 'b>(_x: &'a u8, _y: &'b u8) {}
+
+fn static_where<'a>(_x: &'a u8)
+where
+    'static: 'a,
+{
+}
+
+fn repeated<'a, 'b: 'a>(_x: &'a u8, _y: &'b u8)
+where
+    'b: 'a,
+{
+}
+
+struct Repeated<'a, T: 'a>(&'a T)
+where
+    T: 'a;
+
+// no error
+fn bounded<'a, 'b: 'a, T: 'b>(_x: &'a u8, _y: &'b T)
+where
+    T: 'a,
+{
+}
+
+fn main() {}
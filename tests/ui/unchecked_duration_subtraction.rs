@@ -0,0 +1,17 @@
+#![warn(unchecked_duration_subtraction)]
+
+use std::time::{Duration, Instant};
+
+fn main() {
+    let first = Instant::now();
+    let second = Duration::from_secs(3);
+
+    let _ = first - second;
+
+    let _ = Instant::now() - Duration::from_secs(5);
+
+    let _ = second - Duration::from_millis(500);
+
+    // subtracting instants yields a `Duration`, not an underflow-prone `Instant`
+    let _ = Instant::now() - first;
+}
@@ -0,0 +1,50 @@
+#![warn(unifiable_lifetimes)]
+#![allow(dead_code)]
+
+fn longest<'a, 'b: 'a>(x: &'a str, _y: &'b str) -> &'a str {
+    x
+}
+
+fn both<'a, 'b: 'a, 'c: 'a>(x: &'a u8, _y: &'b u8, _z: &'c mut u8) -> &'a u8 {
+    x
+}
+
+fn generic<'a, 'b: 'a, T: Clone + 'a>(x: &'a T, _y: &'b T) -> &'a T {
+    x
+}
+
+// `'b` can't be replaced, it is the bound of `'c`
+fn chain<'a, 'b: 'a, 'c: 'b>(x: &'a u8, _y: &'b u8, _z: &'c u8) -> &'a u8 {
+    x
+}
+
+struct Foo;
+
+impl Foo {
+    fn method<'a, 'b: 'a>(&'a self, _other: &'b Foo) -> &'a Foo {
+        self
+    }
+}
+
+// no error, the return type doesn't borrow from `_y`
+fn unbounded<'a, 'b>(x: &'a u8, _y: &'b u8) -> &'a u8 {
+    x
+}
+
+// no error, `'b` is used in the return type
+fn output<'a, 'b: 'a>(_x: &'a u8, y: &'b u8) -> &'b u8 {
+    y
+}
+
+// no error, `'b` is used inside of another type
+fn nested<'a, 'b: 'a>(x: &'a u8, _y: &'a &'b u8) -> &'a u8 {
+    x
+}
+
+// no error, `'b` is used in the body
+fn in_body<'a, 'b: 'a>(x: &'a u8, y: &'b u8) -> &'a u8 {
+    let _z: &'b u8 = y;
+    x
+}
+
+fn main() {}
@@ -0,0 +1,30 @@
+#![feature(never_type)]
+#![warn(uninhabited_references)]
+#![allow(dead_code, unused_variables)]
+
+enum Void {}
+
+fn ret_never() -> &'static ! {
+    unimplemented!()
+}
+
+fn take_void(x: &Void) {}
+
+fn ret_void(x: &Void) -> &Void {
+    x
+}
+
+// Ok, methods on uninhabited types
+impl Void {
+    fn foo(&self) -> u32 {
+        match *self {}
+    }
+}
+
+// Ok, not a reference
+fn take_void_by_value(x: Void) {}
+
+// Ok, inhabited
+fn take_option(x: &Option<Void>) {}
+
+fn main() {}
@@ -0,0 +1,32 @@
+#![warn(unit_hash)]
+#![allow(let_unit_value, unit_arg)]
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+
+enum Foo {
+    Empty,
+    WithValue(u8),
+}
+use Foo::*;
+
+fn do_nothing() {}
+
+fn main() {
+    let mut state = DefaultHasher::new();
+    let my_enum = Foo::Empty;
+
+    match my_enum {
+        Empty => ().hash(&mut state),
+        WithValue(x) => x.hash(&mut state),
+    }
+
+    let res = ();
+    res.hash(&mut state);
+
+    do_nothing().hash(&mut state);
+
+    // ok
+    0_u8.hash(&mut state);
+    (1, 2).hash(&mut state);
+}
@@ -0,0 +1,52 @@
+#![warn(unnecessary_box_returns)]
+#![allow(dead_code, box_collection)]
+
+use std::fmt::Debug;
+
+trait Bar {
+    // lint
+    fn bar(&self) -> Box<usize>;
+}
+
+pub struct Foo;
+
+impl Bar for Foo {
+    // don't lint: this is a problem with the trait, not the implementation
+    fn bar(&self) -> Box<usize> {
+        Box::new(42)
+    }
+}
+
+impl Foo {
+    // lint
+    fn boxed_usize(&self) -> Box<usize> {
+        Box::new(5)
+    }
+}
+
+// lint
+fn boxed_string() -> Box<String> {
+    Box::new(String::from("Hello, world!"))
+}
+
+// don't lint: large type
+fn boxed_large() -> Box<[u8; 1024]> {
+    Box::new([0; 1024])
+}
+
+// don't lint: str is unsized
+fn boxed_str() -> Box<str> {
+    "Hello, world!".to_string().into_boxed_str()
+}
+
+// don't lint: trait objects are unsized
+fn boxed_trait_object() -> Box<dyn Debug> {
+    Box::new(5)
+}
+
+// don't lint: not a box
+fn not_boxed() -> usize {
+    5
+}
+
+fn main() {}
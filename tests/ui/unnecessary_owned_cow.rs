@@ -0,0 +1,71 @@
+#![warn(unnecessary_owned_cow)]
+#![allow(dead_code)]
+
+use std::borrow::Cow;
+
+fn load_name() -> String {
+    String::new()
+}
+
+fn literals(flag: bool) -> Cow<'static, str> {
+    if flag {
+        Cow::Owned(String::from("foo"))
+    } else if !flag {
+        Cow::Owned("bar".to_string())
+    } else {
+        Cow::Borrowed("baz")
+    }
+}
+
+fn slice(s: &str) -> Cow<str> {
+    if s.is_empty() {
+        return Cow::Borrowed(s);
+    }
+    Cow::Owned(s.to_owned())
+}
+
+fn always_owned(flag: bool) -> Cow<'static, str> {
+    if flag {
+        return Cow::Owned(load_name());
+    }
+    match load_name().len() {
+        0 => Cow::Owned(load_name()),
+        _ => {
+            let name = load_name();
+            Cow::Owned(name)
+        },
+    }
+}
+
+struct S;
+
+impl S {
+    fn method(&self) -> Cow<'static, [u8]> {
+        Cow::Owned(vec![1, 2, 3])
+    }
+}
+
+// Ok, sometimes borrowed
+fn sometimes_borrowed(flag: bool) -> Cow<'static, str> {
+    if flag {
+        Cow::Owned(load_name())
+    } else {
+        Cow::Borrowed("")
+    }
+}
+
+// Ok, returns a `Cow` from elsewhere
+fn from_elsewhere(s: &str) -> Cow<str> {
+    String::from_utf8_lossy(s.as_bytes())
+}
+
+// Ok, not a string slice
+fn from_string(s: &String) -> Cow<str> {
+    if s.is_empty() {
+        Cow::Owned(s.to_string())
+    } else {
+        Cow::Borrowed(s)
+    }
+}
+
+fn main() {}
@@ -1,6 +1,6 @@
 
 
-#![allow(unused, dead_code, needless_lifetimes, needless_pass_by_value, trivially_copy_pass_by_ref)]
+#![allow(unused, dead_code, needless_lifetimes, needless_pass_by_value, trivially_copy_pass_by_ref, unifiable_lifetimes)]
 #![warn(extra_unused_lifetimes)]
 
 fn empty() {
@@ -65,6 +65,26 @@ impl X {
     fn explicit_self_with_lifetime<'a>(self: &'a Self) {}
 }
 
+struct Baz;
+
+impl<'a> Baz {
+    fn y(&self) {}
+}
+
+// no error, the lifetime is used in an associated item
+impl<'a> Baz {
+    fn z(&self, x: &'a u8) -> &'a u8 {
+        x
+    }
+}
+
+// no error, the lifetime is used in a body
+impl<'a> Baz {
+    fn w(&self) {
+        let _: Option<&'a u8> = None;
+    }
+}
+
 fn main() {
 
 }
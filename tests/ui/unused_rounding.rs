@@ -0,0 +1,15 @@
+#![warn(unused_rounding)]
+
+fn main() {
+    let _ = 1f32.ceil();
+    let _ = 1.0f64.floor();
+    let _ = 1.00f32.round();
+
+    // Do not lint
+    let _ = 1.5f32.round();
+    let _ = 3.3_f32.ceil();
+    let x = 1.0f64;
+    let _ = x.floor();
+    let _ = 1e3f64.round();
+    let _ = 2e-54f64.floor();
+}
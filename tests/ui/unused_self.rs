@@ -0,0 +1,73 @@
+#![warn(unused_self)]
+#![allow(dead_code)]
+
+mod unused_self {
+    pub struct A;
+
+    impl A {
+        fn unused_self_move(self) {}
+        fn unused_self_ref(&self) {}
+        fn unused_self_mut_ref(&mut self) {}
+        fn unused_self_with_args(&self, x: u8, y: u8) -> u8 {
+            x + y
+        }
+    }
+}
+
+mod used_self {
+    use std::ops::AddAssign;
+
+    pub struct A {
+        x: u8,
+    }
+
+    impl A {
+        fn used_self_move(self) -> u8 {
+            self.x
+        }
+        fn used_self_ref(&self) -> u8 {
+            self.x
+        }
+        fn used_self_mut_ref(&mut self) {
+            self.x += 1
+        }
+        fn used_self_in_closure(&self) -> u8 {
+            let get = || self.x;
+            get()
+        }
+        fn used_self_as_arg(&self) -> &Self {
+            identity(self)
+        }
+    }
+
+    fn identity(a: &A) -> &A {
+        a
+    }
+
+    // trait implementations can't drop `self`
+    impl AddAssign for A {
+        fn add_assign(&mut self, _: Self) {}
+    }
+}
+
+mod escape_hatch {
+    pub struct A;
+
+    impl A {
+        // kept for API stability
+        #[allow(unused_self)]
+        pub fn allowed(&self) {}
+    }
+}
+
+mod associated {
+    pub struct A;
+
+    impl A {
+        fn associated(x: u8) -> u8 {
+            x
+        }
+    }
+}
+
+fn main() {}
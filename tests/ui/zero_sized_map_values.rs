@@ -0,0 +1,27 @@
+#![warn(zero_sized_map_values)]
+#![allow(dead_code, unused_variables)]
+
+use std::collections::{BTreeMap, HashMap};
+
+type Unit = ();
+
+struct Empty;
+
+struct Foo {
+    field: HashMap<String, ()>,
+    ok: HashMap<String, u8>,
+}
+
+fn args(map: &HashMap<u32, Unit>, ok: &HashMap<u32, u32>) {}
+
+fn ret() -> BTreeMap<u32, Empty> {
+    BTreeMap::new()
+}
+
+fn generic<T>(map: HashMap<u32, T>) {}
+
+fn main() {
+    let local = HashMap::<u32, [u8; 0]>::new();
+    let ok: HashMap<u32, u8> = HashMap::new();
+    generic::<()>(HashMap::new());
+}
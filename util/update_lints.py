@@ -74,6 +74,15 @@ def gen_deprecated(lints):
         yield '    );\n'
 
 
+def gen_categories(lints):
+    """Declare the category of each lint, sorted by name"""
+
+    categories = [(name, cat) for cat, value in lints.items()
+                  for (_, name, _, _) in value]
+    for (name, cat) in sorted(categories):
+        yield '    ("%s", "%s"),\n' % (name, cat)
+
+
 def replace_region(fn, region_start, region_end, callback,
                    replace_start=True, write_back=True):
     """Replace a region in a file delimited by two lines matching regexes.
@@ -238,6 +247,12 @@ def main(print_only=False, check=False):
         replace_start=False,
         write_back=not check)
 
+    # same for the lint categories of the SARIF output
+    changed |= replace_region(
+        'src/sarif.rs', r'begin lint categories', r'end lint categories',
+        lambda: gen_categories(clippy_lints),
+        replace_start=False, write_back=not check)
+
     if check and changed:
         print('Please run util/update_lints.py to regenerate lints lists.')
         return 1